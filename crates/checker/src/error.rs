@@ -120,6 +120,53 @@ pub enum CheckErrorMessage {
     RecursingFragmentSpread { name: String },
     #[error("Subscription operation must have exactly one root field")]
     SubscriptionMustHaveExactlyOneRootField,
+    #[error("Client-only field '{name}' should be marked with @client")]
+    ClientFieldWithoutDirective { name: String },
+    // lint errors for type system
+    #[error("Type name '{name}' should be in PascalCase")]
+    TypeNameNotPascalCase { name: String },
+    #[error("Name '{name}' should be in camelCase")]
+    NameNotCamelCase { name: String },
+    #[error("Enum value '{name}' should be in SCREAMING_SNAKE_CASE")]
+    EnumValueNotScreamingCase { name: String },
+    #[error("Name of input type '{name}' should end with '{suffix}'")]
+    MissingInputTypeSuffix { name: String, suffix: String },
+    #[error("Root field '{name}' should have a description")]
+    RootFieldWithoutDescription { name: String },
+    #[error("Items of list type '{r#type}' should be non-nullable")]
+    NullableListItem { r#type: String },
+    #[error("Deprecation should specify a reason")]
+    DeprecationWithoutReason,
+    #[error("Connection type '{name}' should have a field '{field_name}' of type '{expected}'")]
+    InvalidRelayConnection {
+        name: String,
+        field_name: String,
+        expected: String,
+    },
+    #[error("Edge type '{name}' should have a field '{field_name}'")]
+    InvalidRelayEdge { name: String, field_name: String },
+    // lint errors for operation
+    #[error("Operation should have a name")]
+    OperationNameRequired,
+    #[error("Operation name '{name}' should match the file name '{file_name}'")]
+    OperationNameMismatchesFileName { name: String, file_name: String },
+    #[error("Fragment '{name}' is defined in another file and should be imported by name")]
+    FragmentNotImportedByName { name: String },
+    #[error("Field 'id' of type '{type_name}' should be selected")]
+    IdNotSelected { type_name: String },
+    #[error("Selection depth {depth} exceeds the maximum of {max}")]
+    SelectionTooDeep { depth: usize, max: usize },
+    #[error("'__typename' should not be aliased")]
+    TypenameAliased,
+    #[error("Key fields {fields} of type '{type_name}' should be selected")]
+    CacheKeysNotSelected { type_name: String, fields: String },
+    #[error("Mutation result of type '{type_name}' should select key fields {fields} so that cached data can be updated")]
+    MutationResultWithoutCacheKeys { type_name: String, fields: String },
+    // complexity errors for operation
+    #[error("Operation uses {count} aliases, exceeding the maximum of {max}")]
+    TooManyAliases { count: usize, max: usize },
+    #[error("Operation cost {cost} exceeds the maximum of {max}")]
+    OperationCostExceeded { cost: u64, max: u64 },
     // Error that should be checked in type system check phase
     #[error("Type system error. This is a bug of checker")]
     TypeSystemError,
@@ -130,6 +177,8 @@ pub enum CheckErrorMessage {
     DefinitionPos { name: String },
     #[error("Root types are defined here")]
     RootTypesAreDefinedHere,
+    #[error("Field '{name}' costs {cost}")]
+    CostBreakdown { name: String, cost: u64 },
     // Error from plugin
     #[error("{message}")]
     Plugin { message: String },
@@ -1,9 +1,15 @@
 mod common;
 mod error;
 mod operation_checker;
+mod schema_linter;
 mod type_system_checker;
 mod types;
 
 pub use error::{CheckError, CheckErrorMessage};
-pub use operation_checker::{check_operation_document, OperationCheckContext};
+pub use operation_checker::{
+    check_client_fields, check_operation_complexity, check_operation_document,
+    lint_operation_document, ComplexityOptions, FieldCosts, OperationCheckContext,
+    OperationLintOptions,
+};
+pub use schema_linter::{lint_type_system_document, SchemaLintOptions};
 pub use type_system_checker::check_type_system_document;
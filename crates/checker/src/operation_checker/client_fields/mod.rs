@@ -0,0 +1,122 @@
+use graphql_type_system::{Node, OriginalNodeRef, Text, TypeDefinition};
+use nitrogql_ast::{
+    base::Pos,
+    operation::{ExecutableDefinition, OperationDocument},
+    selection_set::{Selection, SelectionSet},
+};
+use nitrogql_semantics::{direct_fields_of_output_type, has_client_directive};
+
+use super::{context::OperationCheckContext, operation_type_from_root_types};
+use crate::error::{CheckError, CheckErrorMessage};
+
+#[cfg(test)]
+mod tests;
+
+/// Checks that fields defined in client schema files are selected with `@client`.
+/// `client_schema_files` is the list of file indices of client schema files.
+/// Document should be already checked by `check_operation_document`.
+pub fn check_client_fields<'src, S: Text<'src>>(
+    document: &OperationDocument<'src>,
+    context: &OperationCheckContext<'_, 'src, S>,
+    client_schema_files: &[usize],
+) -> Vec<CheckError> {
+    let mut result = vec![];
+    if client_schema_files.is_empty() {
+        return result;
+    }
+    let checker = ClientFieldsChecker {
+        context,
+        client_schema_files,
+    };
+    for def in document.definitions.iter() {
+        match def {
+            ExecutableDefinition::OperationDefinition(op) => {
+                let root_types = context.definitions.root_types().unwrap_or_default();
+                let root_type_name = operation_type_from_root_types(&root_types, op.operation_type);
+                if let Some(root_type) = context.definitions.get_type(root_type_name) {
+                    checker.check(root_type, &op.selection_set, &mut result);
+                }
+            }
+            ExecutableDefinition::FragmentDefinition(fragment) => {
+                if fragment.position.file != document.position.file {
+                    // Imported fragments are checked in their own document.
+                    continue;
+                }
+                if let Some(fragment_type) =
+                    context.definitions.get_type(fragment.type_condition.name)
+                {
+                    checker.check(fragment_type, &fragment.selection_set, &mut result);
+                }
+            }
+        }
+    }
+    result
+}
+
+struct ClientFieldsChecker<'a, 'src, S> {
+    context: &'a OperationCheckContext<'a, 'src, S>,
+    client_schema_files: &'a [usize],
+}
+
+impl<'src, S: Text<'src>> ClientFieldsChecker<'_, 'src, S> {
+    /// Checks given selection set.
+    /// Selections marked with `@client` are not checked further
+    /// because everything below them is resolved on the client.
+    fn check(
+        &self,
+        parent_type: &Node<TypeDefinition<S, Pos>, Pos>,
+        selection_set: &SelectionSet<'src>,
+        result: &mut Vec<CheckError>,
+    ) {
+        let Some(parent_fields) = direct_fields_of_output_type(parent_type) else {
+            return;
+        };
+        for selection in selection_set.selections.iter() {
+            if has_client_directive(selection.directives()) {
+                continue;
+            }
+            match selection {
+                Selection::Field(field_selection) => {
+                    let Some(field) = parent_fields
+                        .iter()
+                        .find(|f| f.name == field_selection.name.name)
+                    else {
+                        continue;
+                    };
+                    if self
+                        .client_schema_files
+                        .contains(&field.name.original_node_ref().file)
+                    {
+                        result.push(
+                            CheckErrorMessage::ClientFieldWithoutDirective {
+                                name: field_selection.name.name.to_owned(),
+                            }
+                            .with_pos(field_selection.name.position),
+                        );
+                        continue;
+                    }
+                    let Some(ref field_selection_set) = field_selection.selection_set else {
+                        continue;
+                    };
+                    if let Some(field_type) =
+                        self.context.definitions.get_type(field.r#type.unwrapped())
+                    {
+                        self.check(field_type, field_selection_set, result);
+                    }
+                }
+                Selection::FragmentSpread(_) => {}
+                Selection::InlineFragment(inline_fragment) => {
+                    let fragment_type = match inline_fragment.type_condition {
+                        None => Some(parent_type),
+                        Some(ref type_condition) => {
+                            self.context.definitions.get_type(type_condition.name)
+                        }
+                    };
+                    if let Some(fragment_type) = fragment_type {
+                        self.check(fragment_type, &inline_fragment.selection_set, result);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use graphql_builtins::generate_builtins;
+use graphql_type_system::Schema;
+use insta::assert_debug_snapshot;
+use nitrogql_ast::{base::Pos, set_current_file_of_pos, TypeSystemDocument};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_semantics::{
+    ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+
+use crate::{CheckError, OperationCheckContext};
+
+use super::check_client_fields;
+
+const SCHEMA: &str = "
+directive @client on FIELD | FRAGMENT_SPREAD | INLINE_FRAGMENT
+
+type Query {
+    me: User!
+}
+type User {
+    id: ID!
+    name: String!
+}
+";
+
+const CLIENT_SCHEMA: &str = "
+extend type Query {
+    isLoggedIn: Boolean!
+    settings: Settings!
+}
+extend type User {
+    isSelected: Boolean!
+}
+type Settings {
+    theme: String!
+}
+";
+
+/// Index of the client schema file.
+const CLIENT_FILE: usize = 1;
+
+fn type_system_document() -> TypeSystemDocument<'static> {
+    set_current_file_of_pos(0);
+    let mut doc = parse_type_system_document(SCHEMA).unwrap();
+    set_current_file_of_pos(CLIENT_FILE);
+    doc.extend(
+        parse_type_system_document(CLIENT_SCHEMA)
+            .unwrap()
+            .definitions,
+    );
+    doc.extend(generate_builtins());
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn test_client_fields(source: &str) -> Vec<CheckError> {
+    let type_system_document = type_system_document();
+    let schema: Schema<Cow<str>, Pos> = ast_to_type_system(&type_system_document);
+    set_current_file_of_pos(2);
+    let doc = parse_operation_document(source).unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    let context = OperationCheckContext::new(&schema);
+    check_client_fields(&doc, &context, &[CLIENT_FILE])
+}
+
+#[test]
+fn client_fields_with_directive() {
+    let errors = test_client_fields(
+        "
+        query {
+            isLoggedIn @client
+            settings @client { theme }
+            me { id name isSelected @client }
+        }
+        ",
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn client_fields_without_directive() {
+    let errors = test_client_fields(
+        "
+        query {
+            isLoggedIn
+            me {
+                name
+                ... on User { isSelected }
+            }
+        }
+        fragment F on User {
+            isSelected
+        }
+        ",
+    );
+    assert_debug_snapshot!(errors);
+}
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use graphql_type_system::{Text, Type, TypeDefinition};
+use nitrogql_ast::{
+    base::{HasPos, Pos},
+    directive::Directive,
+    operation::{ExecutableDefinition, OperationDefinition, OperationDocument},
+    selection_set::{Field as SelectionField, Selection, SelectionSet},
+    type_system::{TypeDefinition as AstTypeDefinition, TypeSystemDefinition},
+    value::Value,
+    TypeSystemDocument,
+};
+use nitrogql_semantics::direct_fields_of_output_type;
+
+use super::{
+    context::OperationCheckContext,
+    count_selection_set_fields::count_aliases,
+    fragment_map::{generate_fragment_map, FragmentMap},
+    operation_type_from_root_types,
+};
+use crate::error::{CheckError, CheckErrorMessage};
+
+mod options;
+#[cfg(test)]
+mod tests;
+
+pub use options::ComplexityOptions;
+
+/// Name of directive that specifies the cost of a field or a type.
+/// The directive is not built in; schemas declare it themselves.
+const COST_DIRECTIVE: &str = "cost";
+
+/// Weights declared by `@cost(weight:)` directives in the schema.
+#[derive(Debug, Default)]
+pub struct FieldCosts<'src> {
+    /// Weights of fields, keyed by (type name, field name).
+    fields: HashMap<(&'src str, &'src str), u64>,
+    /// Weights of types, applied to fields that return the type.
+    types: HashMap<&'src str, u64>,
+}
+
+impl<'src> FieldCosts<'src> {
+    /// Collects weights from given schema document.
+    pub fn from_document(document: &TypeSystemDocument<'src>) -> Self {
+        let mut result = FieldCosts::default();
+        let no_fields = vec![];
+        for def in document.definitions.iter() {
+            let TypeSystemDefinition::TypeDefinition(def) = def else {
+                continue;
+            };
+            let (type_name, directives, fields) = match def {
+                AstTypeDefinition::Object(def) => (def.name.name, &def.directives, &def.fields),
+                AstTypeDefinition::Interface(def) => (def.name.name, &def.directives, &def.fields),
+                AstTypeDefinition::Scalar(def) => (def.name.name, &def.directives, &no_fields),
+                AstTypeDefinition::Union(def) => (def.name.name, &def.directives, &no_fields),
+                AstTypeDefinition::Enum(def) => (def.name.name, &def.directives, &no_fields),
+                AstTypeDefinition::InputObject(_) => continue,
+            };
+            if let Some(weight) = cost_weight(directives) {
+                result.types.insert(type_name, weight);
+            }
+            for field in fields.iter() {
+                if let Some(weight) = cost_weight(&field.directives) {
+                    result.fields.insert((type_name, field.name.name), weight);
+                }
+            }
+        }
+        result
+    }
+
+    fn field_weight(&self, type_name: &str, field_name: &str) -> Option<u64> {
+        self.fields.get(&(type_name, field_name)).copied()
+    }
+
+    fn type_weight(&self, type_name: &str) -> Option<u64> {
+        self.types.get(type_name).copied()
+    }
+}
+
+/// Reads the weight from `@cost(weight:)` directive, if any.
+fn cost_weight(directives: &[Directive]) -> Option<u64> {
+    let directive = directives
+        .iter()
+        .find(|directive| directive.name.name == COST_DIRECTIVE)?;
+    let (_, weight) = directive
+        .arguments
+        .as_ref()?
+        .arguments
+        .iter()
+        .find(|(name, _)| name.name == "weight")?;
+    match weight {
+        Value::IntValue(value) => value.value.parse().ok(),
+        Value::FloatValue(value) => parse_float_weight(value.value),
+        Value::StringValue(value) => parse_float_weight(&value.value),
+        _ => None,
+    }
+}
+
+fn parse_float_weight(value: &str) -> Option<u64> {
+    let value: f64 = value.parse().ok()?;
+    (value >= 0.0).then(|| value.ceil() as u64)
+}
+
+/// Checks given operation document against complexity limits.
+/// Document should be already checked by `check_operation_document`.
+pub fn check_operation_complexity<'src, S: Text<'src>>(
+    document: &OperationDocument<'src>,
+    context: &OperationCheckContext<'_, 'src, S>,
+    field_costs: &FieldCosts,
+    options: &ComplexityOptions,
+) -> Vec<CheckError> {
+    let mut result = vec![];
+    let fragment_map = generate_fragment_map(document);
+
+    for def in document.definitions.iter() {
+        let ExecutableDefinition::OperationDefinition(op) = def else {
+            continue;
+        };
+        let op_pos = *op.name_pos().position();
+        if let Some(max) = options.max_aliases {
+            let count = count_aliases(&fragment_map, &op.selection_set);
+            if count > max {
+                result.push(CheckErrorMessage::TooManyAliases { count, max }.with_pos(op_pos));
+            }
+        }
+        if let Some(max) = options.max_cost {
+            let calculator = CostCalculator {
+                fragment_map: &fragment_map,
+                context,
+                field_costs,
+                options,
+                variable_defaults: variable_defaults(op),
+            };
+            let root_types = context.definitions.root_types().unwrap_or_default();
+            let root_type_name = operation_type_from_root_types(&root_types, op.operation_type);
+            let Some(root_type) = context.definitions.get_type(root_type_name) else {
+                continue;
+            };
+            let mut breakdown = vec![];
+            calculator.selection_set_costs(root_type, &op.selection_set, &[], &mut breakdown);
+            let cost = breakdown
+                .iter()
+                .fold(0u64, |total, (_, cost)| total.saturating_add(*cost));
+            if cost > max {
+                result.push(
+                    CheckErrorMessage::OperationCostExceeded { cost, max }
+                        .with_pos(op_pos)
+                        .with_additional_info(breakdown.into_iter().map(|(field, cost)| {
+                            (
+                                field.name.position,
+                                CheckErrorMessage::CostBreakdown {
+                                    name: field.alias.unwrap_or(field.name).name.to_owned(),
+                                    cost,
+                                },
+                            )
+                        })),
+                );
+            }
+        }
+    }
+
+    result
+}
+
+/// Collects default values of variables in given operation.
+fn variable_defaults<'a, 'src>(
+    op: &'a OperationDefinition<'src>,
+) -> HashMap<&'src str, Option<&'a Value<'src>>> {
+    op.variables_definition
+        .iter()
+        .flat_map(|variables| variables.definitions.iter())
+        .map(|def| (def.name.name, def.default_value.as_ref()))
+        .collect()
+}
+
+struct CostCalculator<'a, 'b, 'src, S> {
+    fragment_map: &'a FragmentMap<'b, 'src>,
+    context: &'a OperationCheckContext<'a, 'src, S>,
+    field_costs: &'a FieldCosts<'a>,
+    options: &'a ComplexityOptions,
+    variable_defaults: HashMap<&'src str, Option<&'a Value<'src>>>,
+}
+
+impl<'a, 'b, 'src, S: Text<'src>> CostCalculator<'a, 'b, 'src, S> {
+    /// Calculates the cost of each field in given selection set.
+    /// Fields selected through fragments are listed as if they are selected directly.
+    fn selection_set_costs<'c>(
+        &self,
+        parent_type: &TypeDefinition<S, Pos>,
+        selection_set: &'c SelectionSet<'src>,
+        seen_fragments: &[&str],
+        result: &mut Vec<(&'c SelectionField<'src>, u64)>,
+    ) where
+        'b: 'c,
+    {
+        for selection in selection_set.selections.iter() {
+            match selection {
+                Selection::Field(field) => {
+                    let cost = self.field_cost(parent_type, field, seen_fragments);
+                    result.push((field, cost));
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let fragment_name = fragment_spread.fragment_name.name;
+                    if seen_fragments.contains(&fragment_name) {
+                        // prevent infinite recursions
+                        continue;
+                    }
+                    let Some(fragment) = self.fragment_map.get(fragment_name) else {
+                        continue;
+                    };
+                    let Some(fragment_type) = self
+                        .context
+                        .definitions
+                        .get_type(fragment.type_condition.name)
+                    else {
+                        continue;
+                    };
+                    let seen_fragments: Vec<_> = seen_fragments
+                        .iter()
+                        .copied()
+                        .chain(vec![fragment_name])
+                        .collect();
+                    self.selection_set_costs(
+                        fragment_type,
+                        &fragment.selection_set,
+                        &seen_fragments,
+                        result,
+                    );
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    let fragment_type = match inline_fragment.type_condition {
+                        None => Some(parent_type),
+                        Some(ref type_condition) => self
+                            .context
+                            .definitions
+                            .get_type(type_condition.name)
+                            .map(|ty| &**ty),
+                    };
+                    let Some(fragment_type) = fragment_type else {
+                        continue;
+                    };
+                    self.selection_set_costs(
+                        fragment_type,
+                        &inline_fragment.selection_set,
+                        seen_fragments,
+                        result,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Calculates the cost of given field.
+    /// The field's own weight is counted once, while the cost of its children
+    /// is multiplied by the expected number of list items.
+    fn field_cost(
+        &self,
+        parent_type: &TypeDefinition<S, Pos>,
+        field: &SelectionField<'src>,
+        seen_fragments: &[&str],
+    ) -> u64 {
+        let Some(parent_fields) = direct_fields_of_output_type(parent_type) else {
+            return 0;
+        };
+        let Some(field_def) = parent_fields.iter().find(|f| f.name == field.name.name) else {
+            return 0;
+        };
+        let type_name: &str = field_def.r#type.unwrapped();
+        let weight = self
+            .field_costs
+            .field_weight(parent_type.name(), field.name.name)
+            .or_else(|| self.field_costs.type_weight(type_name))
+            .unwrap_or(if field.selection_set.is_some() { 1 } else { 0 });
+        let children_cost = match (
+            field.selection_set.as_ref(),
+            self.context.definitions.get_type(type_name),
+        ) {
+            (Some(selection_set), Some(field_type)) => {
+                let mut children = vec![];
+                self.selection_set_costs(field_type, selection_set, seen_fragments, &mut children);
+                children
+                    .iter()
+                    .fold(0u64, |total, (_, cost)| total.saturating_add(*cost))
+            }
+            _ => 0,
+        };
+        let multiplier = if is_list_type(&field_def.r#type) {
+            self.list_size(field)
+        } else {
+            1
+        };
+        weight.saturating_add(multiplier.saturating_mul(children_cost))
+    }
+
+    /// Expected number of items returned from given list field.
+    fn list_size(&self, field: &SelectionField<'src>) -> u64 {
+        let Some(ref arguments) = field.arguments else {
+            return self.options.default_list_size;
+        };
+        arguments
+            .arguments
+            .iter()
+            .filter(|(name, _)| name.name == "first" || name.name == "last")
+            .find_map(|(_, value)| self.int_value(value))
+            .unwrap_or(self.options.default_list_size)
+    }
+
+    fn int_value(&self, value: &Value) -> Option<u64> {
+        match value {
+            Value::IntValue(value) => value.value.parse().ok(),
+            Value::Variable(variable) => self
+                .variable_defaults
+                .get(variable.name)
+                .copied()
+                .flatten()
+                .and_then(|value| self.int_value(value)),
+            _ => None,
+        }
+    }
+}
+
+fn is_list_type<S, D>(ty: &Type<S, D>) -> bool {
+    match ty {
+        Type::Named(_) => false,
+        Type::List(_) => true,
+        Type::NonNull(inner) => is_list_type(inner.as_inner()),
+    }
+}
@@ -0,0 +1,23 @@
+/// Complexity limits for operations.
+/// Limits are not enforced by default.
+/// Depth of operations is limited by the `maxSelectionDepth` lint rule.
+#[derive(Debug, Clone)]
+pub struct ComplexityOptions {
+    /// Maximum number of aliases in an operation.
+    pub max_aliases: Option<usize>,
+    /// Maximum cost of an operation.
+    pub max_cost: Option<u64>,
+    /// Number of items assumed for list fields
+    /// that are not limited by `first` or `last` argument.
+    pub default_list_size: u64,
+}
+
+impl Default for ComplexityOptions {
+    fn default() -> Self {
+        Self {
+            max_aliases: None,
+            max_cost: None,
+            default_list_size: 1,
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+
+use super::ComplexityOptions;
+use graphql_builtins::generate_builtins;
+use graphql_type_system::Schema;
+use insta::assert_debug_snapshot;
+use nitrogql_ast::{base::Pos, TypeSystemDocument};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_semantics::{
+    ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+
+use crate::{CheckError, OperationCheckContext};
+
+use super::{check_operation_complexity, FieldCosts};
+
+const SCHEMA: &str = "
+directive @cost(weight: Int!) on FIELD_DEFINITION | OBJECT
+
+type Query {
+    me: User!
+    user(id: ID!): User
+    users(first: Int, last: Int): [User!]! @cost(weight: 5)
+}
+type User {
+    id: ID!
+    name: String!
+    posts(first: Int): [Post!]!
+}
+type Post @cost(weight: 2) {
+    id: ID!
+    title: String!
+    body: String! @cost(weight: 3)
+}
+";
+
+fn type_system_document() -> TypeSystemDocument<'static> {
+    let mut doc = parse_type_system_document(SCHEMA).unwrap();
+    doc.extend(generate_builtins());
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn type_system(doc: &TypeSystemDocument) -> Schema<Cow<'static, str>, Pos> {
+    ast_to_type_system(doc).map_str(|s| Cow::Owned(s.to_string()))
+}
+
+fn test_complexity(source: &str, options: &ComplexityOptions) -> Vec<CheckError> {
+    let type_system_document = type_system_document();
+    let schema = type_system(&type_system_document);
+    let field_costs = FieldCosts::from_document(&type_system_document);
+    let doc = parse_operation_document(source).unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    let context = OperationCheckContext::new(&schema);
+    check_operation_complexity(&doc, &context, &field_costs, options)
+}
+
+#[test]
+fn no_limits() {
+    let errors = test_complexity(
+        "
+        query Q {
+            a: me { b: posts { c: body } }
+            users { posts { body } }
+        }
+        ",
+        &ComplexityOptions::default(),
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn max_aliases() {
+    let options = ComplexityOptions {
+        max_aliases: Some(2),
+        ..ComplexityOptions::default()
+    };
+    let errors = test_complexity(
+        "
+        query Few {
+            a: me { id }
+            b: me { id }
+        }
+        query Many {
+            a: me { ...F }
+            b: me { ...F }
+        }
+        fragment F on User {
+            userName: name
+        }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn max_cost() {
+    let options = ComplexityOptions {
+        max_cost: Some(20),
+        default_list_size: 10,
+        ..ComplexityOptions::default()
+    };
+    let errors = test_complexity(
+        "
+        query Cheap {
+            me { name posts(first: 3) { title } }
+        }
+        query Expensive($count: Int = 5) {
+            users(first: $count) { ...UserPosts }
+            user(id: \"1\") { posts { body } }
+        }
+        fragment UserPosts on User {
+            posts(first: 2) { title }
+        }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
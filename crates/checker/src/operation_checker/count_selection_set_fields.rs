@@ -56,3 +56,55 @@ fn selection_set_has_more_than_one_fields_impl(
     }
     count
 }
+
+/// Counts aliased fields in given selection set, including nested selection sets.
+/// Fragments are counted every time they are spread.
+pub fn count_aliases(fragment_map: &FragmentMap, selection_set: &SelectionSet) -> usize {
+    count_aliases_impl(fragment_map, selection_set, &[])
+}
+
+fn count_aliases_impl(
+    fragment_map: &FragmentMap,
+    selection_set: &SelectionSet,
+    seen_fragments: &[&str],
+) -> usize {
+    let mut count = 0;
+    for selection in selection_set.selections.iter() {
+        match selection {
+            Selection::Field(field) => {
+                if field.alias.is_some() {
+                    count += 1;
+                }
+                if let Some(ref selection_set) = field.selection_set {
+                    count += count_aliases_impl(fragment_map, selection_set, seen_fragments);
+                }
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if seen_fragments.contains(&fragment_spread.fragment_name.name) {
+                    // prevent infinite recursions
+                    continue;
+                }
+                let Some(fragment_def) = fragment_map.get(fragment_spread.fragment_name.name)
+                else {
+                    // This should be handled elsewhere
+                    continue;
+                };
+                let seen_fragments: Vec<_> = seen_fragments
+                    .iter()
+                    .copied()
+                    .chain(vec![fragment_spread.fragment_name.name])
+                    .collect();
+                count +=
+                    count_aliases_impl(fragment_map, &fragment_def.selection_set, &seen_fragments);
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                count += count_aliases_impl(
+                    fragment_map,
+                    &inline_fragment.selection_set,
+                    seen_fragments,
+                );
+            }
+        }
+    }
+    count
+}
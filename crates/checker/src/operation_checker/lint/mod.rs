@@ -0,0 +1,398 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use graphql_type_system::{Node, Text, TypeDefinition};
+use nitrogql_ast::{
+    base::{HasPos, Pos},
+    operation::{ExecutableDefinition, OperationDefinition, OperationDocument, OperationType},
+    selection_set::{Selection, SelectionSet},
+};
+use nitrogql_semantics::{
+    cache_keys_of_type, direct_fields_of_output_type, CacheKeys, ImportTargets, OperationExtension,
+};
+
+use super::{
+    context::OperationCheckContext,
+    fragment_map::{generate_fragment_map, FragmentMap},
+    operation_type_from_root_types,
+    selection_depth::selection_set_depth,
+};
+use crate::error::{CheckError, CheckErrorMessage};
+
+mod options;
+#[cfg(test)]
+mod tests;
+
+pub use options::OperationLintOptions;
+
+/// Checks given operation document against lint rules.
+/// Document should be already checked by `check_operation_document`.
+/// `extension` is the extension of the document before imports are resolved.
+/// `cache_keys` is used by the `requireCacheKeys` rule.
+pub fn lint_operation_document<'src, S: Text<'src>>(
+    path: &Path,
+    document: &OperationDocument<'src>,
+    extension: &OperationExtension<'src>,
+    context: &OperationCheckContext<'_, 'src, S>,
+    options: &OperationLintOptions,
+    cache_keys: &HashMap<String, Vec<String>>,
+) -> Vec<CheckError> {
+    let mut result = vec![];
+    let fragment_map = generate_fragment_map(document);
+    let named_imports = extension
+        .imports
+        .iter()
+        .flat_map(|import| match import.targets {
+            ImportTargets::Wildcard => &[][..],
+            ImportTargets::Specific(ref names) => names,
+        })
+        .map(|name| name.name)
+        .collect();
+    let linter = SelectionSetLinter {
+        fragment_map: &fragment_map,
+        named_imports,
+        context,
+        options,
+        cache_keys,
+    };
+    let mutation_fragments = fragments_used_in_mutations(document, &fragment_map);
+
+    for def in document.definitions.iter() {
+        match def {
+            ExecutableDefinition::OperationDefinition(op) => {
+                lint_operation_name(path, op, options, &mut result);
+                if let Some(max) = options.max_selection_depth {
+                    let depth = selection_set_depth(&fragment_map, &op.selection_set);
+                    if depth > max {
+                        result.push(
+                            CheckErrorMessage::SelectionTooDeep { depth, max }
+                                .with_pos(*op.name_pos().position()),
+                        );
+                    }
+                }
+                let root_types = context.definitions.root_types().unwrap_or_default();
+                let root_type_name = operation_type_from_root_types(&root_types, op.operation_type);
+                if let Some(root_type) = context.definitions.get_type(root_type_name) {
+                    linter.lint(
+                        root_type,
+                        &op.selection_set,
+                        op.operation_type == OperationType::Mutation,
+                        &mut result,
+                    );
+                }
+            }
+            ExecutableDefinition::FragmentDefinition(fragment) => {
+                if fragment.position.file != document.position.file {
+                    // Imported fragments are linted in their own document.
+                    continue;
+                }
+                if let Some(fragment_type) =
+                    context.definitions.get_type(fragment.type_condition.name)
+                {
+                    // Fragments spread into a mutation are linted as part of its result.
+                    linter.lint(
+                        fragment_type,
+                        &fragment.selection_set,
+                        mutation_fragments.contains(fragment.name.name),
+                        &mut result,
+                    );
+                }
+            }
+        }
+    }
+
+    result
+}
+
+fn lint_operation_name(
+    path: &Path,
+    op: &OperationDefinition,
+    options: &OperationLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    match op.name {
+        None => {
+            if options.require_operation_name {
+                result.push(CheckErrorMessage::OperationNameRequired.with_pos(op.position));
+            }
+        }
+        Some(ref name) => {
+            if !options.operation_name_matches_file_name {
+                return;
+            }
+            let Some(file_name) = path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(|file_name| file_name.split('.').next())
+            else {
+                return;
+            };
+            if normalize_name(name.name) != normalize_name(file_name) {
+                result.push(
+                    CheckErrorMessage::OperationNameMismatchesFileName {
+                        name: name.to_string(),
+                        file_name: file_name.to_owned(),
+                    }
+                    .with_pos(name.position),
+                );
+            }
+        }
+    }
+}
+
+/// Normalizes a name so that `get-user`, `get_user` and `getUser` are considered the same.
+fn normalize_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+struct SelectionSetLinter<'a, 'src, S> {
+    fragment_map: &'a FragmentMap<'a, 'src>,
+    /// Names of fragments imported by name.
+    named_imports: Vec<&'src str>,
+    context: &'a OperationCheckContext<'a, 'src, S>,
+    options: &'a OperationLintOptions,
+    cache_keys: &'a HashMap<String, Vec<String>>,
+}
+
+impl<'src, S: Text<'src>> SelectionSetLinter<'_, 'src, S> {
+    /// Lints given selection set.
+    /// Fragment spreads are not followed because fragment definitions are linted separately.
+    /// `in_mutation` is true for selection sets in the result of a mutation.
+    fn lint(
+        &self,
+        parent_type: &Node<TypeDefinition<S, Pos>, Pos>,
+        selection_set: &SelectionSet<'src>,
+        in_mutation: bool,
+        result: &mut Vec<CheckError>,
+    ) {
+        let Some(parent_fields) = direct_fields_of_output_type(parent_type) else {
+            return;
+        };
+        for selection in selection_set.selections.iter() {
+            match selection {
+                Selection::Field(field_selection) => {
+                    if self.options.no_typename_alias && field_selection.name.name == "__typename" {
+                        if let Some(ref alias) = field_selection.alias {
+                            result
+                                .push(CheckErrorMessage::TypenameAliased.with_pos(alias.position));
+                        }
+                    }
+                    let Some(ref field_selection_set) = field_selection.selection_set else {
+                        continue;
+                    };
+                    let Some(field_type) = parent_fields
+                        .iter()
+                        .find(|f| f.name == field_selection.name.name)
+                        .and_then(|f| self.context.definitions.get_type(f.r#type.unwrapped()))
+                    else {
+                        continue;
+                    };
+                    if self.options.require_id_selection
+                        && has_id_field(field_type)
+                        && !selects_field(self.fragment_map, &[], field_selection_set, "id")
+                    {
+                        result.push(
+                            CheckErrorMessage::IdNotSelected {
+                                type_name: field_type.name().to_string(),
+                            }
+                            .with_pos(field_selection.name.position),
+                        );
+                    }
+                    self.lint_cache_keys(
+                        field_type,
+                        &[field_selection_set],
+                        in_mutation,
+                        field_selection.name.position,
+                        result,
+                    );
+                    self.lint(field_type, field_selection_set, in_mutation, result);
+                }
+                Selection::FragmentSpread(fragment_spread) => {
+                    let name = fragment_spread.fragment_name.name;
+                    let Some(fragment) = self.fragment_map.get(name) else {
+                        continue;
+                    };
+                    if self.options.require_named_fragment_imports
+                        && fragment.position.file != fragment_spread.position.file
+                        && !self.named_imports.contains(&name)
+                    {
+                        result.push(
+                            CheckErrorMessage::FragmentNotImportedByName {
+                                name: name.to_owned(),
+                            }
+                            .with_pos(fragment_spread.fragment_name.position),
+                        );
+                    }
+                }
+                Selection::InlineFragment(inline_fragment) => {
+                    let fragment_type = match inline_fragment.type_condition {
+                        None => Some(parent_type),
+                        Some(ref type_condition) => {
+                            self.context.definitions.get_type(type_condition.name)
+                        }
+                    };
+                    let Some(fragment_type) = fragment_type else {
+                        continue;
+                    };
+                    if let Some(ref type_condition) = inline_fragment.type_condition {
+                        if type_condition.name != parent_type.name().borrow() {
+                            // Key fields may be selected either inside or outside the fragment.
+                            self.lint_cache_keys(
+                                fragment_type,
+                                &[selection_set, &inline_fragment.selection_set],
+                                in_mutation,
+                                type_condition.position,
+                                result,
+                            );
+                        }
+                    }
+                    self.lint(
+                        fragment_type,
+                        &inline_fragment.selection_set,
+                        in_mutation,
+                        result,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Checks that key fields of given type are selected in any of given selection sets.
+    /// Only types whose keys are configured in `cacheKeys` are checked.
+    fn lint_cache_keys(
+        &self,
+        ty: &TypeDefinition<S, Pos>,
+        selection_sets: &[&SelectionSet],
+        in_mutation: bool,
+        position: Pos,
+        result: &mut Vec<CheckError>,
+    ) {
+        if !self.options.require_cache_keys {
+            return;
+        }
+        let CacheKeys::Configured(keys) = cache_keys_of_type(ty, self.cache_keys) else {
+            return;
+        };
+        let missing_keys = keys
+            .into_iter()
+            .filter(|key| {
+                !selection_sets
+                    .iter()
+                    .any(|selection_set| selects_field(self.fragment_map, &[], selection_set, key))
+            })
+            .map(|key| format!("'{key}'"))
+            .collect::<Vec<_>>();
+        if missing_keys.is_empty() {
+            return;
+        }
+        let type_name = ty.name().to_string();
+        let fields = missing_keys.join(", ");
+        let message = if in_mutation {
+            CheckErrorMessage::MutationResultWithoutCacheKeys { type_name, fields }
+        } else {
+            CheckErrorMessage::CacheKeysNotSelected { type_name, fields }
+        };
+        result.push(message.with_pos(position));
+    }
+}
+
+fn has_id_field<'src, S: Text<'src>>(ty: &TypeDefinition<S, Pos>) -> bool {
+    match ty {
+        TypeDefinition::Object(obj) => obj.fields.iter().any(|f| f.name == "id"),
+        TypeDefinition::Interface(intf) => intf.fields.iter().any(|f| f.name == "id"),
+        _ => false,
+    }
+}
+
+/// Collects names of fragments that are spread (possibly indirectly) into mutations of given document.
+fn fragments_used_in_mutations<'a>(
+    document: &OperationDocument<'a>,
+    fragment_map: &FragmentMap<'_, 'a>,
+) -> HashSet<&'a str> {
+    let mut result = HashSet::new();
+    for def in document.definitions.iter() {
+        if let ExecutableDefinition::OperationDefinition(op) = def {
+            if op.operation_type == OperationType::Mutation {
+                collect_fragment_spreads(fragment_map, &op.selection_set, &mut result);
+            }
+        }
+    }
+    result
+}
+
+fn collect_fragment_spreads<'a>(
+    fragment_map: &FragmentMap<'_, 'a>,
+    selection_set: &SelectionSet<'a>,
+    result: &mut HashSet<&'a str>,
+) {
+    for selection in selection_set.selections.iter() {
+        match selection {
+            Selection::Field(field_selection) => {
+                if let Some(ref selection_set) = field_selection.selection_set {
+                    collect_fragment_spreads(fragment_map, selection_set, result);
+                }
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let name = fragment_spread.fragment_name.name;
+                if !result.insert(name) {
+                    continue;
+                }
+                if let Some(fragment) = fragment_map.get(name) {
+                    collect_fragment_spreads(fragment_map, &fragment.selection_set, result);
+                }
+            }
+            Selection::InlineFragment(inline_fragment) => {
+                collect_fragment_spreads(fragment_map, &inline_fragment.selection_set, result);
+            }
+        }
+    }
+}
+
+/// Checks whether given selection set selects a field of given name
+/// (without alias), either directly or through fragments.
+fn selects_field(
+    fragment_map: &FragmentMap,
+    seen_fragments: &[&str],
+    selection_set: &SelectionSet,
+    field_name: &str,
+) -> bool {
+    selection_set
+        .selections
+        .iter()
+        .any(|selection| match selection {
+            Selection::Field(field_selection) => {
+                field_selection.name.name == field_name && field_selection.alias.is_none()
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                let fragment_name = fragment_spread.fragment_name.name;
+                if seen_fragments.contains(&fragment_name) {
+                    return false;
+                }
+                let Some(fragment) = fragment_map.get(fragment_name) else {
+                    return false;
+                };
+                let seen_fragments: Vec<&str> = seen_fragments
+                    .iter()
+                    .copied()
+                    .chain(vec![fragment_name])
+                    .collect();
+                selects_field(
+                    fragment_map,
+                    &seen_fragments,
+                    &fragment.selection_set,
+                    field_name,
+                )
+            }
+            Selection::InlineFragment(inline_fragment) => selects_field(
+                fragment_map,
+                seen_fragments,
+                &inline_fragment.selection_set,
+                field_name,
+            ),
+        })
+}
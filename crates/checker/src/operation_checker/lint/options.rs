@@ -0,0 +1,20 @@
+/// Lint rules for operations.
+/// All rules are disabled by default.
+#[derive(Debug, Default, Clone)]
+pub struct OperationLintOptions {
+    /// Whether every operation must have a name.
+    pub require_operation_name: bool,
+    /// Whether operation names must match the name of the file they are defined in.
+    pub operation_name_matches_file_name: bool,
+    /// Whether fragments spread across files must be imported by name
+    /// instead of with a wildcard import.
+    pub require_named_fragment_imports: bool,
+    /// Whether `id` must be selected from types that have an `id` field.
+    pub require_id_selection: bool,
+    /// Maximum depth of selection sets.
+    pub max_selection_depth: Option<usize>,
+    /// Whether aliasing `__typename` is disallowed.
+    pub no_typename_alias: bool,
+    /// Whether key fields must be selected from types listed in `cacheKeys`.
+    pub require_cache_keys: bool,
+}
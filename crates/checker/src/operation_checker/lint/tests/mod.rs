@@ -0,0 +1,305 @@
+use std::{borrow::Cow, collections::HashMap, path::Path};
+
+use super::OperationLintOptions;
+use graphql_builtins::generate_builtins;
+use graphql_type_system::Schema;
+use insta::assert_debug_snapshot;
+use nitrogql_ast::{base::Pos, set_current_file_of_pos};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_semantics::{
+    ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+
+use crate::{CheckError, OperationCheckContext};
+
+use super::lint_operation_document;
+
+fn type_system() -> Schema<Cow<'static, str>, Pos> {
+    let mut doc = parse_type_system_document(
+        "
+        type Query {
+            me: User!
+            user(id: ID!): User
+            search(keyword: String!): [SearchResult!]!
+            product(sku: String!): Product
+        }
+        type Mutation {
+            updateProduct(sku: String!): UpdateProductPayload!
+        }
+        type Product {
+            sku: String!
+            region: String!
+            name: String!
+        }
+        type UpdateProductPayload {
+            product: Product
+        }
+        type User {
+            id: ID!
+            name: String!
+            posts: [Post!]!
+        }
+        type Post {
+            id: ID!
+            title: String!
+            author: User!
+            tags: [Tag!]!
+        }
+        type Tag {
+            name: String!
+        }
+        union SearchResult = User | Post | Product
+        ",
+    )
+    .unwrap();
+    doc.extend(generate_builtins());
+    let doc = resolve_schema_extensions(doc).unwrap();
+    ast_to_type_system(&doc)
+}
+
+fn test_lint(path: &str, source: &str, options: &OperationLintOptions) -> Vec<CheckError> {
+    test_lint_with_cache_keys(path, source, options, &HashMap::new())
+}
+
+fn test_lint_with_cache_keys(
+    path: &str,
+    source: &str,
+    options: &OperationLintOptions,
+    cache_keys: &HashMap<String, Vec<String>>,
+) -> Vec<CheckError> {
+    let schema = type_system();
+    let doc = parse_operation_document(source).unwrap();
+    let (doc, ext) = resolve_operation_extensions(doc).unwrap();
+    let context = OperationCheckContext::new(&schema);
+    lint_operation_document(Path::new(path), &doc, &ext, &context, options, cache_keys)
+}
+
+#[test]
+fn no_rules_enabled() {
+    let errors = test_lint(
+        "query.graphql",
+        "
+        query {
+            me { name posts { ... { title } } }
+            t: __typename
+        }
+        ",
+        &OperationLintOptions::default(),
+    );
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn operation_name() {
+    let options = OperationLintOptions {
+        require_operation_name: true,
+        operation_name_matches_file_name: true,
+        ..OperationLintOptions::default()
+    };
+    let errors = test_lint(
+        "src/get-me.graphql",
+        "
+        query GetMe { me { id } }
+        query getOther { me { id } }
+        mutation { __typename }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn named_fragment_imports() {
+    let options = OperationLintOptions {
+        require_named_fragment_imports: true,
+        ..OperationLintOptions::default()
+    };
+    let schema = type_system();
+    set_current_file_of_pos(1);
+    let user_fragments = parse_operation_document("fragment UserName on User { name }").unwrap();
+    set_current_file_of_pos(2);
+    let post_fragments = parse_operation_document("fragment PostTitle on Post { title }").unwrap();
+    set_current_file_of_pos(0);
+    let doc = parse_operation_document(
+        r#"
+        #import UserName from "./user.graphql"
+        #import * from "./post.graphql"
+        query Q {
+            me {
+                ...UserName
+                posts { ...PostTitle ...LocalPost }
+            }
+        }
+        fragment LocalPost on Post { id }
+        "#,
+    )
+    .unwrap();
+    let (mut doc, ext) = resolve_operation_extensions(doc).unwrap();
+    for fragments in [user_fragments, post_fragments] {
+        let (fragments, _) = resolve_operation_extensions(fragments).unwrap();
+        doc.definitions.extend(fragments.definitions);
+    }
+    let context = OperationCheckContext::new(&schema);
+    let errors = lint_operation_document(
+        Path::new("query.graphql"),
+        &doc,
+        &ext,
+        &context,
+        &options,
+        &HashMap::new(),
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn id_selection() {
+    let options = OperationLintOptions {
+        require_id_selection: true,
+        ..OperationLintOptions::default()
+    };
+    let errors = test_lint(
+        "query.graphql",
+        "
+        query Q {
+            me {
+                name
+                posts {
+                    ...PostId
+                    tags { name }
+                    author { userId: id }
+                }
+            }
+            search(keyword: \"foo\") {
+                ... on User { id }
+                ... on Post { title }
+            }
+        }
+        fragment PostId on Post {
+            id
+        }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn max_selection_depth() {
+    let options = OperationLintOptions {
+        max_selection_depth: Some(3),
+        ..OperationLintOptions::default()
+    };
+    let errors = test_lint(
+        "query.graphql",
+        "
+        query Shallow {
+            me { posts { title } }
+        }
+        query Deep {
+            me { ...F }
+        }
+        fragment F on User {
+            posts { author { name } }
+        }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn typename_alias() {
+    let options = OperationLintOptions {
+        no_typename_alias: true,
+        ..OperationLintOptions::default()
+    };
+    let errors = test_lint(
+        "query.graphql",
+        "
+        query Q {
+            __typename
+            me { type: __typename }
+        }
+        fragment F on User {
+            kind: __typename
+        }
+        ",
+        &options,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn cache_keys() {
+    let options = OperationLintOptions {
+        require_cache_keys: true,
+        ..OperationLintOptions::default()
+    };
+    let cache_keys = HashMap::from([(
+        "Product".to_owned(),
+        vec!["sku".to_owned(), "region".to_owned()],
+    )]);
+    let errors = test_lint_with_cache_keys(
+        "query.graphql",
+        "
+        query Q {
+            me { name }
+            product(sku: \"1\") { sku name }
+            search(keyword: \"foo\") {
+                ... on Product { name }
+                ... on User { name }
+            }
+        }
+        query Complete {
+            product(sku: \"1\") { ...ProductKey name }
+        }
+        mutation M {
+            updateProduct(sku: \"1\") {
+                product { name }
+            }
+        }
+        fragment ProductKey on Product {
+            sku
+            region
+        }
+        ",
+        &options,
+        &cache_keys,
+    );
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn cache_keys_in_fragments_spread_into_mutations() {
+    let options = OperationLintOptions {
+        require_cache_keys: true,
+        ..OperationLintOptions::default()
+    };
+    let cache_keys = HashMap::from([(
+        "Product".to_owned(),
+        vec!["sku".to_owned(), "region".to_owned()],
+    )]);
+    let errors = test_lint_with_cache_keys(
+        "mutation.graphql",
+        "
+        mutation M {
+            updateProduct(sku: \"1\") { ...Payload }
+        }
+        query Q {
+            product(sku: \"1\") { ...ProductName }
+        }
+        fragment Payload on UpdateProductPayload {
+            product { ...ProductName }
+        }
+        fragment ProductName on Product {
+            name
+        }
+        fragment Unused on UpdateProductPayload {
+            product { name }
+        }
+        ",
+        &options,
+        &cache_keys,
+    );
+    assert_debug_snapshot!(errors);
+}
@@ -25,13 +25,20 @@ use super::{
 };
 use nitrogql_semantics::direct_fields_of_output_type;
 
+mod client_fields;
+mod complexity;
 mod context;
 mod count_selection_set_fields;
 mod fragment_map;
+mod lint;
+mod selection_depth;
 #[cfg(test)]
 mod tests;
 
+pub use client_fields::check_client_fields;
+pub use complexity::{check_operation_complexity, ComplexityOptions, FieldCosts};
 pub use context::OperationCheckContext;
+pub use lint::{lint_operation_document, OperationLintOptions};
 
 pub fn check_operation_document<'src, S: Text<'src>>(
     document: &OperationDocument<'src>,
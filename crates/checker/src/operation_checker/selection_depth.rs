@@ -0,0 +1,50 @@
+use nitrogql_ast::selection_set::{Selection, SelectionSet};
+
+use super::fragment_map::FragmentMap;
+
+/// Calculates the depth of given selection set.
+/// Fragments are expanded; they do not count as a level of depth by themselves.
+pub fn selection_set_depth(fragment_map: &FragmentMap, selection_set: &SelectionSet) -> usize {
+    selection_set_depth_impl(fragment_map, selection_set, &[])
+}
+
+fn selection_set_depth_impl(
+    fragment_map: &FragmentMap,
+    selection_set: &SelectionSet,
+    seen_fragments: &[&str],
+) -> usize {
+    let mut depth = 0;
+    for selection in selection_set.selections.iter() {
+        let selection_depth = match selection {
+            Selection::Field(field) => {
+                1 + field.selection_set.as_ref().map_or(0, |selection_set| {
+                    selection_set_depth_impl(fragment_map, selection_set, seen_fragments)
+                })
+            }
+            Selection::FragmentSpread(fragment_spread) => {
+                if seen_fragments.contains(&fragment_spread.fragment_name.name) {
+                    // prevent infinite recursions
+                    continue;
+                }
+                let Some(fragment_def) = fragment_map.get(fragment_spread.fragment_name.name)
+                else {
+                    // This should be handled elsewhere
+                    continue;
+                };
+                let seen_fragments: Vec<_> = seen_fragments
+                    .iter()
+                    .copied()
+                    .chain(vec![fragment_spread.fragment_name.name])
+                    .collect();
+                selection_set_depth_impl(fragment_map, &fragment_def.selection_set, &seen_fragments)
+            }
+            Selection::InlineFragment(inline_fragment) => selection_set_depth_impl(
+                fragment_map,
+                &inline_fragment.selection_set,
+                seen_fragments,
+            ),
+        };
+        depth = depth.max(selection_depth);
+    }
+    depth
+}
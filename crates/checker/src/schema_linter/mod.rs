@@ -0,0 +1,295 @@
+use nitrogql_ast::{
+    base::{HasPos, Ident},
+    directive::Directive,
+    operation::OperationType,
+    r#type::Type,
+    type_system::{
+        ArgumentsDefinition, EnumTypeDefinition, FieldDefinition, InputObjectTypeDefinition,
+        InputValueDefinition, ObjectTypeDefinition, TypeDefinition, TypeSystemDefinition,
+        TypeSystemDocument,
+    },
+};
+use nitrogql_semantics::{generate_definition_map, DefinitionMap};
+
+use super::error::{CheckError, CheckErrorMessage};
+
+mod case;
+mod options;
+#[cfg(test)]
+mod tests;
+
+use case::{is_camel_case, is_pascal_case, is_screaming_case};
+pub use options::SchemaLintOptions;
+
+/// Checks given type system document against lint rules.
+/// Document should be already checked by `check_type_system_document`.
+/// Built-in definitions are not subject to lint rules.
+pub fn lint_type_system_document(
+    document: &TypeSystemDocument,
+    options: &SchemaLintOptions,
+) -> Vec<CheckError> {
+    let definition_map = generate_definition_map(document);
+    let root_type_names = [
+        OperationType::Query,
+        OperationType::Mutation,
+        OperationType::Subscription,
+    ]
+    .into_iter()
+    .filter_map(|op| definition_map.root_type(op))
+    .map(|def| def.name().name)
+    .collect::<Vec<_>>();
+
+    let mut result = vec![];
+
+    for def in document.definitions.iter() {
+        if def.position().builtin {
+            continue;
+        }
+        let TypeSystemDefinition::TypeDefinition(def) = def else {
+            continue;
+        };
+        if options.type_name_pascal_case && !is_pascal_case(def.name().name) {
+            result.push(
+                CheckErrorMessage::TypeNameNotPascalCase {
+                    name: def.name().to_string(),
+                }
+                .with_pos(def.name().position),
+            );
+        }
+        match def {
+            TypeDefinition::Object(def) => {
+                let is_root_type = root_type_names.contains(&def.name.name);
+                lint_fields(&def.fields, is_root_type, options, &mut result);
+                if options.relay_connection {
+                    lint_relay_connection(def, &definition_map, &mut result);
+                }
+            }
+            TypeDefinition::Interface(def) => {
+                lint_fields(&def.fields, false, options, &mut result);
+            }
+            TypeDefinition::Enum(def) => {
+                lint_enum(def, options, &mut result);
+            }
+            TypeDefinition::InputObject(def) => {
+                lint_input_object(def, options, &mut result);
+            }
+            TypeDefinition::Scalar(_) | TypeDefinition::Union(_) => {}
+        }
+    }
+
+    result
+}
+
+fn lint_fields(
+    fields: &[FieldDefinition],
+    is_root_type: bool,
+    options: &SchemaLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    for f in fields.iter() {
+        lint_field_name(&f.name, options, result);
+        if is_root_type && options.require_root_field_description && f.description.is_none() {
+            result.push(
+                CheckErrorMessage::RootFieldWithoutDescription {
+                    name: f.name.to_string(),
+                }
+                .with_pos(f.name.position),
+            );
+        }
+        if options.non_null_list_items {
+            lint_list_items(&f.r#type, result);
+        }
+        if options.require_deprecation_reason {
+            lint_deprecation_reason(&f.directives, result);
+        }
+        if let Some(ref arguments) = f.arguments {
+            lint_arguments_definition(arguments, options, result);
+        }
+    }
+}
+
+fn lint_enum(
+    enum_def: &EnumTypeDefinition,
+    options: &SchemaLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    for v in enum_def.values.iter() {
+        if options.enum_value_screaming_case && !is_screaming_case(v.name.name) {
+            result.push(
+                CheckErrorMessage::EnumValueNotScreamingCase {
+                    name: v.name.to_string(),
+                }
+                .with_pos(v.name.position),
+            );
+        }
+        if options.require_deprecation_reason {
+            lint_deprecation_reason(&v.directives, result);
+        }
+    }
+}
+
+fn lint_input_object(
+    input: &InputObjectTypeDefinition,
+    options: &SchemaLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    if let Some(ref suffix) = options.input_type_suffix {
+        if !input.name.name.ends_with(suffix.as_str()) {
+            result.push(
+                CheckErrorMessage::MissingInputTypeSuffix {
+                    name: input.name.to_string(),
+                    suffix: suffix.clone(),
+                }
+                .with_pos(input.name.position),
+            );
+        }
+    }
+    for f in input.fields.iter() {
+        lint_input_value(f, options, result);
+    }
+}
+
+fn lint_arguments_definition(
+    def: &ArgumentsDefinition,
+    options: &SchemaLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    for v in def.input_values.iter() {
+        lint_input_value(v, options, result);
+    }
+}
+
+fn lint_input_value(
+    v: &InputValueDefinition,
+    options: &SchemaLintOptions,
+    result: &mut Vec<CheckError>,
+) {
+    lint_field_name(&v.name, options, result);
+    if options.non_null_list_items {
+        lint_list_items(&v.r#type, result);
+    }
+    if options.require_deprecation_reason {
+        lint_deprecation_reason(&v.directives, result);
+    }
+}
+
+fn lint_field_name(name: &Ident, options: &SchemaLintOptions, result: &mut Vec<CheckError>) {
+    if options.field_name_camel_case && !is_camel_case(name.name) {
+        result.push(
+            CheckErrorMessage::NameNotCamelCase {
+                name: name.to_string(),
+            }
+            .with_pos(name.position),
+        );
+    }
+}
+
+/// Reports list types whose items are nullable.
+fn lint_list_items(ty: &Type, result: &mut Vec<CheckError>) {
+    match ty {
+        Type::Named(_) => {}
+        Type::NonNull(inner) => lint_list_items(&inner.r#type, result),
+        Type::List(inner) => {
+            if !inner.r#type.is_nonnull() {
+                result.push(
+                    CheckErrorMessage::NullableListItem {
+                        r#type: ty.to_string(),
+                    }
+                    .with_pos(inner.position),
+                );
+            }
+            lint_list_items(&inner.r#type, result);
+        }
+    }
+}
+
+/// Reports applications of `@deprecated` that do not have an explicit reason.
+fn lint_deprecation_reason(directives: &[Directive], result: &mut Vec<CheckError>) {
+    for d in directives.iter() {
+        if d.name.name != "deprecated" {
+            continue;
+        }
+        let reason = d
+            .arguments
+            .iter()
+            .flatten()
+            .find(|(name, _)| name.name == "reason")
+            .and_then(|(_, value)| value.as_string());
+        if reason.is_none_or(|reason| reason.trim().is_empty()) {
+            result.push(CheckErrorMessage::DeprecationWithoutReason.with_pos(d.position));
+        }
+    }
+}
+
+/// Checks that `*Connection` types follow the Relay connection specification.
+/// https://relay.dev/graphql/connections.htm
+fn lint_relay_connection(
+    object: &ObjectTypeDefinition,
+    definitions: &DefinitionMap,
+    result: &mut Vec<CheckError>,
+) {
+    let name = object.name.name;
+    if name == "Connection" || !name.ends_with("Connection") {
+        return;
+    }
+    let edges = find_field(&object.fields, "edges");
+    match edges {
+        Some(edges) if matches!(unwrap_nonnull(&edges.r#type), Type::List(_)) => {
+            let edge_type_name = edges.r#type.unwrapped_type().name.name;
+            if let Some(TypeDefinition::Object(edge_def)) = definitions.types.get(edge_type_name) {
+                for field_name in ["node", "cursor"] {
+                    if find_field(&edge_def.fields, field_name).is_none() {
+                        result.push(
+                            CheckErrorMessage::InvalidRelayEdge {
+                                name: edge_type_name.to_owned(),
+                                field_name: field_name.to_owned(),
+                            }
+                            .with_pos(edge_def.name.position),
+                        );
+                    }
+                }
+            }
+        }
+        _ => {
+            result.push(
+                CheckErrorMessage::InvalidRelayConnection {
+                    name: name.to_owned(),
+                    field_name: "edges".to_owned(),
+                    expected: "[Edge]".to_owned(),
+                }
+                .with_pos(edges.map_or(object.name.position, |edges| *edges.r#type.position())),
+            );
+        }
+    }
+
+    let page_info = find_field(&object.fields, "pageInfo");
+    let page_info_is_valid = page_info.is_some_and(|page_info| {
+        page_info.r#type.is_nonnull() && page_info.r#type.unwrapped_type().name.name == "PageInfo"
+    });
+    if !page_info_is_valid {
+        result.push(
+            CheckErrorMessage::InvalidRelayConnection {
+                name: name.to_owned(),
+                field_name: "pageInfo".to_owned(),
+                expected: "PageInfo!".to_owned(),
+            }
+            .with_pos(page_info.map_or(object.name.position, |page_info| {
+                *page_info.r#type.position()
+            })),
+        );
+    }
+}
+
+fn find_field<'a, 'src>(
+    fields: &'a [FieldDefinition<'src>],
+    name: &str,
+) -> Option<&'a FieldDefinition<'src>> {
+    fields.iter().find(|f| f.name.name == name)
+}
+
+fn unwrap_nonnull<'a, 'src>(ty: &'a Type<'src>) -> &'a Type<'src> {
+    match ty {
+        Type::NonNull(inner) => &inner.r#type,
+        _ => ty,
+    }
+}
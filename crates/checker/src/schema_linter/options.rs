@@ -0,0 +1,22 @@
+/// Lint rules for the schema.
+/// All rules are disabled by default.
+#[derive(Debug, Default, Clone)]
+pub struct SchemaLintOptions {
+    /// Whether type names must be in PascalCase.
+    pub type_name_pascal_case: bool,
+    /// Whether field and argument names must be in camelCase.
+    pub field_name_camel_case: bool,
+    /// Whether enum values must be in SCREAMING_SNAKE_CASE.
+    pub enum_value_screaming_case: bool,
+    /// Suffix that names of input object types must have.
+    pub input_type_suffix: Option<String>,
+    /// Whether fields of root types must have descriptions.
+    pub require_root_field_description: bool,
+    /// Whether items of list types must be non-nullable.
+    pub non_null_list_items: bool,
+    /// Whether every `@deprecated` must specify a reason.
+    pub require_deprecation_reason: bool,
+    /// Whether types named `*Connection` and `*Edge` must follow
+    /// the Relay connection specification.
+    pub relay_connection: bool,
+}
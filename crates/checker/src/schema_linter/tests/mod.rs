@@ -0,0 +1,186 @@
+use super::SchemaLintOptions;
+use graphql_builtins::generate_builtins;
+use insta::assert_debug_snapshot;
+use nitrogql_ast::TypeSystemDocument;
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_semantics::resolve_schema_extensions;
+
+use super::lint_type_system_document;
+
+#[test]
+fn no_rules_enabled() {
+    let doc = parse_to_type_system_document(
+        "
+        type query_root {
+            Foo: [String]
+            bar: Int @deprecated
+        }
+        ",
+    );
+    let errors = lint_type_system_document(&doc, &SchemaLintOptions::default());
+    assert!(errors.is_empty());
+}
+
+#[test]
+fn naming_conventions() {
+    let doc = parse_to_type_system_document(
+        "
+        type Query {
+            user_by_id(user_id: ID!): user
+            UserCount: Int!
+        }
+        type user {
+            id: ID!
+            role: Role!
+        }
+        enum Role {
+            ADMIN
+            normalUser
+            GUEST_2
+        }
+        input UserFilter {
+            nameContains: String
+        }
+        input SearchInput {
+            Keyword: String
+        }
+        ",
+    );
+    let options = SchemaLintOptions {
+        type_name_pascal_case: true,
+        field_name_camel_case: true,
+        enum_value_screaming_case: true,
+        input_type_suffix: Some("Input".to_owned()),
+        ..SchemaLintOptions::default()
+    };
+    let errors = lint_type_system_document(&doc, &options);
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn root_field_description() {
+    let doc = parse_to_type_system_document(
+        r#"
+        schema {
+            query: RootQuery
+        }
+        type RootQuery {
+            "Returns the current user."
+            me: User
+            user(id: ID!): User
+        }
+        type User {
+            id: ID!
+        }
+        "#,
+    );
+    let options = SchemaLintOptions {
+        require_root_field_description: true,
+        ..SchemaLintOptions::default()
+    };
+    let errors = lint_type_system_document(&doc, &options);
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn non_null_list_items() {
+    let doc = parse_to_type_system_document(
+        "
+        type Query {
+            ok: [String!]!
+            nullableItems: [String]!
+            nested(ids: [[ID!]]): [[String!]!]
+        }
+        input Filter {
+            tags: [String]
+        }
+        ",
+    );
+    let options = SchemaLintOptions {
+        non_null_list_items: true,
+        ..SchemaLintOptions::default()
+    };
+    let errors = lint_type_system_document(&doc, &options);
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn deprecation_reason() {
+    let doc = parse_to_type_system_document(
+        r#"
+        type Query {
+            ok: Int @deprecated(reason: "Use 'ok2' instead.")
+            noReason: Int @deprecated
+            emptyReason(arg: Int @deprecated): Int @deprecated(reason: "")
+        }
+        enum Role {
+            ADMIN
+            OLD_ADMIN @deprecated
+        }
+        "#,
+    );
+    let options = SchemaLintOptions {
+        require_deprecation_reason: true,
+        ..SchemaLintOptions::default()
+    };
+    let errors = lint_type_system_document(&doc, &options);
+    assert_debug_snapshot!(errors);
+}
+
+#[test]
+fn relay_connection() {
+    let doc = parse_to_type_system_document(
+        "
+        type Query {
+            users: UserConnection!
+            posts: PostConnection!
+        }
+        type PageInfo {
+            hasNextPage: Boolean!
+            hasPreviousPage: Boolean!
+            startCursor: String
+            endCursor: String
+        }
+        type User {
+            id: ID!
+        }
+        type UserConnection {
+            edges: [UserEdge!]!
+            pageInfo: PageInfo!
+        }
+        type UserEdge {
+            node: User!
+            cursor: String!
+        }
+        type Post {
+            id: ID!
+        }
+        type PostConnection {
+            edges: PostEdge
+            pageInfo: PageInfo
+        }
+        type PostEdge {
+            node: Post!
+        }
+        type CommentConnection {
+            edges: [CommentEdge!]!
+        }
+        type CommentEdge {
+            post: Post!
+            cursor: String!
+        }
+        ",
+    );
+    let options = SchemaLintOptions {
+        relay_connection: true,
+        ..SchemaLintOptions::default()
+    };
+    let errors = lint_type_system_document(&doc, &options);
+    assert_debug_snapshot!(errors);
+}
+
+fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
+    let mut doc = parse_type_system_document(source).unwrap();
+    doc.extend(generate_builtins());
+    resolve_schema_extensions(doc).unwrap()
+}
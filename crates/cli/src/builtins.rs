@@ -7,11 +7,15 @@ use nitrogql_ast::{
     },
     TypeSystemDocument,
 };
+use nitrogql_semantics::CLIENT_DIRECTIVE;
+
+/// Names of nitrogql-specific built-in directives.
+const NITROGQL_DIRECTIVES: [&str; 2] = ["nitrogql_ts_type", "unmask"];
 
 /// Build nitrogql-specific built-in definitions.
 pub fn nitrogql_builtins() -> Vec<TypeSystemDefinitionOrExtension<'static>> {
-    vec![TypeSystemDefinitionOrExtension::DirectiveDefinition(
-        DirectiveDefinition {
+    vec![
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
             directive_keyword: keyword("directive"),
             position: Pos::builtin(),
             name: ident("nitrogql_ts_type"),
@@ -40,8 +44,49 @@ pub fn nitrogql_builtins() -> Vec<TypeSystemDefinitionOrExtension<'static>> {
             }),
             repeatable: None,
             locations: vec![ident("SCALAR")],
-        },
-    )]
+        }),
+        // Opts a fragment spread out of fragment masking.
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
+            directive_keyword: keyword("directive"),
+            position: Pos::builtin(),
+            name: ident("unmask"),
+            description: None,
+            arguments: None,
+            repeatable: None,
+            locations: vec![ident("FRAGMENT_SPREAD")],
+        }),
+    ]
+}
+
+/// Build definitions available when a client schema is used.
+pub fn client_builtins() -> Vec<TypeSystemDefinitionOrExtension<'static>> {
+    vec![
+        // Marks selections resolved on the client.
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(DirectiveDefinition {
+            directive_keyword: keyword("directive"),
+            position: Pos::builtin(),
+            name: ident(CLIENT_DIRECTIVE),
+            description: None,
+            arguments: Some(ArgumentsDefinition {
+                input_values: vec![InputValueDefinition {
+                    description: None,
+                    position: Pos::builtin(),
+                    name: ident("always"),
+                    r#type: Type::Named(NamedType {
+                        name: ident("Boolean"),
+                    }),
+                    default_value: None,
+                    directives: vec![],
+                }],
+            }),
+            repeatable: None,
+            locations: vec![
+                ident("FIELD"),
+                ident("FRAGMENT_SPREAD"),
+                ident("INLINE_FRAGMENT"),
+            ],
+        }),
+    ]
 }
 
 fn ident(name: &str) -> Ident {
@@ -65,8 +110,11 @@ pub fn remove_builtins<'src>(schema: &TypeSystemDocument<'src>) -> TypeSystemDoc
         .iter()
         .cloned()
         .filter_map(|d| match d {
-            TypeSystemDefinition::DirectiveDefinition(def) => (def.name.name != "nitrogql_ts_type")
-                .then_some(TypeSystemDefinition::DirectiveDefinition(def)),
+            TypeSystemDefinition::DirectiveDefinition(def) => {
+                let is_builtin = NITROGQL_DIRECTIVES.contains(&def.name.name)
+                    || (def.name.name == CLIENT_DIRECTIVE && def.position.builtin);
+                (!is_builtin).then_some(TypeSystemDefinition::DirectiveDefinition(def))
+            }
             TypeSystemDefinition::SchemaDefinition(_) => Some(d),
             TypeSystemDefinition::TypeDefinition(def) => {
                 if let TypeDefinition::Scalar(def) = def {
@@ -7,25 +7,32 @@ use std::{
 use itertools::Itertools;
 use log::{debug, info};
 
+use graphql_type_system::Schema;
 use nitrogql_ast::{
-    OperationDocument, OperationDocumentExt, TypeSystemDocument, TypeSystemOrExtensionDocument,
+    base::Pos, OperationDocument, OperationDocumentExt, TypeSystemDocument,
+    TypeSystemOrExtensionDocument,
 };
 use nitrogql_checker::{
-    check_operation_document, check_type_system_document, CheckError, CheckErrorMessage,
-    OperationCheckContext,
+    check_client_fields, check_operation_complexity, check_operation_document,
+    check_type_system_document, lint_operation_document, lint_type_system_document, CheckError,
+    CheckErrorMessage, ComplexityOptions, FieldCosts, OperationCheckContext, OperationLintOptions,
+    SchemaLintOptions,
 };
+use nitrogql_config_file::CheckConfig;
 use nitrogql_error::{PositionedError, Result};
-use nitrogql_plugin::Plugin;
+use nitrogql_plugin::{Plugin, PluginCheckResult};
 use nitrogql_semantics::{
     ast_to_type_system, resolve_operation_extensions, resolve_operation_imports,
     resolve_schema_extensions, OperationExtension, OperationResolver,
 };
 
-use crate::{output::InputFileKind, schema_loader::LoadedSchema};
+use crate::{file_store::FileKind, output::InputFileKind, schema_loader::LoadedSchema};
 
 use super::{error::CliError, CliContext};
 
-pub fn run_check(context: CliContext) -> Result<CliContext> {
+mod tests;
+
+pub async fn run_check(context: CliContext<'_>) -> Result<CliContext<'_>> {
     debug!("Checking");
     match context {
         CliContext::SchemaUnresolved {
@@ -36,11 +43,20 @@ pub fn run_check(context: CliContext) -> Result<CliContext> {
             output,
         } => {
             output.command_run("check".to_owned());
+            let client_schema_files = file_store
+                .iter()
+                .filter(|(_, (_, _, kind))| *kind == FileKind::ClientSchema)
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
             let result = check_impl(CheckImplInput {
                 schema,
                 operations,
                 plugins: &config.plugins,
-            });
+                check_options: &CheckOptions::from_config(&config.config.check),
+                cache_keys: &config.config.cache_keys,
+                client_schema_files: &client_schema_files,
+            })
+            .await;
             match result {
                 CheckImplOutput::Ok { schema, operations } => {
                     info!("Check succeeded");
@@ -66,10 +82,55 @@ pub fn run_check(context: CliContext) -> Result<CliContext> {
     }
 }
 
+/// Options of checks configured by the 'check' section of the config file.
+struct CheckOptions {
+    schema_lint: SchemaLintOptions,
+    operation_lint: OperationLintOptions,
+    complexity: ComplexityOptions,
+}
+
+impl CheckOptions {
+    fn from_config(config: &CheckConfig) -> Self {
+        let schema_lint = &config.schema_lint;
+        let operation_lint = &config.operation_lint;
+        let complexity = &config.complexity;
+        CheckOptions {
+            schema_lint: SchemaLintOptions {
+                type_name_pascal_case: schema_lint.type_name_pascal_case,
+                field_name_camel_case: schema_lint.field_name_camel_case,
+                enum_value_screaming_case: schema_lint.enum_value_screaming_case,
+                input_type_suffix: schema_lint.input_type_suffix.clone(),
+                require_root_field_description: schema_lint.require_root_field_description,
+                non_null_list_items: schema_lint.non_null_list_items,
+                require_deprecation_reason: schema_lint.require_deprecation_reason,
+                relay_connection: schema_lint.relay_connection,
+            },
+            operation_lint: OperationLintOptions {
+                require_operation_name: operation_lint.require_operation_name,
+                operation_name_matches_file_name: operation_lint.operation_name_matches_file_name,
+                require_named_fragment_imports: operation_lint.require_named_fragment_imports,
+                require_id_selection: operation_lint.require_id_selection,
+                max_selection_depth: operation_lint.max_selection_depth,
+                no_typename_alias: operation_lint.no_typename_alias,
+                require_cache_keys: operation_lint.require_cache_keys,
+            },
+            complexity: ComplexityOptions {
+                max_aliases: complexity.max_aliases,
+                max_cost: complexity.max_cost,
+                default_list_size: complexity.default_list_size,
+            },
+        }
+    }
+}
+
 struct CheckImplInput<'src, 'a> {
     pub schema: LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
     pub operations: Vec<(PathBuf, OperationDocumentExt<'src>, usize)>,
     pub plugins: &'a [Plugin<'src>],
+    pub check_options: &'a CheckOptions,
+    pub cache_keys: &'a HashMap<String, Vec<String>>,
+    /// Indices of client schema files in the file store.
+    pub client_schema_files: &'a [usize],
 }
 
 enum CheckImplOutput<'src> {
@@ -87,14 +148,17 @@ enum CheckImplOutput<'src> {
     },
 }
 
-fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
+async fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
     let CheckImplInput {
         schema,
         operations,
         plugins,
+        check_options,
+        cache_keys,
+        client_schema_files,
     } = input;
 
-    let loaded_schema = match resolve_schema(schema, plugins) {
+    let loaded_schema = match resolve_schema(schema, plugins, check_options) {
         Ok(schema) => schema,
         Err(errors) => {
             return CheckImplOutput::Err {
@@ -119,34 +183,137 @@ fn check_impl<'src>(input: CheckImplInput<'src, '_>) -> CheckImplOutput<'src> {
         }
     };
 
-    let context = OperationCheckContext::new(&schema);
-    let errors = operations
-        .iter()
-        .flat_map(|(_, doc, _, file_by_index)| {
-            check_operation_document(doc, &context)
-                .into_iter()
-                .map(move |err| (err, file_by_index))
-        })
-        .collect::<Vec<_>>();
+    // Cost directives are only available in GraphQL schema files.
+    let field_costs = loaded_schema.map_into(FieldCosts::from_document, |_| FieldCosts::default());
 
-    if !errors.is_empty() {
-        CheckImplOutput::Err {
-            errors: errors
-                .into_iter()
-                .map(|(err, _)| (InputFileKind::Operation, err.into()))
-                .collect(),
+    let context = OperationCheckContext::new(&schema);
+    let mut errors = vec![];
+    for (path, doc, ext, _) in operations.iter() {
+        let mut doc_errors = check_operation_document(doc, &context);
+        // Lint rules are applied only to a valid document.
+        if doc_errors.is_empty() {
+            doc_errors = lint_operation_document(
+                path,
+                doc,
+                ext,
+                &context,
+                &check_options.operation_lint,
+                cache_keys,
+            );
+            doc_errors.extend(check_client_fields(doc, &context, client_schema_files));
+            doc_errors.extend(check_operation_complexity(
+                doc,
+                &context,
+                &field_costs,
+                &check_options.complexity,
+            ));
+            doc_errors.extend(check_operation_with_plugins(plugins, &schema, doc).await);
         }
-    } else {
-        CheckImplOutput::Ok {
-            schema: loaded_schema,
-            operations,
+        errors.extend(doc_errors);
+    }
+    if errors.is_empty() {
+        // Plugins transform operations after they are checked.
+        let mut transformed_operations = vec![];
+        for (path, doc, ext, file_by_index) in operations {
+            let (doc, doc_errors) = transform_operation_with_plugins(plugins, &context, doc).await;
+            errors.extend(doc_errors);
+            transformed_operations.push((path, doc, ext, file_by_index));
+        }
+        if errors.is_empty() {
+            return CheckImplOutput::Ok {
+                schema: loaded_schema,
+                operations: transformed_operations,
+            };
+        }
+    }
+    CheckImplOutput::Err {
+        errors: errors
+            .into_iter()
+            .map(|err| (InputFileKind::Operation, err.into()))
+            .collect(),
+    }
+}
+
+/// Checks an operation document with plugins.
+async fn check_operation_with_plugins(
+    plugins: &[Plugin<'_>],
+    schema: &Schema<Cow<'_, str>, Pos>,
+    doc: &OperationDocument<'_>,
+) -> Vec<CheckError> {
+    let mut errors = vec![];
+    for plugin in plugins {
+        errors.extend(plugin_check_errors(
+            plugin.check_operation(schema, doc).await,
+        ));
+    }
+    errors
+}
+
+/// Transforms a checked operation document with plugins in order.
+/// Each transformed document is checked again, because code generation relies on it being valid.
+/// When a plugin fails, the document is left as it was before the plugin.
+async fn transform_operation_with_plugins<'src>(
+    plugins: &[Plugin<'_>],
+    context: &OperationCheckContext<'_, 'src, Cow<'_, str>>,
+    doc: OperationDocument<'src>,
+) -> (OperationDocument<'src>, Vec<CheckError>) {
+    let mut doc = doc;
+    let mut errors = vec![];
+    for plugin in plugins {
+        let transformed = match plugin
+            .transform_operation_document(context.definitions, &doc)
+            .await
+        {
+            Ok(Some(transformed)) => transformed,
+            Ok(None) => continue,
+            Err(message) => {
+                errors.push(CheckError {
+                    position: doc.position,
+                    message: CheckErrorMessage::Plugin { message },
+                    additional_info: vec![],
+                });
+                continue;
+            }
+        };
+        let transform_errors = check_operation_document(&transformed, context);
+        if transform_errors.is_empty() {
+            doc = transformed;
+        } else {
+            errors.extend(transformed_document_errors(
+                plugin,
+                doc.position,
+                transform_errors,
+            ));
         }
     }
+    (doc, errors)
+}
+
+/// Converts errors found in a document transformed by a plugin.
+/// Errors point to the original document, since positions in the transformed one
+/// do not correspond to the source file.
+fn transformed_document_errors(
+    plugin: &Plugin,
+    position: Pos,
+    errors: Vec<CheckError>,
+) -> impl Iterator<Item = CheckError> {
+    let name = plugin.name().to_owned();
+    errors.into_iter().map(move |error| CheckError {
+        position,
+        message: CheckErrorMessage::Plugin {
+            message: format!(
+                "Plugin '{name}' transformed the document into an invalid one: {}",
+                error.message
+            ),
+        },
+        additional_info: error.additional_info,
+    })
 }
 
 fn resolve_schema<'src>(
     schema: LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
     plugins: &[Plugin<'src>],
+    check_options: &CheckOptions,
 ) -> std::result::Result<LoadedSchema<'src, TypeSystemDocument<'src>>, Vec<PositionedError>> {
     match schema {
         LoadedSchema::GraphQL(document) => {
@@ -156,27 +323,16 @@ fn resolve_schema<'src>(
             if errors.is_empty() {
                 // check schema with plugins
                 for plugin in plugins {
-                    errors.extend(
-                        plugin
-                            .check_schema(&resolved)
-                            .errors
-                            .into_iter()
-                            .map(|error| CheckError {
-                                position: error.position,
-                                message: CheckErrorMessage::Plugin {
-                                    message: error.message,
-                                },
-                                additional_info: error
-                                    .additional_info
-                                    .into_iter()
-                                    .map(|(pos, message)| {
-                                        (pos, CheckErrorMessage::Plugin { message })
-                                    })
-                                    .collect(),
-                            }),
-                    );
+                    errors.extend(plugin_check_errors(plugin.check_schema(&resolved)));
                 }
             }
+            // Lint rules are applied only to a valid schema.
+            if errors.is_empty() {
+                errors.extend(lint_type_system_document(
+                    &resolved,
+                    &check_options.schema_lint,
+                ));
+            }
 
             if !errors.is_empty() {
                 return Err(errors.into_iter().map(|err| err.into()).collect());
@@ -187,6 +343,21 @@ fn resolve_schema<'src>(
     }
 }
 
+/// Converts errors reported by a plugin.
+fn plugin_check_errors(result: PluginCheckResult) -> impl Iterator<Item = CheckError> {
+    result.errors.into_iter().map(|error| CheckError {
+        position: error.position,
+        message: CheckErrorMessage::Plugin {
+            message: error.message,
+        },
+        additional_info: error
+            .additional_info
+            .into_iter()
+            .map(|(pos, message)| (pos, CheckErrorMessage::Plugin { message }))
+            .collect(),
+    })
+}
+
 type ResolveOperationsResult<'src> = std::result::Result<
     Vec<(
         PathBuf,
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use std::{borrow::Cow, future::ready};
+
+use futures::executor::block_on;
+use graphql_builtins::generate_builtins;
+use graphql_type_system::Schema;
+use nitrogql_ast::{base::Pos, OperationDocument};
+use nitrogql_checker::OperationCheckContext;
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_plugin::{Plugin, PluginCheckError, PluginCheckResult, PluginFuture, PluginV1Beta};
+use nitrogql_printer::GraphQLPrinter;
+use nitrogql_semantics::{
+    ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+use sourcemap_writer::JustWriter;
+
+use super::{check_operation_with_plugins, transform_operation_with_plugins};
+
+/// Plugin that reports given error and transforms documents into given source.
+#[derive(Debug, Default)]
+struct StubPlugin {
+    check_error: Option<&'static str>,
+    transformed: Option<&'static str>,
+}
+
+impl PluginV1Beta for StubPlugin {
+    fn name(&self) -> &str {
+        "stub"
+    }
+    fn check_operation<'a>(
+        &'a self,
+        _schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument,
+    ) -> PluginFuture<'a, PluginCheckResult> {
+        Box::pin(ready(PluginCheckResult {
+            errors: self
+                .check_error
+                .iter()
+                .map(|message| PluginCheckError {
+                    position: operation.position,
+                    message: message.to_string(),
+                    additional_info: vec![],
+                })
+                .collect(),
+        }))
+    }
+    fn transform_operation_document<'a, 'src>(
+        &'a self,
+        _schema: &'a Schema<Cow<str>, Pos>,
+        _operation: &'a OperationDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<OperationDocument<'src>>, String>> {
+        Box::pin(ready(Ok(self.transformed.map(parse_operation))))
+    }
+}
+
+const SCHEMA: &str = "
+type Query {
+    me: User!
+}
+
+type User {
+    id: ID!
+    name: String!
+}
+";
+
+#[test]
+fn valid_transform() {
+    let plugins = [Plugin::new(Box::new(StubPlugin {
+        transformed: Some("query Me { me { id name } }"),
+        ..StubPlugin::default()
+    }))];
+    let (document, errors) = transform("query Me { me { id } }", &plugins);
+    assert!(errors.is_empty(), "{errors:?}");
+    assert_eq!(document, "query Me {\n  me {\n    id\n    name\n  }\n}\n");
+}
+
+#[test]
+fn transform_into_invalid_document() {
+    let plugins = [Plugin::new(Box::new(StubPlugin {
+        transformed: Some("query Me { me { unknown } }"),
+        ..StubPlugin::default()
+    }))];
+    let (document, errors) = transform("query Me { me { id } }", &plugins);
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].starts_with("Plugin 'stub' transformed the document into an invalid one"),
+        "{}",
+        errors[0]
+    );
+    // The document is left as it was before the plugin.
+    assert_eq!(document, "query Me {\n  me {\n    id\n  }\n}\n");
+}
+
+#[test]
+fn check_operation_error() {
+    let plugins = [
+        Plugin::new(Box::new(StubPlugin {
+            check_error: Some("operation is not allowed"),
+            ..StubPlugin::default()
+        })),
+        Plugin::new(Box::new(StubPlugin::default())),
+    ];
+    let schema = build_schema();
+    let operation = parse_operation("query Me { me { id } }");
+    let errors = block_on(check_operation_with_plugins(&plugins, &schema, &operation));
+    let messages = errors
+        .iter()
+        .map(|error| error.message.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(messages, vec!["operation is not allowed".to_owned()]);
+}
+
+fn build_schema() -> Schema<Cow<'static, str>, Pos> {
+    let mut document = parse_type_system_document(SCHEMA).unwrap();
+    document.extend(generate_builtins());
+    ast_to_type_system(&resolve_schema_extensions(document).unwrap())
+}
+
+fn parse_operation(source: &'static str) -> OperationDocument<'static> {
+    let (document, _) =
+        resolve_operation_extensions(parse_operation_document(source).unwrap()).unwrap();
+    document
+}
+
+/// Transforms given operation with plugins.
+/// Returns the printed document and messages of errors.
+fn transform(source: &'static str, plugins: &[Plugin]) -> (String, Vec<String>) {
+    let schema = build_schema();
+    let context = OperationCheckContext::new(&schema);
+    let operation = parse_operation(source);
+    let (document, errors) = block_on(transform_operation_with_plugins(
+        plugins, &context, operation,
+    ));
+    let mut printed = String::new();
+    document.print_graphql(&mut JustWriter::new(&mut printed));
+    (
+        printed,
+        errors
+            .iter()
+            .map(|error| error.message.to_string())
+            .collect(),
+    )
+}
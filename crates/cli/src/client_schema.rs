@@ -0,0 +1,45 @@
+use nitrogql_ast::{
+    base::{HasPos, Pos},
+    type_system::{TypeDefinition, TypeSystemDefinition},
+    TypeSystemDocument,
+};
+
+/// Removes definitions that come from client schema files.
+/// Fields added to server types by client schema files are removed too.
+/// `client_schema_files` is the list of file indices of client schema files.
+pub fn remove_client_definitions<'src>(
+    schema: &TypeSystemDocument<'src>,
+    client_schema_files: &[usize],
+) -> TypeSystemDocument<'src> {
+    let is_client = |position: &Pos| client_schema_files.contains(&position.file);
+    let definitions = schema
+        .definitions
+        .iter()
+        .filter(|def| !is_client(def.position()))
+        .cloned()
+        .map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(mut def)) => {
+                def.fields.retain(|field| !is_client(&field.name.position));
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def))
+            }
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Interface(mut def)) => {
+                def.fields.retain(|field| !is_client(&field.name.position));
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::Interface(def))
+            }
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Union(mut def)) => {
+                def.members.retain(|member| !is_client(&member.position));
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::Union(def))
+            }
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Enum(mut def)) => {
+                def.values.retain(|value| !is_client(&value.name.position));
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::Enum(def))
+            }
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::InputObject(mut def)) => {
+                def.fields.retain(|field| !is_client(&field.position));
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::InputObject(def))
+            }
+            def => def,
+        })
+        .collect();
+    TypeSystemDocument { definitions }
+}
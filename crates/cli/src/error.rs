@@ -12,16 +12,28 @@ pub enum CliError {
     InvalidCommand(String),
     #[error("Cannot load plugin '{0}'")]
     CannotLoadPlugin(String),
+    #[error("Plugin '{name}' failed: {message}")]
+    PluginFailed { name: String, message: String },
+    #[error("Invalid options for plugin '{name}': {message}")]
+    InvalidPluginOptions { name: String, message: String },
+    #[error("Invalid config file:\n{0}")]
+    InvalidConfig(String),
+    #[error("Project '{0}' is not found in the config file")]
+    UnknownProject(String),
     #[error("Schema file not specified")]
     NoSchemaSpecified,
     #[error("Introspection JSON can only be specified once")]
     IntrospectionOnce,
     #[error("Cannot mix GraphQL and Introspection JSON for schema")]
     MixGraphQLAndIntrospection,
+    #[error("Client schema cannot be used with Introspection JSON")]
+    ClientSchemaWithIntrospection,
     #[error("Option '{option}' is required for the '{command}' command. ")]
     OptionRequired { option: String, command: String },
     #[error("Cannot emit code including runtime to a .d.ts file.")]
     CannotEmitRuntimeToDts,
+    #[error("'emitResultSchemas' requires 'standalone-ts-4.0' mode.")]
+    ResultSchemasRequireStandaloneMode,
     #[error("Failed to calculate source map file name for '{path}'.")]
     FailedToCalculateSourceMapFileName { path: PathBuf },
     #[error("{0}")]
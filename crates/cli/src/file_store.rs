@@ -13,9 +13,18 @@ pub struct FileStore {
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum FileKind {
     Schema,
+    /// Schema file that defines client-only types and fields.
+    ClientSchema,
     Operation,
 }
 
+impl FileKind {
+    /// Checks whether this is a kind of schema file.
+    pub fn is_schema(self) -> bool {
+        matches!(self, FileKind::Schema | FileKind::ClientSchema)
+    }
+}
+
 impl FileStore {
     /// Create a new file store.
     pub fn new() -> Self {
@@ -30,12 +39,12 @@ impl FileStore {
     /// Once the index is issued, it will not change.
     /// After an operation is added, schema cannot be added.
     pub fn add_file(&mut self, path: PathBuf, content: String, kind: FileKind) -> usize {
-        if !self.operation_files.is_empty() && kind == FileKind::Schema {
+        if !self.operation_files.is_empty() && kind.is_schema() {
             panic!("Cannot add schema file after operation file is added");
         }
         let schema_len = self.schema_files.len();
         match kind {
-            FileKind::Schema => {
+            FileKind::Schema | FileKind::ClientSchema => {
                 self.schema_files
                     .push((path, Box::leak(content.into_boxed_str()), kind));
                 schema_len
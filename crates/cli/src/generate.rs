@@ -4,28 +4,37 @@ use std::io::Write;
 use std::path::{Path, PathBuf};
 
 use log::{debug, info};
-use nitrogql_semantics::{ast_to_type_system, type_system_to_ast};
+use nitrogql_ast::TypeSystemDocument;
+use nitrogql_semantics::{
+    add_cache_fields, ast_to_type_system, remove_client_fields, type_system_to_ast,
+};
 
 use crate::builtins::remove_builtins;
+use crate::client_schema::remove_client_definitions;
 use crate::error::CliError;
 use crate::file_store::{FileKind, FileStore};
 use crate::output::{CliOutput, OutputFileKind};
 use crate::schema_loader::LoadedSchema;
 use nitrogql_config_file::{Config, GenerateMode};
-use nitrogql_error::Result;
+use nitrogql_error::{print_positioned_error, PositionedError, Result};
+use nitrogql_plugin::{Plugin, PluginGenerateInput, PluginOutputContent, PluginOutputFile};
 use nitrogql_printer::{
-    print_types_for_operation_document, GraphQLPrinter, OperationTypePrinterOptions,
+    persisted_operations, print_persisted_query_manifest, print_types_for_operation_document,
+    GraphQLPrinter, MocksPrinter, MocksPrinterOptions, OperationTypePrinterOptions,
     ResolverTypePrinter, ResolverTypePrinterOptions, SchemaTypePrinter, SchemaTypePrinterOptions,
+    ValidationSchemaPrinter, ValidationSchemaPrinterOptions,
 };
 use nitrogql_utils::relative_path;
 use sourcemap_writer::{print_source_map_json, JsStringWriter, SourceWriter, SourceWriterBuffers};
 
 use super::{check::run_check, context::CliContext};
 
-pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
+mod tests;
+
+pub async fn run_generate(mut context: CliContext<'_>) -> Result<CliContext<'_>> {
     if let CliContext::SchemaUnresolved { .. } = context {
         // Seems like check is not run
-        context = run_check(context)?;
+        context = run_check(context).await?;
     }
     match context {
         CliContext::SchemaUnresolved { .. } => panic!("Something went wrong"),
@@ -37,6 +46,11 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
             output,
         } => {
             output.command_run("generate".to_owned());
+            let client_schema_files = file_store
+                .iter()
+                .filter(|(_, (_, _, kind))| *kind == FileKind::ClientSchema)
+                .map(|(idx, _)| idx)
+                .collect::<Vec<_>>();
             if config.config.generate.schema_output.is_none()
                 && config.config.generate.schema_module_specifier.is_none()
             {
@@ -61,19 +75,27 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
             {
                 return Err(CliError::CannotEmitRuntimeToDts.into());
             }
+            // Result schemas are printed along with runtime values of operations
+            if config.config.generate.emit_result_schemas
+                && config.config.generate.mode != GenerateMode::StandaloneTS4_0
+            {
+                return Err(CliError::ResultSchemasRequireStandaloneMode.into());
+            }
             if let Some(schema_output) = schema_output.as_ref() {
                 debug!("Processing schema");
                 let file_map = FileMap {
                     file_store,
                     file_indices: file_store
                         .iter()
-                        .map(|(idx, (_, _, kind))| {
-                            if kind == FileKind::Schema {
-                                idx
-                            } else {
-                                usize::MAX
-                            }
-                        })
+                        .map(
+                            |(idx, (_, _, kind))| {
+                                if kind.is_schema() {
+                                    idx
+                                } else {
+                                    usize::MAX
+                                }
+                            },
+                        )
                         .collect(),
                 };
 
@@ -117,29 +139,22 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
 
                 match schema {
                     LoadedSchema::GraphQL(ref schema) => {
-                        // apply plugins
-                        let schema = config.plugins.iter().fold(
-                            remove_builtins(schema),
-                            |schema, plugin| match plugin
-                                .transform_document_for_runtime_server(&schema)
-                            {
-                                Some(next) => next,
-                                None => schema,
-                            },
+                        // Client schema is not served.
+                        let schema = remove_client_definitions(
+                            &remove_builtins(schema),
+                            &client_schema_files,
                         );
+                        // apply plugins
+                        let schema =
+                            transform_document_for_runtime_server(schema, &config.plugins).await?;
                         schema.print_graphql(&mut writer);
                     }
                     LoadedSchema::Introspection(ref schema) => {
                         let schema = type_system_to_ast(schema);
                         let schema = remove_builtins(&schema);
                         // apply plugins
-                        let schema = config.plugins.iter().fold(schema, |schema, plugin| {
-                            match plugin.transform_document_for_runtime_server(&schema) {
-                                Some(next) => next,
-                                None => schema,
-                            }
-                        });
-                        // config.plugins[0].transform_document_for_runtime_server(&schema);
+                        let schema =
+                            transform_document_for_runtime_server(schema, &config.plugins).await?;
                         schema.print_graphql(&mut writer);
                     }
                 }
@@ -165,13 +180,15 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                     file_store,
                     file_indices: file_store
                         .iter()
-                        .map(|(idx, (_, _, kind))| {
-                            if kind == FileKind::Schema {
-                                idx
-                            } else {
-                                usize::MAX
-                            }
-                        })
+                        .map(
+                            |(idx, (_, _, kind))| {
+                                if kind.is_schema() {
+                                    idx
+                                } else {
+                                    usize::MAX
+                                }
+                            },
+                        )
                         .collect(),
                 };
 
@@ -197,11 +214,13 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
 
                 match schema {
                     LoadedSchema::GraphQL(ref schema) => {
-                        printer.print_document(schema, &config.plugins)?;
+                        // Client-only fields are not resolved by the server.
+                        let schema = remove_client_definitions(schema, &client_schema_files);
+                        printer.print_document(&schema, &config.plugins).await?;
                     }
                     LoadedSchema::Introspection(ref schema) => {
                         let ast = type_system_to_ast(schema);
-                        printer.print_document(&ast, &config.plugins)?;
+                        printer.print_document(&ast, &config.plugins).await?;
                     }
                 }
 
@@ -215,17 +234,167 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                 )?;
             }
 
+            if let Some(validation_schema_output) = config
+                .config
+                .generate
+                .validation_schema_output
+                .as_ref()
+                .map(|validation_schema_output| config.root_dir.join(validation_schema_output))
+            {
+                info!("Processing validation schemas");
+                let file_map = FileMap {
+                    file_store,
+                    file_indices: file_store
+                        .iter()
+                        .map(
+                            |(idx, (_, _, kind))| {
+                                if kind.is_schema() {
+                                    idx
+                                } else {
+                                    usize::MAX
+                                }
+                            },
+                        )
+                        .collect(),
+                };
+
+                let mut options = ValidationSchemaPrinterOptions::from_config(&config.config);
+                options.schema_source = config
+                    .config
+                    .generate
+                    .schema_module_specifier
+                    .clone()
+                    .unwrap_or_else(|| {
+                        path_to_ts(relative_path(
+                            validation_schema_output.as_ref(),
+                            schema_output
+                                .as_ref()
+                                .expect("This should be prevented by config validation"),
+                        ))
+                        .to_string_lossy()
+                        .to_string()
+                    });
+                let mut writer = SourceWriter::new();
+                writer.set_file_index_mapper(file_map.file_indices.clone());
+                let mut printer = ValidationSchemaPrinter::new(options, &mut writer);
+
+                match schema {
+                    LoadedSchema::GraphQL(ref schema) => {
+                        printer.print_document(schema);
+                    }
+                    LoadedSchema::Introspection(ref schema) => {
+                        let ast = type_system_to_ast(schema);
+                        printer.print_document(&ast);
+                    }
+                }
+
+                let buffers = writer.into_buffers();
+                write_file_and_sourcemap(
+                    &file_map,
+                    output,
+                    OutputFileKind::ValidationSchema,
+                    &validation_schema_output,
+                    buffers,
+                )?;
+            }
+
+            let mocks_output = config
+                .config
+                .generate
+                .mocks_output
+                .as_ref()
+                .map(|mocks_output| config.root_dir.join(mocks_output));
+            if let Some(ref mocks_output) = mocks_output {
+                info!("Processing mocks");
+                let file_map = FileMap {
+                    file_store,
+                    file_indices: file_store
+                        .iter()
+                        .map(
+                            |(idx, (_, _, kind))| {
+                                if kind.is_schema() {
+                                    idx
+                                } else {
+                                    usize::MAX
+                                }
+                            },
+                        )
+                        .collect(),
+                };
+
+                let mut options = MocksPrinterOptions::from_config(&config.config);
+                options.schema_source = config
+                    .config
+                    .generate
+                    .schema_module_specifier
+                    .clone()
+                    .unwrap_or_else(|| {
+                        path_to_ts(relative_path(
+                            mocks_output.as_ref(),
+                            schema_output
+                                .as_ref()
+                                .expect("This should be prevented by config validation"),
+                        ))
+                        .to_string_lossy()
+                        .to_string()
+                    });
+                let mut writer = SourceWriter::new();
+                writer.set_file_index_mapper(file_map.file_indices.clone());
+                let mut printer = MocksPrinter::new(options, &mut writer);
+
+                let warnings = match schema {
+                    LoadedSchema::GraphQL(ref schema) => printer.print_document(schema),
+                    LoadedSchema::Introspection(ref schema) => {
+                        let ast = type_system_to_ast(schema);
+                        printer.print_document(&ast)
+                    }
+                };
+                for warning in warnings {
+                    let position = warning.position();
+                    let warning = PositionedError::new(warning.into(), Some(position), vec![]);
+                    eprintln!(
+                        "Warning: {}",
+                        print_positioned_error(&warning, file_map.file_store)
+                    );
+                }
+
+                let buffers = writer.into_buffers();
+                write_file_and_sourcemap(
+                    &file_map,
+                    output,
+                    OutputFileKind::Mocks,
+                    mocks_output,
+                    buffers,
+                )?;
+            }
+
             let mapped_schema =
                 schema.map_into(|doc| Cow::Owned(ast_to_type_system(doc)), Cow::Borrowed);
 
-            for (path, doc, _, file_index) in operations.iter() {
+            // Documents are printed with fields required by normalized caches.
+            let documents = operations
+                .iter()
+                .map(|(_, doc, _, _)| {
+                    if config.config.generate.add_cache_fields {
+                        Cow::Owned(add_cache_fields(
+                            doc,
+                            &mapped_schema,
+                            &config.config.cache_keys,
+                        ))
+                    } else {
+                        Cow::Borrowed(doc)
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            for ((path, _, _, file_index), doc) in operations.iter().zip(documents.iter()) {
                 debug!("Processing {}", path.to_string_lossy());
                 let file_map = FileMap {
                     file_store,
                     file_indices: file_store
                         .iter()
                         .map(|(idx, (_, _, kind))| {
-                            if kind == FileKind::Schema {
+                            if kind.is_schema() {
                                 idx
                             } else if idx == *file_index {
                                 file_store.schema_len()
@@ -252,12 +421,14 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                     &config.config,
                     &decl_file_path,
                     schema_output.as_deref(),
+                    mocks_output.as_deref(),
                 );
 
                 print_types_for_operation_document(
                     printer_options,
                     &mapped_schema,
                     doc,
+                    &config.plugins,
                     &mut writer,
                 );
 
@@ -271,6 +442,61 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
                     buffers,
                 )?;
             }
+
+            if !config.plugins.is_empty() {
+                let input = PluginGenerateInput {
+                    schema: &mapped_schema,
+                    operations: operations
+                        .iter()
+                        .zip(documents.iter())
+                        .map(|((path, _, _, _), doc)| (path.as_path(), doc.as_ref()))
+                        .collect(),
+                };
+                // Plugins may map positions in any input file.
+                let file_map = FileMap {
+                    file_store,
+                    file_indices: file_store.iter().map(|(idx, _)| idx).collect(),
+                };
+                for plugin in config.plugins.iter() {
+                    let files = plugin.generate_files(&input).await.map_err(|message| {
+                        CliError::PluginFailed {
+                            name: plugin.name().to_owned(),
+                            message,
+                        }
+                    })?;
+                    write_plugin_files(&config.root_dir, &file_map, output, plugin.name(), files)?;
+                }
+            }
+
+            if let Some(manifest_output) = config
+                .config
+                .generate
+                .persisted_queries
+                .manifest_output
+                .as_ref()
+                .map(|manifest_output| config.root_dir.join(manifest_output))
+            {
+                info!("Emitting persisted query manifest");
+                let persisted_operations = documents
+                    .iter()
+                    .flat_map(|doc| {
+                        // Manifest contains documents sent to the server.
+                        if config.config.client_schema.is_empty() {
+                            persisted_operations(doc)
+                        } else {
+                            persisted_operations(&remove_client_fields(doc))
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let mut buffer = print_persisted_query_manifest(&persisted_operations);
+                buffer.push('\n');
+                write_file_without_sourcemap(
+                    output,
+                    OutputFileKind::PersistedQueryManifest,
+                    &manifest_output,
+                    buffer,
+                )?;
+            }
             eprintln!("'generate' finished");
             Ok(CliContext::SchemaResolved {
                 config,
@@ -283,10 +509,31 @@ pub fn run_generate(mut context: CliContext) -> Result<CliContext> {
     }
 }
 
+/// Applies plugins to the schema served by a GraphQL server.
+async fn transform_document_for_runtime_server<'src>(
+    mut schema: TypeSystemDocument<'src>,
+    plugins: &[Plugin<'_>],
+) -> Result<TypeSystemDocument<'src>> {
+    for plugin in plugins {
+        if let Some(next) = plugin
+            .transform_document_for_runtime_server(&schema)
+            .await
+            .map_err(|message| CliError::PluginFailed {
+                name: plugin.name().to_owned(),
+                message,
+            })?
+        {
+            schema = next;
+        }
+    }
+    Ok(schema)
+}
+
 fn generate_operation_type_printer_options(
     config: &Config,
     decl_file_path: &Path,
     schema_output: Option<&Path>,
+    mocks_output: Option<&Path>,
 ) -> OperationTypePrinterOptions {
     let mut printer_options = OperationTypePrinterOptions::from_config(config);
     printer_options.schema_source = config
@@ -303,6 +550,11 @@ fn generate_operation_type_printer_options(
             .to_string_lossy()
             .to_string()
         });
+    printer_options.mocks_source = mocks_output.map(|mocks_output| {
+        path_to_ts(relative_path(decl_file_path, mocks_output))
+            .to_string_lossy()
+            .to_string()
+    });
     printer_options
 }
 
@@ -387,6 +639,40 @@ fn write_file_and_sourcemap(
     Ok(())
 }
 
+/// Writes files generated by a plugin. Paths are resolved from `root_dir`.
+fn write_plugin_files(
+    root_dir: &Path,
+    file_map: &FileMap,
+    cli_output: &mut CliOutput,
+    plugin_name: &str,
+    files: Vec<PluginOutputFile>,
+) -> Result<()> {
+    for file in files {
+        info!(
+            "Emitting {} from plugin {}",
+            file.path.display(),
+            plugin_name
+        );
+        let output_path = root_dir.join(&file.path);
+        match file.content {
+            PluginOutputContent::Text(buffer) => write_file_without_sourcemap(
+                cli_output,
+                OutputFileKind::PluginOutput,
+                &output_path,
+                buffer,
+            )?,
+            PluginOutputContent::SourceMapped(buffers) => write_file_and_sourcemap(
+                file_map,
+                cli_output,
+                OutputFileKind::PluginOutput,
+                &output_path,
+                buffers,
+            )?,
+        }
+    }
+    Ok(())
+}
+
 fn write_file_without_sourcemap(
     cli_output: &mut CliOutput,
     output_file_kind: OutputFileKind,
@@ -0,0 +1,81 @@
+#![cfg(test)]
+
+use std::{fs, path::PathBuf};
+
+use nitrogql_plugin::{PluginOutputContent, PluginOutputFile};
+use sourcemap_writer::SourceWriterBuffers;
+
+use crate::{
+    file_store::{FileKind, FileStore},
+    output::CliOutput,
+};
+
+use super::{write_plugin_files, FileMap};
+
+#[test]
+fn plugin_files() {
+    let root_dir =
+        std::env::temp_dir().join(format!("nitrogql-cli-plugin-files-{}", std::process::id()));
+    let mut file_store = FileStore::new();
+    let schema_file = file_store.add_file(
+        root_dir.join("schema.graphql"),
+        "type Query { me: String }".to_owned(),
+        FileKind::Schema,
+    );
+    let file_map = FileMap {
+        file_store: &file_store,
+        file_indices: vec![schema_file],
+    };
+    let mut output = CliOutput::new();
+    let files = vec![
+        PluginOutputFile {
+            path: PathBuf::from("generated/plain.txt"),
+            content: PluginOutputContent::Text("plain\n".to_owned()),
+        },
+        PluginOutputFile {
+            path: PathBuf::from("generated/mapped.ts"),
+            content: PluginOutputContent::SourceMapped(SourceWriterBuffers {
+                buffer: "export {};".to_owned(),
+                source_map: "AAAA".to_owned(),
+                names: vec![],
+            }),
+        },
+    ];
+    write_plugin_files(&root_dir, &file_map, &mut output, "test-plugin", files).unwrap();
+
+    let generated = output
+        .generated_files()
+        .iter()
+        .map(|(kind, path)| (kind.to_string(), path.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        generated,
+        vec![
+            (
+                "pluginOutput".to_owned(),
+                root_dir.join("generated/plain.txt")
+            ),
+            (
+                "pluginOutput".to_owned(),
+                root_dir.join("generated/mapped.ts")
+            ),
+            (
+                "pluginOutputSourceMap".to_owned(),
+                root_dir.join("generated/mapped.ts.map")
+            ),
+        ]
+    );
+    assert_eq!(
+        fs::read_to_string(root_dir.join("generated/plain.txt")).unwrap(),
+        "plain\n"
+    );
+    assert_eq!(
+        fs::read_to_string(root_dir.join("generated/mapped.ts")).unwrap(),
+        "export {};\n//# sourceMappingURL=mapped.ts.map\n"
+    );
+    let source_map = fs::read_to_string(root_dir.join("generated/mapped.ts.map")).unwrap();
+    assert!(source_map.contains(r#""mappings":"AAAA""#), "{source_map}");
+    assert!(source_map.contains("schema.graphql"), "{source_map}");
+
+    fs::remove_dir_all(&root_dir).unwrap();
+}
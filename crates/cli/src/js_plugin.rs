@@ -0,0 +1,330 @@
+use std::{borrow::Cow, collections::HashMap, path::Path};
+
+use graphql_type_system::Schema;
+use nitrogql_ast::{base::Pos, OperationDocument, TypeSystemDocument};
+use nitrogql_config_file::run_node;
+use nitrogql_plugin::{
+    apply_resolver_output_types, external_operation_check_result, external_schema_check_result,
+    parse_external_operation_document, parse_external_type_system_document,
+    print_external_type_system_document, print_resolver_output_types, ExternalPluginCheckError,
+    ExternalPluginOutputFile, PluginCheckError, PluginCheckResult, PluginFuture,
+    PluginGenerateInput, PluginOutputFile, PluginPrepareFuture, PluginV1Beta,
+};
+use nitrogql_printer::{ts_types::TSType, GraphQLPrinter, ResolverTypePrinterOptions};
+use serde::Deserialize;
+use sourcemap_writer::JustWriter;
+
+use crate::error::CliError;
+
+/// Plugin implemented as a JavaScript (or TypeScript) module.
+/// The schema is checked by Node.js when the plugin is prepared.
+/// Other hooks are run by Node.js each time they are called.
+#[derive(Debug)]
+pub struct JsPlugin {
+    name: String,
+    /// Module specifier used to import the plugin.
+    module: String,
+    /// Plugin options serialized as JSON.
+    options: String,
+    schema_addition: Option<String>,
+    /// Names of hooks implemented by the plugin.
+    hooks: Vec<String>,
+    /// Schema source given when the plugin is prepared.
+    schema_source: String,
+    /// Errors returned by `checkSchema` when the plugin is prepared.
+    check_errors: Vec<ExternalPluginCheckError>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct JsPluginMetadata {
+    name: String,
+    schema_addition: Option<String>,
+    hooks: Vec<String>,
+}
+
+impl JsPlugin {
+    /// Loads a plugin module. Relative specifiers are resolved from `root_dir`.
+    /// When options are given, the module must export a function that receives them.
+    pub async fn load(
+        specifier: &str,
+        options: Option<&serde_yaml::Value>,
+        root_dir: &Path,
+    ) -> Result<Self, CliError> {
+        let module = if specifier.starts_with("./") || specifier.starts_with("../") {
+            root_dir.join(specifier).display().to_string()
+        } else {
+            specifier.to_owned()
+        };
+        let options =
+            serde_json::to_string(&options).map_err(|err| CliError::InvalidPluginOptions {
+                name: specifier.to_owned(),
+                message: err.to_string(),
+            })?;
+        let result = run_node(&format!(
+            r#"
+{}
+export default {{
+  name: plugin.name,
+  schemaAddition: plugin.schemaAddition ?? null,
+  hooks: Object.keys(plugin).filter((key) => typeof plugin[key] === "function"),
+}};
+"#,
+            import_plugin(&module, &options)
+        ))
+        .await
+        .map_err(|err| CliError::PluginFailed {
+            name: specifier.to_owned(),
+            message: err.to_string(),
+        })?;
+        let metadata: JsPluginMetadata =
+            serde_json::from_str(&result).map_err(|err| CliError::PluginFailed {
+                name: specifier.to_owned(),
+                message: err.to_string(),
+            })?;
+        Ok(JsPlugin {
+            name: metadata.name,
+            module,
+            options,
+            schema_addition: metadata.schema_addition,
+            hooks: metadata.hooks,
+            schema_source: String::new(),
+            check_errors: vec![],
+        })
+    }
+
+    fn implements(&self, hook: &str) -> bool {
+        self.hooks.iter().any(|name| name == hook)
+    }
+
+    /// Runs a hook with given arguments.
+    /// Returns the JSON result of the hook.
+    async fn run_hook(
+        &self,
+        hook: &str,
+        arguments: &[serde_json::Value],
+    ) -> Result<String, String> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        run_node(&format!(
+            r#"
+{}
+export default (await plugin.{hook}({arguments})) ?? null;
+"#,
+            import_plugin(&self.module, &self.options),
+        ))
+        .await
+        .map_err(|err| err.to_string())
+    }
+
+    /// Runs a hook that receives an operation document and the schema.
+    /// Returns the JSON result of the hook.
+    async fn run_operation_hook(
+        &self,
+        hook: &str,
+        operation: &OperationDocument<'_>,
+    ) -> Result<String, String> {
+        let mut document = String::new();
+        operation.print_graphql(&mut JustWriter::new(&mut document));
+        self.run_hook(hook, &[document.into(), self.schema_source.as_str().into()])
+            .await
+    }
+
+    /// Runs a hook that receives the schema and returns a transformed schema.
+    async fn transform_document<'src>(
+        &self,
+        hook: &str,
+        document: &TypeSystemDocument<'src>,
+    ) -> Result<Option<TypeSystemDocument<'src>>, String> {
+        if !self.implements(hook) {
+            return Ok(None);
+        }
+        let result = self
+            .run_hook(
+                hook,
+                &[print_external_type_system_document(document).into()],
+            )
+            .await?;
+        let source: Option<String> =
+            serde_json::from_str(&result).map_err(|err| err.to_string())?;
+        source
+            .map(|source| {
+                parse_external_type_system_document(document, source).map_err(|err| err.to_string())
+            })
+            .transpose()
+    }
+}
+
+impl PluginV1Beta for JsPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn prepare<'a>(&'a mut self, schema_source: &'a str) -> PluginPrepareFuture<'a> {
+        Box::pin(async move {
+            self.schema_source = schema_source.to_owned();
+            if !self.implements("checkSchema") {
+                return Ok(());
+            }
+            let result = self
+                .run_hook("checkSchema", &[schema_source.into()])
+                .await?;
+            let errors: Option<Vec<ExternalPluginCheckError>> =
+                serde_json::from_str(&result).map_err(|err| err.to_string())?;
+            self.check_errors = errors.unwrap_or_default();
+            Ok(())
+        })
+    }
+    fn schema_addition(&self) -> Option<String> {
+        self.schema_addition.clone()
+    }
+    fn check_schema(&self, schema: &TypeSystemDocument) -> PluginCheckResult {
+        external_schema_check_result(schema, &self.check_errors)
+    }
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        _options: &'a ResolverTypePrinterOptions,
+        base: HashMap<&'src str, TSType>,
+    ) -> PluginFuture<'a, Result<HashMap<&'src str, TSType>, String>> {
+        Box::pin(async move {
+            if !self.implements("overrideResolverOutputTypes") {
+                return Ok(base);
+            }
+            let types = serde_json::to_value(print_resolver_output_types(&base))
+                .map_err(|err| err.to_string())?;
+            let result = self
+                .run_hook(
+                    "overrideResolverOutputTypes",
+                    &[print_external_type_system_document(document).into(), types],
+                )
+                .await?;
+            let types: Option<HashMap<String, String>> =
+                serde_json::from_str(&result).map_err(|err| err.to_string())?;
+            Ok(match types {
+                Some(types) => apply_resolver_output_types(&types, base),
+                None => base,
+            })
+        })
+    }
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(self.transform_document("transformDocumentForResolvers", document))
+    }
+    fn transform_document_for_runtime_server<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(self.transform_document("transformDocumentForRuntimeServer", document))
+    }
+    fn check_operation<'a>(
+        &'a self,
+        _schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument,
+    ) -> PluginFuture<'a, PluginCheckResult> {
+        Box::pin(async move {
+            if !self.implements("checkOperation") {
+                return PluginCheckResult::success();
+            }
+            let errors = self
+                .run_operation_hook("checkOperation", operation)
+                .await
+                .and_then(|result| {
+                    serde_json::from_str::<Option<Vec<ExternalPluginCheckError>>>(&result)
+                        .map_err(|err| err.to_string())
+                });
+            match errors {
+                Ok(errors) => {
+                    external_operation_check_result(operation, &errors.unwrap_or_default())
+                }
+                Err(message) => PluginCheckResult {
+                    errors: vec![PluginCheckError {
+                        position: operation.position,
+                        message,
+                        additional_info: vec![],
+                    }],
+                },
+            }
+        })
+    }
+    fn generate_files<'a>(
+        &'a self,
+        input: &'a PluginGenerateInput,
+    ) -> PluginFuture<'a, Result<Vec<PluginOutputFile>, String>> {
+        Box::pin(async move {
+            if !self.implements("generateFiles") {
+                return Ok(vec![]);
+            }
+            let operations = input
+                .operations
+                .iter()
+                .map(|(path, document)| {
+                    let mut source = String::new();
+                    document.print_graphql(&mut JustWriter::new(&mut source));
+                    serde_json::json!({
+                        "path": path.to_string_lossy(),
+                        "document": source,
+                    })
+                })
+                .collect::<Vec<_>>();
+            let input = serde_json::json!({
+                "schema": self.schema_source,
+                "operations": operations,
+            });
+            let result = run_node(&format!(
+                r#"
+{}
+export default (await plugin.generateFiles({input})) ?? [];
+"#,
+                import_plugin(&self.module, &self.options),
+            ))
+            .await
+            .map_err(|err| err.to_string())?;
+            let files: Vec<ExternalPluginOutputFile> =
+                serde_json::from_str(&result).map_err(|err| err.to_string())?;
+            Ok(files.into_iter().map(PluginOutputFile::from).collect())
+        })
+    }
+    fn transform_operation_document<'a, 'src>(
+        &'a self,
+        _schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<OperationDocument<'src>>, String>> {
+        Box::pin(async move {
+            if !self.implements("transformOperationDocument") {
+                return Ok(None);
+            }
+            let result = self
+                .run_operation_hook("transformOperationDocument", operation)
+                .await?;
+            let source: Option<String> =
+                serde_json::from_str(&result).map_err(|err| err.to_string())?;
+            source
+                .map(|source| {
+                    parse_external_operation_document(operation, source)
+                        .map_err(|err| err.to_string())
+                })
+                .transpose()
+        })
+    }
+}
+
+/// Generates code that imports given plugin module as `plugin`.
+/// A plugin exported as a function is called with options to create the plugin.
+fn import_plugin(module: &str, options: &str) -> String {
+    format!(
+        r#"import pluginModule from {};
+const pluginExport = pluginModule?.default ?? pluginModule;
+const options = {options};
+if (options !== null && typeof pluginExport !== "function") {{
+  throw new Error("This plugin does not accept options");
+}}
+const plugin =
+  typeof pluginExport === "function" ? await pluginExport(options) : pluginExport;"#,
+        serde_json::to_string(module).expect("failed to serialize module specifier")
+    )
+}
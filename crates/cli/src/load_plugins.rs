@@ -1,18 +1,80 @@
-use nitrogql_plugin::{GraphQLScalarsPlugin, ModelPlugin, Plugin, PluginV1Beta};
+use std::{fs, path::Path};
 
-use crate::error::CliError;
+use nitrogql_config_file::PluginConfig;
+use nitrogql_plugin::{
+    GraphQLScalarsPlugin, ModelPlugin, Plugin, PluginV1Beta, WasmPlugin, WasmPluginError,
+};
+use serde::de::DeserializeOwned;
+
+use crate::{error::CliError, js_plugin::JsPlugin};
 
 /// Load plugins by name.
-pub fn load_plugins<'host, S: AsRef<str>>(plugins: &[S]) -> Result<Vec<Plugin<'host>>, CliError> {
-    plugins
-        .iter()
-        .map(|plugin| {
-            let p: Result<Box<dyn PluginV1Beta>, _> = match plugin.as_ref() {
-                "nitrogql:model-plugin" => Ok(Box::new(ModelPlugin {})),
-                "nitrogql:graphql-scalars-plugin" => Ok(Box::<GraphQLScalarsPlugin>::default()),
-                _ => Err(CliError::CannotLoadPlugin(plugin.as_ref().to_string())),
-            };
-            p.map(Plugin::new)
-        })
-        .collect()
+/// Names ending with `.wasm` are treated as paths to WebAssembly modules.
+/// Other names are treated as JavaScript modules.
+pub async fn load_plugins<'host>(
+    plugins: &[PluginConfig],
+    root_dir: &Path,
+) -> Result<Vec<Plugin<'host>>, CliError> {
+    let mut result = vec![];
+    for plugin in plugins {
+        let p: Box<dyn PluginV1Beta> = match plugin.name() {
+            "nitrogql:model-plugin" => Box::new(ModelPlugin::new(parse_options(plugin)?)),
+            "nitrogql:graphql-scalars-plugin" => {
+                if plugin.options().is_some() {
+                    return Err(CliError::InvalidPluginOptions {
+                        name: plugin.name().to_owned(),
+                        message: "this plugin does not accept options".to_owned(),
+                    });
+                }
+                Box::<GraphQLScalarsPlugin>::default()
+            }
+            name if name.starts_with("nitrogql:") => {
+                return Err(CliError::CannotLoadPlugin(name.to_owned()))
+            }
+            name if name.ends_with(".wasm") => {
+                Box::new(load_wasm_plugin(name, plugin.options(), root_dir)?)
+            }
+            name => Box::new(JsPlugin::load(name, plugin.options(), root_dir).await?),
+        };
+        result.push(Plugin::new(p));
+    }
+    Ok(result)
+}
+
+/// Parses options of a built-in plugin.
+/// Options default to `T::default()` when not given.
+fn parse_options<T: DeserializeOwned + Default>(plugin: &PluginConfig) -> Result<T, CliError> {
+    let Some(options) = plugin.options() else {
+        return Ok(T::default());
+    };
+    serde_yaml::from_value(options.clone()).map_err(|err| CliError::InvalidPluginOptions {
+        name: plugin.name().to_owned(),
+        message: err.to_string(),
+    })
+}
+
+/// Loads a WebAssembly plugin module. Paths are resolved from `root_dir`.
+fn load_wasm_plugin(
+    path: &str,
+    options: Option<&serde_yaml::Value>,
+    root_dir: &Path,
+) -> Result<WasmPlugin, CliError> {
+    let plugin_failed = |message: String| CliError::PluginFailed {
+        name: path.to_owned(),
+        message,
+    };
+    let invalid_options = |message: String| CliError::InvalidPluginOptions {
+        name: path.to_owned(),
+        message,
+    };
+    let options = options
+        .map(serde_json::to_value)
+        .transpose()
+        .map_err(|err| invalid_options(err.to_string()))?
+        .unwrap_or_default();
+    let binary = fs::read(root_dir.join(path)).map_err(|err| plugin_failed(err.to_string()))?;
+    WasmPlugin::new(&binary, &options).map_err(|err| match err {
+        WasmPluginError::InvalidOptions(message) => invalid_options(message),
+        err => plugin_failed(err.to_string()),
+    })
 }
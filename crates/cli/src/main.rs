@@ -6,7 +6,7 @@ use std::{
 };
 
 use anyhow::Result;
-use builtins::nitrogql_builtins;
+use builtins::{client_builtins, nitrogql_builtins};
 use clap::Parser;
 use context::OutputFormat;
 use file_store::FileStore;
@@ -16,7 +16,10 @@ use graphql_type_system::Schema;
 use itertools::Itertools;
 use log::info;
 use nitrogql_ast::{
-    set_current_file_of_pos, type_system::TypeSystemOrExtensionDocument, OperationDocumentExt,
+    base::Pos,
+    set_current_file_of_pos,
+    type_system::{TypeSystemDefinitionOrExtension, TypeSystemOrExtensionDocument},
+    OperationDocumentExt,
 };
 use nitrogql_introspection::schema_from_introspection_json;
 use nitrogql_plugin::{Plugin, PluginSchemaExtensions};
@@ -32,19 +35,26 @@ use crate::{
     load_plugins::load_plugins,
     schema_loader::{load_schema_js, schema_kind_by_path, LoadSchemaJsResult, SchemaFileKind},
 };
-use nitrogql_config_file::load_config;
+use nitrogql_config_file::{
+    load_config, Config, ConfigDiagnostic, ConfigFileError, LoadedConfig, ProjectConfig,
+    DEFAULT_PROJECT_NAME,
+};
 
 use nitrogql_error::{print_positioned_error, PositionedError};
 use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_printer::GraphQLPrinter;
+use sourcemap_writer::JustWriter;
 
 use self::{check::run_check, context::CliConfig, generate::run_generate};
 
 mod builtins;
 mod check;
+mod client_schema;
 mod context;
 mod error;
 mod file_store;
 mod generate;
+mod js_plugin;
 mod load_plugins;
 mod output;
 mod plugin_host;
@@ -55,6 +65,9 @@ struct Args {
     #[arg(long, short = 'c')]
     /// Path to config file.
     config_file: Option<PathBuf>,
+    #[arg(long, short = 'p')]
+    /// Name of the project to run. All projects are run if omitted.
+    project: Option<String>,
     #[arg(long)]
     /// Path to schema document(s).
     schema: Vec<String>,
@@ -83,23 +96,19 @@ pub async fn run_cli(args: impl IntoIterator<Item = String>) {
         .init()
         .unwrap();
     let mut output = CliOutput::new();
-    let file_store = Box::leak(Box::new(FileStore::new()));
+    // Each project has its own file store.
+    let file_stores: &mut Vec<FileStore> = Box::leak(Box::default());
     let args = Args::parse_from(args);
     let output_format = args.output_format;
-    let res = run_cli_impl(args, file_store, &mut output).await;
+    let res = run_cli_impl(args, file_stores, &mut output).await;
     let code = match res {
         Ok(()) => 0,
         Err(err) => {
             let message = err
                 .inner
                 .into_iter()
-                .map(|e| {
-                    if e.has_position() {
-                        print_positioned_error(&e, file_store)
-                    } else {
-                        format!("{}", e.into_inner())
-                    }
-                })
+                // Errors of projects are already rendered with their files.
+                .map(|e| format!("{}", e.into_inner()))
                 .join("\n");
             output.command_error(err.command, message);
             1
@@ -108,13 +117,13 @@ pub async fn run_cli(args: impl IntoIterator<Item = String>) {
 
     match output_format {
         OutputFormat::Human => {
-            output.human_output(file_store);
+            output.human_output(file_stores);
         }
         OutputFormat::Json => {
-            output.json_output(file_store);
+            output.json_output(file_stores);
         }
         OutputFormat::Rdjson => {
-            output.rdjson_output(file_store);
+            output.rdjson_output(file_stores);
         }
     }
 
@@ -123,37 +132,103 @@ pub async fn run_cli(args: impl IntoIterator<Item = String>) {
 
 async fn run_cli_impl(
     args: Args,
-    file_store: &mut FileStore,
+    file_stores: &mut Vec<FileStore>,
     output: &mut CliOutput,
 ) -> Result<(), CommandError> {
     if args.commands.is_empty() {
         return Err(CliError::NoCommandSpecified.into());
     }
     let cwd = get_cwd()?;
-    let config_file = load_config(&cwd, args.config_file.as_deref()).await?;
-    let (root_dir, mut config) = if let Some((config_path, config_file)) = config_file {
-        info!("Loaded config file from {}", config_path.display());
+    let config_file = match load_config(&cwd, args.config_file.as_deref()).await {
+        Ok(config_file) => config_file,
+        Err(ConfigFileError::Validation {
+            path,
+            content,
+            error,
+        }) => {
+            let message = print_config_diagnostic(&path, &content, &error);
+            return Err(CliError::InvalidConfig(message).into());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let (root_dir, mut projects) = if let Some(config_file) = config_file {
+        let LoadedConfig {
+            path,
+            content,
+            projects,
+            warnings,
+        } = config_file;
+        info!("Loaded config file from {}", path.display());
+        for warning in warnings {
+            eprintln!(
+                "Warning: {}",
+                print_config_diagnostic(&path, &content, &warning)
+            );
+        }
         (
-            normalize_path(config_path.parent().unwrap_or(Path::new(""))),
-            config_file,
+            normalize_path(path.parent().unwrap_or(Path::new(""))),
+            projects,
         )
     } else {
-        (get_cwd()?, Default::default())
+        (
+            get_cwd()?,
+            vec![ProjectConfig {
+                name: DEFAULT_PROJECT_NAME.to_owned(),
+                config: Default::default(),
+            }],
+        )
     };
-    // Override config with args
-    if !args.schema.is_empty() {
-        config.schema = args.schema;
+    if let Some(name) = &args.project {
+        projects.retain(|project| project.name == *name);
+        if projects.is_empty() {
+            return Err(CliError::UnknownProject(name.clone()).into());
+        }
     }
-    if !args.operation.is_empty() {
-        config.operations = args.operation;
+    info!("root_dir {}", root_dir.display());
+
+    let project_count = projects.len();
+    let mut project_errors = vec![];
+    for ProjectConfig { name, mut config } in projects {
+        // Override config with args
+        if !args.schema.is_empty() {
+            config.schema = args.schema.clone();
+        }
+        if !args.operation.is_empty() {
+            config.operations = args.operation.clone();
+        }
+        if let Some(path) = &args.schema_output {
+            config.generate.schema_output = Some(path.clone());
+        }
+        info!("Loaded config of project '{name}' {config:?}");
+        if project_count > 1 {
+            eprintln!("Running project '{name}'");
+        }
+        output.set_project_index(file_stores.len());
+        file_stores.push(FileStore::new());
+        let file_store = file_stores.last_mut().unwrap();
+        if let Err(err) =
+            run_project(&args.commands, root_dir.clone(), config, file_store, output).await
+        {
+            let project_name = (project_count > 1).then_some(name.as_str());
+            project_errors.push(render_project_error(err, file_store, project_name));
+        }
     }
-    if let Some(path) = args.schema_output {
-        config.generate.schema_output = Some(path);
+    if !project_errors.is_empty() {
+        return Err(CommandError::merge(project_errors));
     }
-    info!("Loaded config {config:?}");
-    info!("root_dir {}", root_dir.display());
 
-    let mut plugins = load_plugins(&config.plugins)?;
+    Ok(())
+}
+
+/// Runs commands for one project.
+async fn run_project(
+    commands: &[String],
+    root_dir: PathBuf,
+    config: Config,
+    file_store: &mut FileStore,
+    output: &mut CliOutput,
+) -> Result<(), CommandError> {
+    let mut plugins = load_plugins(&config.plugins, &root_dir).await?;
 
     if config.schema.is_empty() {
         return Err(CliError::NoSchemaSpecified.into());
@@ -206,16 +281,40 @@ async fn run_cli_impl(
         return Err(CommandError::merge(schema_errors));
     }
 
-    let config = CliConfig {
+    // Client schema extends the schema with client-only types and fields.
+    let client_schema_files = load_glob_files(&root_dir, &config.client_schema)?;
+    if !client_schema_files.is_empty()
+        && schema_docs
+            .iter()
+            .any(|doc| matches!(doc, LoadedSchema::Introspection(_)))
+    {
+        return Err(CliError::ClientSchemaWithIntrospection.into());
+    }
+    for (path, buf) in client_schema_files {
+        let file_idx = file_store.add_file(path, buf, FileKind::ClientSchema);
+        let (ref path, buf, _) = file_store.get_file(file_idx).unwrap();
+        info!("parsing(client schema) {}", path.to_string_lossy());
+        set_current_file_of_pos(file_idx);
+        let doc = parse_type_system_document(buf)?;
+        schema_docs.push(LoadedSchema::GraphQL(doc));
+    }
+
+    let mut config = CliConfig {
         root_dir,
         config,
         plugins,
     };
     let merged_schema_doc = {
         let mut merged_schema_doc = resolve_loaded_schema(schema_docs)?;
-        extend_loaded_schema(&mut merged_schema_doc, file_store, &config.plugins)?;
+        extend_loaded_schema(
+            &mut merged_schema_doc,
+            file_store,
+            &config.plugins,
+            !config.config.client_schema.is_empty(),
+        )?;
         merged_schema_doc
     };
+    prepare_plugins(&mut config.plugins, &merged_schema_doc).await?;
 
     let operation_files = load_glob_files(&config.root_dir, &config.config.operations)?;
 
@@ -245,8 +344,9 @@ async fn run_cli_impl(
         output,
     };
 
-    for command in args.commands.iter() {
+    for command in commands {
         context = run_command(command, context)
+            .await
             .map_err(|err| CommandError::new(vec![err], command.clone()))?;
     }
 
@@ -286,13 +386,60 @@ impl<E: Into<PositionedError>> From<E> for CommandError {
     }
 }
 
-fn run_command<'a>(
+/// Renders errors of a project with files of the project,
+/// so that errors of multiple projects can be reported together.
+fn render_project_error(
+    err: CommandError,
+    file_store: &FileStore,
+    project_name: Option<&str>,
+) -> CommandError {
+    let inner = err
+        .inner
+        .into_iter()
+        .map(|e| {
+            let message = if e.has_position() {
+                print_positioned_error(&e, file_store)
+            } else {
+                format!("{}", e.into_inner())
+            };
+            let message = match project_name {
+                Some(name) => format!("project '{name}': {message}"),
+                None => message,
+            };
+            PositionedError::new(anyhow::anyhow!(message), None, vec![])
+        })
+        .collect();
+    CommandError {
+        inner,
+        command: err.command,
+    }
+}
+
+/// Prints an error or a warning about the config file with the relevant part of the file.
+fn print_config_diagnostic(path: &Path, content: &str, diagnostic: &ConfigDiagnostic) -> String {
+    let Some(position) = diagnostic.position else {
+        return format!("{}: {}", path.display(), diagnostic.message);
+    };
+    let error = PositionedError::new(
+        anyhow::anyhow!(diagnostic.message.clone()),
+        Some(Pos {
+            line: position.line,
+            column: position.column,
+            file: 0,
+            builtin: false,
+        }),
+        vec![],
+    );
+    print_positioned_error(&error, &[(path.to_owned(), content, ())])
+}
+
+async fn run_command<'a>(
     command: &str,
     context: CliContext<'a>,
 ) -> nitrogql_error::Result<CliContext<'a>> {
     match command {
-        "check" => run_check(context),
-        "generate" => run_generate(context),
+        "check" => run_check(context).await,
+        "generate" => run_generate(context).await,
         command => Err(CliError::UnknownCommand(command.to_owned()).into()),
     }
 }
@@ -350,10 +497,12 @@ fn resolve_loaded_schema<'src>(
 }
 
 /// Extend loaded schema with builtins and plugins.
+/// `@client` is added when a client schema is used.
 fn extend_loaded_schema<'src>(
     schema: &mut LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
     file_store: &mut FileStore,
     plugins: &[Plugin<'src>],
+    client_schema: bool,
 ) -> Result<(), CommandError> {
     let mut plugin_host = PluginHost::new(file_store);
     match schema {
@@ -361,6 +510,9 @@ fn extend_loaded_schema<'src>(
         LoadedSchema::GraphQL(doc) => {
             doc.extend(generate_builtins());
             doc.extend(nitrogql_builtins());
+            if client_schema {
+                doc.extend(client_builtins());
+            }
             for plugin in plugins {
                 if let Some(addition) = plugin.schema_addition(&mut plugin_host)? {
                     doc.extend(addition.definitions);
@@ -371,6 +523,53 @@ fn extend_loaded_schema<'src>(
     }
 }
 
+/// Prepare plugins with the whole schema.
+async fn prepare_plugins<'src>(
+    plugins: &mut [Plugin<'src>],
+    schema: &LoadedSchema<'src, TypeSystemOrExtensionDocument<'src>>,
+) -> Result<(), CliError> {
+    if plugins.is_empty() {
+        return Ok(());
+    }
+    let mut schema_source = String::new();
+    let mut writer = JustWriter::new(&mut schema_source);
+    match schema {
+        LoadedSchema::GraphQL(doc) => {
+            // Built-in definitions are known to every GraphQL implementation.
+            let doc = TypeSystemOrExtensionDocument {
+                definitions: doc
+                    .definitions
+                    .iter()
+                    .filter(|def| !is_builtin_definition(def))
+                    .cloned()
+                    .collect(),
+            };
+            doc.print_graphql(&mut writer);
+        }
+        LoadedSchema::Introspection(schema) => schema.print_graphql(&mut writer),
+    }
+    for plugin in plugins.iter_mut() {
+        plugin
+            .prepare(&schema_source)
+            .await
+            .map_err(|message| CliError::PluginFailed {
+                name: plugin.name().to_owned(),
+                message,
+            })?;
+    }
+    Ok(())
+}
+
+fn is_builtin_definition(def: &TypeSystemDefinitionOrExtension) -> bool {
+    match def {
+        TypeSystemDefinitionOrExtension::SchemaDefinition(def) => def.position.builtin,
+        TypeSystemDefinitionOrExtension::TypeDefinition(def) => def.name().position.builtin,
+        TypeSystemDefinitionOrExtension::DirectiveDefinition(def) => def.position.builtin,
+        TypeSystemDefinitionOrExtension::SchemaExtension(_)
+        | TypeSystemDefinitionOrExtension::TypeExtension(_) => false,
+    }
+}
+
 /// Allocate a string buffer of given size.
 ///
 /// # Safety
@@ -23,10 +23,17 @@ pub enum OutputFileKind {
     SchemaTypeDefinitionSourceMap,
     ResolversTypeDefinition,
     ResolversTypeDefinitionSourceMap,
+    ValidationSchema,
+    ValidationSchemaSourceMap,
+    Mocks,
+    MocksSourceMap,
     OperationTypeDefinition,
     OperationTypeDefinitionSourceMap,
     GraphqlSource,
     GraphqlSourceSourceMap,
+    PersistedQueryManifest,
+    PluginOutput,
+    PluginOutputSourceMap,
 }
 
 impl OutputFileKind {
@@ -44,6 +51,10 @@ impl OutputFileKind {
             OutputFileKind::ResolversTypeDefinitionSourceMap => {
                 OutputFileKind::ResolversTypeDefinitionSourceMap
             }
+            OutputFileKind::ValidationSchema => OutputFileKind::ValidationSchemaSourceMap,
+            OutputFileKind::ValidationSchemaSourceMap => OutputFileKind::ValidationSchemaSourceMap,
+            OutputFileKind::Mocks => OutputFileKind::MocksSourceMap,
+            OutputFileKind::MocksSourceMap => OutputFileKind::MocksSourceMap,
             OutputFileKind::OperationTypeDefinition => {
                 OutputFileKind::OperationTypeDefinitionSourceMap
             }
@@ -52,6 +63,9 @@ impl OutputFileKind {
             }
             OutputFileKind::GraphqlSource => OutputFileKind::GraphqlSourceSourceMap,
             OutputFileKind::GraphqlSourceSourceMap => OutputFileKind::GraphqlSourceSourceMap,
+            OutputFileKind::PersistedQueryManifest => OutputFileKind::PersistedQueryManifest,
+            OutputFileKind::PluginOutput => OutputFileKind::PluginOutputSourceMap,
+            OutputFileKind::PluginOutputSourceMap => OutputFileKind::PluginOutputSourceMap,
         }
     }
 }
@@ -67,12 +81,19 @@ impl Display for OutputFileKind {
             OutputFileKind::ResolversTypeDefinitionSourceMap => {
                 write!(f, "resolversTypeDefinitionSourceMap")
             }
+            OutputFileKind::ValidationSchema => write!(f, "validationSchema"),
+            OutputFileKind::ValidationSchemaSourceMap => write!(f, "validationSchemaSourceMap"),
+            OutputFileKind::Mocks => write!(f, "mocks"),
+            OutputFileKind::MocksSourceMap => write!(f, "mocksSourceMap"),
             OutputFileKind::OperationTypeDefinition => write!(f, "operationTypeDefinition"),
             OutputFileKind::OperationTypeDefinitionSourceMap => {
                 write!(f, "operationTypeDefinitionSourceMap")
             }
             OutputFileKind::GraphqlSource => write!(f, "graphqlSource"),
             OutputFileKind::GraphqlSourceSourceMap => write!(f, "graphqlSourceSourceMap"),
+            OutputFileKind::PersistedQueryManifest => write!(f, "persistedQueryManifest"),
+            OutputFileKind::PluginOutput => write!(f, "pluginOutput"),
+            OutputFileKind::PluginOutputSourceMap => write!(f, "pluginOutputSourceMap"),
         }
     }
 }
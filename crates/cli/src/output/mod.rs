@@ -11,9 +11,12 @@ use crate::file_store::FileStore;
 
 /// Struct that keeps track of all outputs.
 pub struct CliOutput {
+    /// Index of the project currently being run.
+    project_index: usize,
     commands_run: Vec<String>,
     command_error: Option<(Option<String>, String)>,
-    check_errors: Vec<(file_kind::InputFileKind, PositionedError)>,
+    /// Check errors with the index of the project they belong to.
+    check_errors: Vec<(usize, file_kind::InputFileKind, PositionedError)>,
     generated_files: Vec<(file_kind::OutputFileKind, PathBuf)>,
 }
 
@@ -21,6 +24,7 @@ impl CliOutput {
     /// Create an empty set.
     pub fn new() -> Self {
         Self {
+            project_index: 0,
             commands_run: Vec::new(),
             command_error: None,
             check_errors: Vec::new(),
@@ -28,6 +32,12 @@ impl CliOutput {
         }
     }
 
+    /// Sets the index of the project whose outputs are added next.
+    /// Positions in errors of each project refer to the file store of that project.
+    pub fn set_project_index(&mut self, project_index: usize) {
+        self.project_index = project_index;
+    }
+
     /// Indicates that a command is run.
     pub fn command_run(&mut self, command_name: String) {
         self.commands_run.push(command_name);
@@ -43,13 +53,19 @@ impl CliOutput {
         self.generated_files.push((kind, path));
     }
 
+    /// Files generated so far.
+    #[cfg(test)]
+    pub fn generated_files(&self) -> &[(OutputFileKind, PathBuf)] {
+        &self.generated_files
+    }
+
     /// Output for human consumption.
-    pub fn human_output(self, file_store: &FileStore) {
+    pub fn human_output(self, file_stores: &[FileStore]) {
         if !self.check_errors.is_empty() {
             let (schema_errors, operation_errors): (Vec<_>, Vec<_>) = self
                 .check_errors
                 .into_iter()
-                .partition(|(kind, _)| match kind {
+                .partition(|(_, kind, _)| match kind {
                     InputFileKind::Schema => true,
                     InputFileKind::Operation => false,
                 });
@@ -59,8 +75,11 @@ impl CliOutput {
                     schema_errors.len(),
                     if schema_errors.len() > 1 { "s" } else { "" }
                 );
-                for (_, error) in schema_errors {
-                    eprintln!("{}", print_positioned_error(&error, file_store));
+                for (project_index, _, error) in schema_errors {
+                    eprintln!(
+                        "{}",
+                        print_positioned_error(&error, &file_stores[project_index])
+                    );
                 }
                 eprintln!();
             }
@@ -70,8 +89,11 @@ impl CliOutput {
                     operation_errors.len(),
                     if operation_errors.len() > 1 { "s" } else { "" }
                 );
-                for (_, error) in operation_errors {
-                    eprintln!("{}", print_positioned_error(&error, file_store));
+                for (project_index, _, error) in operation_errors {
+                    eprintln!(
+                        "{}",
+                        print_positioned_error(&error, &file_stores[project_index])
+                    );
                 }
                 eprintln!();
             }
@@ -85,7 +107,7 @@ impl CliOutput {
     }
 
     /// Output with JSON format.
-    pub fn json_output(self, file_store: &FileStore) {
+    pub fn json_output(self, file_stores: &[FileStore]) {
         let mut buffer = String::new();
         let mut writer = JSONObjectWriter::new(&mut buffer);
         if let Some((command, message)) = self.command_error {
@@ -96,10 +118,10 @@ impl CliOutput {
         if self.commands_run.iter().any(|c| c == "check") {
             let mut obj = writer.object("check");
             let mut errors = obj.array("errors");
-            for (kind, error) in self.check_errors {
+            for (project_index, kind, error) in self.check_errors {
                 let position = error.position().unwrap_or_default();
                 let file = (!position.builtin)
-                    .then(|| file_store.get_file(position.file))
+                    .then(|| file_stores[project_index].get_file(position.file))
                     .flatten();
                 let mut obj = errors.object();
                 obj.value("fileType", &kind.to_string());
@@ -129,7 +151,7 @@ impl CliOutput {
     }
 
     /// Output in rdjson format.
-    pub fn rdjson_output(self, file_store: &FileStore) {
+    pub fn rdjson_output(self, file_stores: &[FileStore]) {
         let mut buffer = String::new();
         let mut writer = JSONObjectWriter::new(&mut buffer);
         {
@@ -140,14 +162,14 @@ impl CliOutput {
         writer.value("severity", "ERROR");
         {
             let mut diagnostics = writer.array("diagnostics");
-            for (_, error) in self.check_errors {
+            for (project_index, _, error) in self.check_errors {
                 let mut obj = diagnostics.object();
                 let position = error.position().unwrap_or_default();
                 obj.value("message", &error.into_inner().to_string());
                 {
                     let mut location = obj.object("location");
                     let file = (!position.builtin)
-                        .then(|| file_store.get_file(position.file))
+                        .then(|| file_stores[project_index].get_file(position.file))
                         .flatten();
                     if let Some((path, _, _)) = file {
                         location.value("path", &path.to_string_lossy());
@@ -166,6 +188,10 @@ impl CliOutput {
 
 impl Extend<(InputFileKind, PositionedError)> for CliOutput {
     fn extend<T: IntoIterator<Item = (InputFileKind, PositionedError)>>(&mut self, iter: T) {
-        self.check_errors.extend(iter);
+        let project_index = self.project_index;
+        self.check_errors.extend(
+            iter.into_iter()
+                .map(|(kind, error)| (project_index, kind, error)),
+        );
     }
 }
@@ -2,7 +2,11 @@ use std::{collections::HashMap, path::PathBuf, str::FromStr};
 
 use serde::Deserialize;
 
-use crate::{parsing_utils::deserialize_fromstr, scalar_type::ScalarTypeConfig};
+use crate::{
+    parsing_utils::{deserialize_fromstr, deserialize_fromstr_option},
+    plugin_config::PluginConfig,
+    scalar_type::{ScalarSchemaConfig, ScalarTypeConfig},
+};
 
 #[derive(Debug, Default)]
 pub struct Config {
@@ -11,9 +15,26 @@ pub struct Config {
     /// Path(s) to operation definition files.
     pub operations: Vec<String>,
     // extensions
+    /// Path(s) to client schema files that extend the schema with client-only fields.
+    pub client_schema: Vec<String>,
     /// List of plugins.
-    pub plugins: Vec<String>,
+    pub plugins: Vec<PluginConfig>,
+    /// Key fields of each type used by normalized caches.
+    /// Types not listed here are keyed by `id` if they have the field.
+    pub cache_keys: HashMap<String, Vec<String>>,
     pub generate: GenerateConfig,
+    pub check: CheckConfig,
+}
+
+/// Name of the project for a config file without `projects`.
+pub const DEFAULT_PROJECT_NAME: &str = "default";
+
+/// Config of one project in a config file.
+#[derive(Debug)]
+pub struct ProjectConfig {
+    /// Name of the project.
+    pub name: String,
+    pub config: Config,
 }
 
 /// Config related to the 'generate' command.
@@ -29,6 +50,15 @@ pub struct GenerateConfig {
     pub server_graphql_output: Option<PathBuf>,
     /// Output file path for resolvers.
     pub resolvers_output: Option<PathBuf>,
+    /// Config related to generated resolver types.
+    pub resolvers: GenerateResolversConfig,
+    /// Output file path for validation schemas of input types.
+    pub validation_schema_output: Option<PathBuf>,
+    /// Library used by generated validation schemas.
+    #[serde(deserialize_with = "deserialize_fromstr")]
+    pub validation_schema_library: ValidationSchemaLibrary,
+    /// Output file path for mock data factories.
+    pub mocks_output: Option<PathBuf>,
     /// Module specifier for import schema types from operations.
     /// Defaults to relative paths.
     pub schema_module_specifier: Option<String>,
@@ -40,6 +70,20 @@ pub struct GenerateConfig {
     pub export: GenerateExportConfig,
     /// Whether to emit runtime for generated schema types.
     pub emit_schema_runtime: bool,
+    /// How enums are emitted in the schema file.
+    /// Effective only when schema runtime is emitted.
+    #[serde(deserialize_with = "deserialize_fromstr_option")]
+    pub enum_style: Option<EnumStyle>,
+    /// Whether to emit arrays of values of each enum.
+    /// Effective only when schema runtime is emitted.
+    pub emit_enum_values: bool,
+    /// Whether to emit runtime validation schemas of operation results.
+    /// Effective only when operations are generated with runtime values.
+    pub emit_result_schemas: bool,
+    /// Whether to add `__typename` and key fields to selection sets of operations.
+    pub add_cache_fields: bool,
+    /// Config related to persisted queries.
+    pub persisted_queries: GeneratePersistedQueriesConfig,
 }
 
 /// Mode of code generation.
@@ -54,7 +98,10 @@ pub enum GenerateMode {
     StandaloneTS4_0,
 }
 
-pub struct FromStrError;
+/// Error for a string that is not one of the accepted values.
+pub struct FromStrError {
+    pub expected: &'static [&'static str],
+}
 
 impl FromStr for GenerateMode {
     type Err = FromStrError;
@@ -63,7 +110,94 @@ impl FromStr for GenerateMode {
             "with-loader-ts-5.0" => Ok(GenerateMode::WithLoaderTS5_0),
             "with-loader-ts-4.0" => Ok(GenerateMode::WithLoaderTS4_0),
             "standalone-ts-4.0" => Ok(GenerateMode::StandaloneTS4_0),
-            _ => Err(FromStrError),
+            _ => Err(FromStrError {
+                expected: &[
+                    "with-loader-ts-5.0",
+                    "with-loader-ts-4.0",
+                    "standalone-ts-4.0",
+                ],
+            }),
+        }
+    }
+}
+
+/// How enums are emitted in the schema file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum EnumStyle {
+    /// Emit a union of string literal types only.
+    Union,
+    /// Emit a union type and a const object that has the same name.
+    #[default]
+    ConstObject,
+    /// Emit a TypeScript string enum.
+    TsEnum,
+}
+
+impl FromStr for EnumStyle {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "union" => Ok(EnumStyle::Union),
+            "const-object" => Ok(EnumStyle::ConstObject),
+            "ts-enum" => Ok(EnumStyle::TsEnum),
+            _ => Err(FromStrError {
+                expected: &["union", "const-object", "ts-enum"],
+            }),
+        }
+    }
+}
+
+/// Library used by generated validation schemas.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum ValidationSchemaLibrary {
+    #[default]
+    Zod,
+    Valibot,
+}
+
+impl FromStr for ValidationSchemaLibrary {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "zod" => Ok(ValidationSchemaLibrary::Zod),
+            "valibot" => Ok(ValidationSchemaLibrary::Valibot),
+            _ => Err(FromStrError {
+                expected: &["zod", "valibot"],
+            }),
+        }
+    }
+}
+
+/// Config related to persisted queries.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GeneratePersistedQueriesConfig {
+    /// Output file path for the manifest that maps document hashes to documents.
+    pub manifest_output: Option<PathBuf>,
+    /// What generated operation modules embed as the runtime value of operations.
+    #[serde(deserialize_with = "deserialize_fromstr")]
+    pub document_mode: DocumentMode,
+}
+
+/// What generated operation modules embed as the runtime value of operations.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum DocumentMode {
+    /// Embed the full document AST.
+    #[default]
+    Full,
+    /// Embed only the document hash and metadata of the operation.
+    PersistedOnly,
+}
+
+impl FromStr for DocumentMode {
+    type Err = FromStrError;
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "full" => Ok(DocumentMode::Full),
+            "persisted-only" => Ok(DocumentMode::PersistedOnly),
+            _ => Err(FromStrError {
+                expected: &["full", "persisted-only"],
+            }),
         }
     }
 }
@@ -74,20 +208,53 @@ impl FromStr for GenerateMode {
 pub struct GenerateTypeConfig {
     /// Type of scalars.
     pub scalar_types: HashMap<String, ScalarTypeConfig>,
+    /// Validation schema of scalars. Provided as raw TypeScript code.
+    pub scalar_schemas: HashMap<String, ScalarSchemaConfig>,
+    /// Mock value of scalars. Provided as raw TypeScript code.
+    pub scalar_mocks: HashMap<String, String>,
     /// Whether to allow undefined as input value
     /// for nullable input fields.
     pub allow_undefined_as_optional_input: bool,
+    /// Whether fragment spreads are masked in generated result types.
+    pub fragment_masking: bool,
+    /// Whether fields and lists in operation results are readonly.
+    pub readonly_output: bool,
 }
 
 impl Default for GenerateTypeConfig {
     fn default() -> Self {
         Self {
             scalar_types: HashMap::new(),
+            scalar_schemas: HashMap::new(),
+            scalar_mocks: HashMap::new(),
             allow_undefined_as_optional_input: true,
+            fragment_masking: false,
+            readonly_output: false,
         }
     }
 }
 
+/// Config related to generated resolver types.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct GenerateResolversConfig {
+    /// Name of the root resolvers type.
+    pub resolvers_type_name: Option<String>,
+    /// Name of the resolver output type.
+    pub resolver_output_type_name: Option<String>,
+    /// Default type of context.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub context_type: Option<String>,
+    /// Parent type of resolvers for each GraphQL type.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub mappers: HashMap<String, String>,
+    /// Whether field resolvers are optional when the parent type has the same property.
+    pub optional_field_resolvers: bool,
+    /// Payload type of each subscription field.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub subscription_payloads: HashMap<String, String>,
+}
+
 /// Config related to names of generated variables and types.
 #[derive(Debug, Default, Deserialize)]
 #[serde(default, rename_all = "camelCase")]
@@ -132,3 +299,86 @@ impl Default for GenerateExportConfig {
         }
     }
 }
+
+/// Config related to the 'check' command.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct CheckConfig {
+    /// Lint rules applied to the schema.
+    pub schema_lint: SchemaLintConfig,
+    /// Lint rules applied to operations.
+    pub operation_lint: OperationLintConfig,
+    /// Complexity limits applied to operations.
+    pub complexity: ComplexityConfig,
+}
+
+/// Lint rules for the schema.
+/// All rules are disabled by default.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SchemaLintConfig {
+    /// Whether type names must be in PascalCase.
+    pub type_name_pascal_case: bool,
+    /// Whether field and argument names must be in camelCase.
+    pub field_name_camel_case: bool,
+    /// Whether enum values must be in SCREAMING_SNAKE_CASE.
+    pub enum_value_screaming_case: bool,
+    /// Suffix that names of input object types must have.
+    pub input_type_suffix: Option<String>,
+    /// Whether fields of root types must have descriptions.
+    pub require_root_field_description: bool,
+    /// Whether items of list types must be non-nullable.
+    pub non_null_list_items: bool,
+    /// Whether every `@deprecated` must specify a reason.
+    pub require_deprecation_reason: bool,
+    /// Whether types named `*Connection` and `*Edge` must follow
+    /// the Relay connection specification.
+    pub relay_connection: bool,
+}
+
+/// Lint rules for operations.
+/// All rules are disabled by default.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct OperationLintConfig {
+    /// Whether every operation must have a name.
+    pub require_operation_name: bool,
+    /// Whether operation names must match the name of the file they are defined in.
+    pub operation_name_matches_file_name: bool,
+    /// Whether fragments spread across files must be imported by name
+    /// instead of with a wildcard import.
+    pub require_named_fragment_imports: bool,
+    /// Whether `id` must be selected from types that have an `id` field.
+    pub require_id_selection: bool,
+    /// Maximum depth of selection sets.
+    pub max_selection_depth: Option<usize>,
+    /// Whether aliasing `__typename` is disallowed.
+    pub no_typename_alias: bool,
+    /// Whether key fields must be selected from types listed in `cacheKeys`.
+    pub require_cache_keys: bool,
+}
+
+/// Complexity limits for operations.
+/// Limits are not enforced by default.
+/// Depth of operations is limited by `OperationLintConfig::max_selection_depth`.
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ComplexityConfig {
+    /// Maximum number of aliases in an operation.
+    pub max_aliases: Option<usize>,
+    /// Maximum cost of an operation.
+    pub max_cost: Option<u64>,
+    /// Number of items assumed for list fields
+    /// that are not limited by `first` or `last` argument.
+    pub default_list_size: u64,
+}
+
+impl Default for ComplexityConfig {
+    fn default() -> Self {
+        Self {
+            max_aliases: None,
+            max_cost: None,
+            default_list_size: 1,
+        }
+    }
+}
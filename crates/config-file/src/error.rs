@@ -8,6 +8,54 @@ pub enum ConfigFileError {
     IO(#[from] io::Error),
     #[error("Error loading config file: {0}")]
     Yaml(#[from] serde_yaml::Error),
-    #[error("Cannot load config file '{0}': validation error")]
-    Validation(PathBuf),
+    #[error("Cannot load config file '{}': {error}", path.display())]
+    Validation {
+        path: PathBuf,
+        /// Contents of the config file.
+        content: String,
+        error: ConfigDiagnostic,
+    },
+}
+
+/// Error or warning about contents of a config file.
+#[derive(Error, Debug)]
+#[error("{message}")]
+pub struct ConfigDiagnostic {
+    pub message: String,
+    /// Position in the config file, if known.
+    pub position: Option<ConfigPos>,
+}
+
+/// Position in a config file.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConfigPos {
+    /// 0-based line
+    pub line: usize,
+    /// 0-based column
+    pub column: usize,
+}
+
+impl From<serde_yaml::Error> for ConfigDiagnostic {
+    fn from(error: serde_yaml::Error) -> Self {
+        let message = error.to_string();
+        match error.location() {
+            Some(location) => {
+                // Position is reported separately from the message.
+                let suffix = format!(" at line {} column {}", location.line(), location.column());
+                ConfigDiagnostic {
+                    message: message
+                        .strip_suffix(&suffix)
+                        .map_or_else(|| message.clone(), str::to_owned),
+                    position: Some(ConfigPos {
+                        line: location.line() - 1,
+                        column: location.column() - 1,
+                    }),
+                }
+            }
+            None => ConfigDiagnostic {
+                message,
+                position: None,
+            },
+        }
+    }
 }
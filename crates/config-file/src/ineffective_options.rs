@@ -0,0 +1,34 @@
+use crate::{
+    config::{EnumStyle, ProjectConfig},
+    error::ConfigDiagnostic,
+};
+
+/// Finds options that are set but have no effect because of other options.
+pub fn check_ineffective_options(projects: &[ProjectConfig]) -> Vec<ConfigDiagnostic> {
+    let mut diagnostics = vec![];
+    for ProjectConfig { name, config } in projects {
+        let mut messages = vec![];
+        if !config.generate.emit_schema_runtime {
+            if let Some(EnumStyle::ConstObject | EnumStyle::TsEnum) = config.generate.enum_style {
+                messages.push(
+                    "'generate.enumStyle' is ignored because 'generate.emitSchemaRuntime' is not enabled. Enums are emitted as unions.",
+                );
+            }
+            if !config.cache_keys.is_empty() && config.generate.schema_output.is_some() {
+                messages.push(
+                    "'CacheKeyConfig' is not emitted for 'cacheKeys' because 'generate.emitSchemaRuntime' is not enabled.",
+                );
+            }
+        }
+        diagnostics.extend(messages.into_iter().map(|message| ConfigDiagnostic {
+            // Options may come from the top level or from the project, so the position is unknown.
+            message: if projects.len() > 1 {
+                format!("project '{name}': {message}")
+            } else {
+                message.to_owned()
+            },
+            position: None,
+        }));
+    }
+    diagnostics
+}
@@ -2,24 +2,38 @@ mod config;
 mod error;
 #[cfg(feature = "execute_js")]
 mod execute;
+mod ineffective_options;
 #[cfg(feature = "fs")]
 mod load_config;
 #[cfg(feature = "execute_js")]
 mod node;
 mod parse_config;
 mod parsing_utils;
+mod plugin_config;
 mod scalar_type;
+mod suggestion;
 #[cfg(test)]
 mod tests;
 mod type_target;
+mod unknown_keys;
 
-pub use config::{Config, GenerateConfig, GenerateMode};
+pub use config::{
+    CheckConfig, ComplexityConfig, Config, DocumentMode, EnumStyle, GenerateConfig, GenerateMode,
+    GeneratePersistedQueriesConfig, GenerateResolversConfig, OperationLintConfig, ProjectConfig,
+    SchemaLintConfig, ValidationSchemaLibrary, DEFAULT_PROJECT_NAME,
+};
+pub use error::{ConfigDiagnostic, ConfigFileError, ConfigPos};
 #[cfg(feature = "execute_js")]
 pub use execute::execute_js;
+pub use ineffective_options::check_ineffective_options;
 #[cfg(feature = "fs")]
-pub use load_config::load_config;
+pub use load_config::{load_config, LoadedConfig};
 #[cfg(feature = "execute_js")]
 pub use node::{load_default_from_js_file, run_node};
-pub use parse_config::parse_config;
-pub use scalar_type::{ScalarTypeConfig, SendReceiveScalarTypeConfig, SeparateScalarTypeConfig};
+pub use parse_config::{parse_config, parse_config_projects};
+pub use plugin_config::{PluginConfig, PluginConfigWithOptions};
+pub use scalar_type::{
+    ScalarSchemaConfig, ScalarTypeConfig, SendReceiveScalarTypeConfig, SeparateScalarTypeConfig,
+};
 pub use type_target::TypeTarget;
+pub use unknown_keys::check_unknown_keys;
@@ -4,8 +4,10 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use crate::parse_config::parse_config;
-use crate::{config::Config, node::load_default_from_js_file};
+use crate::ineffective_options::check_ineffective_options;
+use crate::parse_config::parse_config_projects;
+use crate::unknown_keys::check_unknown_keys;
+use crate::{config::ProjectConfig, error::ConfigDiagnostic, node::load_default_from_js_file};
 
 use super::error::ConfigFileError;
 
@@ -37,8 +39,22 @@ const CONFIG_NAMES: [(&str, LoaderKind); 19] = [
     (".graphqlrc.cts", LoaderKind::Js),
 ];
 
+/// Config file loaded from the file system.
+#[derive(Debug)]
+pub struct LoadedConfig {
+    /// Path to the config file.
+    pub path: PathBuf,
+    /// Contents of the config file.
+    /// For JavaScript config files, this is the JSON exported from the file.
+    pub content: String,
+    /// Configs of projects in the config file.
+    pub projects: Vec<ProjectConfig>,
+    /// Warnings about the config file.
+    pub warnings: Vec<ConfigDiagnostic>,
+}
+
 /// searches graphql config and loads it if one is found.
-async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String)>> {
+async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String, LoaderKind)>> {
     trace!("search_graphql_config from {}", cwd.display());
     for (name, kind) in CONFIG_NAMES.iter() {
         let config_file_path = cwd.join(name);
@@ -47,7 +63,7 @@ async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String
                 match fs::read_to_string(&config_file_path) {
                     Ok(buf) => {
                         trace!("Found config file {}", config_file_path.display());
-                        return Ok(Some((config_file_path, buf)));
+                        return Ok(Some((config_file_path, buf, *kind)));
                     }
                     Err(err) if err.kind() == io::ErrorKind::NotFound => {
                         trace!("Not found: {}", config_file_path.display());
@@ -68,7 +84,7 @@ async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String
                     trace!("Found config file {}", config_file_path.display());
                     return load_default_from_js_file(&config_file_path)
                         .await
-                        .map(|buf| Some((config_file_path, buf)));
+                        .map(|buf| Some((config_file_path, buf, LoaderKind::Js)));
                 } else {
                     trace!("Not found: {}", config_file_path.display());
                 }
@@ -78,25 +94,50 @@ async fn search_graphql_config(cwd: &Path) -> io::Result<Option<(PathBuf, String
     Ok(None)
 }
 
-/// Loads config file. Returns a pair of loaded file name and loaded config.
+/// Loads config file if one is found.
 /// Config file should follow the GraphQL Config format: https://the-guild.dev/graphql/config/docs
 pub async fn load_config(
     cwd: &Path,
     config_file: Option<&Path>,
-) -> Result<Option<(PathBuf, Config)>, ConfigFileError> {
+) -> Result<Option<LoadedConfig>, ConfigFileError> {
     let config_source = match config_file {
         Some(path) => {
             let mut path_to_read = cwd.to_owned();
             path_to_read.push(path);
-            fs::read_to_string(&path_to_read).map(|source| Some((path_to_read, source)))
+            fs::read_to_string(&path_to_read)
+                .map(|source| Some((path_to_read, source, LoaderKind::Yaml)))
         }
         None => search_graphql_config(cwd).await,
     }?;
 
-    match config_source {
-        None => Ok(None),
-        Some((path, source)) => parse_config(&source)
-            .map(|config| Some((path.clone(), config)))
-            .ok_or_else(|| ConfigFileError::Validation(path)),
+    let Some((path, content, kind)) = config_source else {
+        return Ok(None);
+    };
+    // Positions in JSON generated from JavaScript are not meaningful to users.
+    let strip_position = |mut diagnostic: ConfigDiagnostic| {
+        if let LoaderKind::Js = kind {
+            diagnostic.position = None;
+        }
+        diagnostic
+    };
+    let mut warnings: Vec<_> = check_unknown_keys(&content)
+        .into_iter()
+        .map(strip_position)
+        .collect();
+    match parse_config_projects(&content) {
+        Ok(projects) => {
+            warnings.extend(check_ineffective_options(&projects));
+            Ok(Some(LoadedConfig {
+                path,
+                content,
+                projects,
+                warnings,
+            }))
+        }
+        Err(error) => Err(ConfigFileError::Validation {
+            path,
+            content,
+            error: strip_position(error),
+        }),
     }
 }
@@ -1,12 +1,21 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
+use serde_yaml::Value;
 
-use crate::{parsing_utils::StringOrVecString, Config, GenerateConfig};
+use crate::{
+    config::DEFAULT_PROJECT_NAME, error::ConfigDiagnostic, parsing_utils::StringOrVecString,
+    CheckConfig, Config, GenerateConfig, PluginConfig, ProjectConfig,
+};
 
 #[derive(Deserialize)]
-struct ConfigParser {
+pub(crate) struct ConfigParser {
     schema: Option<StringOrVecString>,
     documents: Option<StringOrVecString>,
     extensions: Option<Extensions>,
+    /// Configs of projects.
+    /// Parsed here so that errors in projects are reported with positions.
+    projects: Option<HashMap<String, ConfigParser>>,
 }
 
 #[derive(Deserialize)]
@@ -15,29 +24,112 @@ struct Extensions {
 }
 
 #[derive(Default, Deserialize)]
-#[serde(default)]
-struct NitrogqlConfigParser {
-    plugins: Vec<String>,
+#[serde(default, rename_all = "camelCase")]
+pub(crate) struct NitrogqlConfigParser {
+    client_schema: Option<StringOrVecString>,
+    plugins: Vec<PluginConfig>,
+    cache_keys: HashMap<String, Vec<String>>,
     generate: Option<GenerateConfig>,
+    check: Option<CheckConfig>,
+}
+
+impl ConfigParser {
+    fn into_config(self) -> Config {
+        let ConfigParser {
+            schema,
+            documents,
+            extensions,
+            projects: _,
+        } = self;
+        let nitrogql = extensions.and_then(|e| e.nitrogql);
+        let (client_schema, plugins, cache_keys, generate, check) = nitrogql
+            .map(|n| {
+                (
+                    n.client_schema.map(|s| s.into_vec()).unwrap_or_default(),
+                    n.plugins,
+                    n.cache_keys,
+                    n.generate.unwrap_or_default(),
+                    n.check.unwrap_or_default(),
+                )
+            })
+            .unwrap_or_default();
+        Config {
+            schema: schema.map(|s| s.into_vec()).unwrap_or_default(),
+            operations: documents.map(|s| s.into_vec()).unwrap_or_default(),
+            client_schema,
+            plugins,
+            cache_keys,
+            generate,
+            check,
+        }
+    }
 }
 
 /// Parse config file from given string.
-/// Returns None if there is a validation error.
-pub fn parse_config(source: &str) -> Option<Config> {
-    let parsed: ConfigParser = serde_yaml::from_str(source).unwrap();
-    let ConfigParser {
-        schema,
-        documents,
-        extensions,
-    } = parsed;
-    let nitrogql = extensions.and_then(|e| e.nitrogql);
-    let (plugins, generate) = nitrogql
-        .map(|n| (n.plugins, n.generate.unwrap_or_default()))
-        .unwrap_or_default();
-    Some(Config {
-        schema: schema.map(|s| s.into_vec()).unwrap_or_default(),
-        operations: documents.map(|s| s.into_vec()).unwrap_or_default(),
-        plugins,
-        generate,
-    })
+/// Only the root-level config is returned; `projects` is validated but otherwise ignored.
+pub fn parse_config(source: &str) -> Result<Config, ConfigDiagnostic> {
+    let parsed: ConfigParser = serde_yaml::from_str(source)?;
+    Ok(parsed.into_config())
+}
+
+/// Parse config file from given string into configs of each project.
+/// Projects inherit root-level settings they do not override.
+/// A config file without `projects` has one project named `default`.
+pub fn parse_config_projects(source: &str) -> Result<Vec<ProjectConfig>, ConfigDiagnostic> {
+    // Validate the whole file first so that errors have positions.
+    let parsed: ConfigParser = serde_yaml::from_str(source)?;
+    if parsed.projects.is_none() {
+        return Ok(vec![ProjectConfig {
+            name: DEFAULT_PROJECT_NAME.to_owned(),
+            config: parsed.into_config(),
+        }]);
+    }
+    let mut root: Value = serde_yaml::from_str(source)?;
+    let projects = root
+        .as_mapping_mut()
+        .and_then(|root| root.remove("projects"));
+    let Some(Value::Mapping(projects)) = projects else {
+        unreachable!("projects are validated as a mapping");
+    };
+    projects
+        .into_iter()
+        .map(|(name, project)| {
+            let name = match name {
+                Value::String(name) => name,
+                name => serde_yaml::from_value::<String>(name)?,
+            };
+            // Merged settings are only known after merging, so errors have no position.
+            let parsed: ConfigParser = serde_yaml::from_value(merge_value(root.clone(), project))
+                .map_err(|error| ConfigDiagnostic {
+                message: format!("project '{name}': {error}"),
+                position: None,
+            })?;
+            Ok(ProjectConfig {
+                name,
+                config: parsed.into_config(),
+            })
+        })
+        .collect()
+}
+
+/// Merges `overlay` into `base`.
+/// Mappings are merged recursively; other values in `overlay` replace those in `base`.
+fn merge_value(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(base_value) => {
+                        let base_value_owned = std::mem::take(base_value);
+                        *base_value = merge_value(base_value_owned, value);
+                    }
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+            Value::Mapping(base)
+        }
+        (_, overlay) => overlay,
+    }
 }
@@ -2,6 +2,8 @@ use std::{marker::PhantomData, str::FromStr};
 
 use serde::{de::Visitor, Deserialize, Deserializer};
 
+use crate::{config::FromStrError, suggestion::suggest};
+
 /// A string or a list of strings.
 pub enum StringOrVecString {
     String(String),
@@ -64,16 +66,25 @@ impl<'de> Visitor<'de> for StringOrVecStringVisitor {
 pub fn deserialize_fromstr<'de, D, T>(deserializer: D) -> Result<T, D::Error>
 where
     D: Deserializer<'de>,
-    T: FromStr,
+    T: FromStr<Err = FromStrError>,
 {
     deserializer.deserialize_str(FromStrVisitor(PhantomData))
 }
 
+/// Same as `deserialize_fromstr` but for optional fields.
+pub fn deserialize_fromstr_option<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr<Err = FromStrError>,
+{
+    deserialize_fromstr(deserializer).map(Some)
+}
+
 struct FromStrVisitor<T>(PhantomData<T>);
 
 impl<'de, T> Visitor<'de> for FromStrVisitor<T>
 where
-    T: FromStr,
+    T: FromStr<Err = FromStrError>,
 {
     type Value = T;
 
@@ -85,6 +96,20 @@ where
     where
         E: serde::de::Error,
     {
-        T::from_str(v).map_err(|_| E::custom(format!("invalid enum value: {v}")))
+        T::from_str(v).map_err(|FromStrError { expected }| {
+            match suggest(v, expected.iter().copied()) {
+                Some(suggestion) => {
+                    E::custom(format!("invalid value '{v}', did you mean '{suggestion}'?"))
+                }
+                None => E::custom(format!(
+                    "invalid value '{v}', expected one of {}",
+                    expected
+                        .iter()
+                        .map(|value| format!("'{value}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            }
+        })
     }
 }
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+
+/// Plugin entry in the config file.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum PluginConfig {
+    /// Plugin specified by name only.
+    Name(String),
+    /// Plugin specified along with options.
+    WithOptions(PluginConfigWithOptions),
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PluginConfigWithOptions {
+    pub name: String,
+    pub options: Option<serde_yaml::Value>,
+}
+
+impl PluginConfig {
+    /// Name of the plugin.
+    pub fn name(&self) -> &str {
+        match self {
+            PluginConfig::Name(name) => name,
+            PluginConfig::WithOptions(config) => &config.name,
+        }
+    }
+    /// Options given to the plugin, if any.
+    pub fn options(&self) -> Option<&serde_yaml::Value> {
+        match self {
+            PluginConfig::Name(_) => None,
+            PluginConfig::WithOptions(config) => config.options.as_ref(),
+        }
+    }
+}
+
+impl From<&str> for PluginConfig {
+    fn from(name: &str) -> Self {
+        PluginConfig::Name(name.to_owned())
+    }
+}
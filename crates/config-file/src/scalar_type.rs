@@ -99,6 +99,33 @@ impl ScalarTypeConfig {
     }
 }
 
+/// Validation schema of a scalar type as defined in the config file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ScalarSchemaConfig {
+    /// Single schema for use in all situations.
+    Single(String),
+    /// Specification as a pair of send schema and receive schema.
+    SendReceive(SendReceiveScalarTypeConfig),
+}
+
+impl ScalarSchemaConfig {
+    /// Get the schema of values sent as operation variables.
+    pub fn send(&self) -> &str {
+        match self {
+            ScalarSchemaConfig::Single(schema) => schema,
+            ScalarSchemaConfig::SendReceive(config) => &config.send,
+        }
+    }
+    /// Get the schema of values received as operation results.
+    pub fn receive(&self) -> &str {
+        match self {
+            ScalarSchemaConfig::Single(schema) => schema,
+            ScalarSchemaConfig::SendReceive(config) => &config.receive,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -202,4 +229,21 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn parsing_schema() {
+        let config: ScalarSchemaConfig = serde_json::from_str(r#""z.string()""#).unwrap();
+        assert_eq!(config.send(), "z.string()");
+        assert_eq!(config.receive(), "z.string()");
+
+        let config: ScalarSchemaConfig = serde_json::from_str(
+            r#"{
+                "send": "z.date()",
+                "receive": "z.string().datetime()"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(config.send(), "z.date()");
+        assert_eq!(config.receive(), "z.string().datetime()");
+    }
 }
@@ -0,0 +1,102 @@
+use crate::parse_config;
+
+#[test]
+fn default_config() {
+    let config = r#"
+schema: schema.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    let lint = config.check.schema_lint;
+    assert!(!lint.type_name_pascal_case);
+    assert!(!lint.field_name_camel_case);
+    assert!(!lint.enum_value_screaming_case);
+    assert_eq!(lint.input_type_suffix, None);
+    assert!(!lint.require_root_field_description);
+    assert!(!lint.non_null_list_items);
+    assert!(!lint.require_deprecation_reason);
+    assert!(!lint.relay_connection);
+    let lint = config.check.operation_lint;
+    assert!(!lint.require_operation_name);
+    assert!(!lint.operation_name_matches_file_name);
+    assert!(!lint.require_named_fragment_imports);
+    assert!(!lint.require_id_selection);
+    assert_eq!(lint.max_selection_depth, None);
+    assert!(!lint.no_typename_alias);
+    let complexity = config.check.complexity;
+    assert_eq!(complexity.max_aliases, None);
+    assert_eq!(complexity.max_cost, None);
+    assert_eq!(complexity.default_list_size, 1);
+}
+
+#[test]
+fn schema_lint_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        check:
+            schemaLint:
+                typeNamePascalCase: true
+                fieldNameCamelCase: true
+                enumValueScreamingCase: true
+                inputTypeSuffix: Input
+                requireRootFieldDescription: true
+                nonNullListItems: true
+                requireDeprecationReason: true
+                relayConnection: true
+"#;
+    let config = parse_config(config).unwrap();
+    let lint = config.check.schema_lint;
+    assert!(lint.type_name_pascal_case);
+    assert!(lint.field_name_camel_case);
+    assert!(lint.enum_value_screaming_case);
+    assert_eq!(lint.input_type_suffix, Some("Input".to_owned()));
+    assert!(lint.require_root_field_description);
+    assert!(lint.non_null_list_items);
+    assert!(lint.require_deprecation_reason);
+    assert!(lint.relay_connection);
+}
+
+#[test]
+fn operation_lint_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        check:
+            operationLint:
+                requireOperationName: true
+                operationNameMatchesFileName: true
+                requireNamedFragmentImports: true
+                requireIdSelection: true
+                maxSelectionDepth: 5
+                noTypenameAlias: true
+"#;
+    let config = parse_config(config).unwrap();
+    let lint = config.check.operation_lint;
+    assert!(lint.require_operation_name);
+    assert!(lint.operation_name_matches_file_name);
+    assert!(lint.require_named_fragment_imports);
+    assert!(lint.require_id_selection);
+    assert_eq!(lint.max_selection_depth, Some(5));
+    assert!(lint.no_typename_alias);
+}
+
+#[test]
+fn complexity_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        check:
+            complexity:
+                maxAliases: 5
+                maxCost: 1000
+                defaultListSize: 20
+"#;
+    let config = parse_config(config).unwrap();
+    let complexity = config.check.complexity;
+    assert_eq!(complexity.max_aliases, Some(5));
+    assert_eq!(complexity.max_cost, Some(1000));
+    assert_eq!(complexity.default_list_size, 20);
+}
@@ -1,10 +1,17 @@
 use std::path::PathBuf;
 
-use crate::{parse_config, GenerateMode, ScalarTypeConfig};
+use crate::{
+    parse_config, EnumStyle, GenerateMode, PluginConfig, ScalarSchemaConfig, ScalarTypeConfig,
+    ValidationSchemaLibrary,
+};
 
+mod check;
 mod export;
 mod name;
+mod persisted_queries;
+mod projects;
 mod r#type;
+mod validation;
 
 #[test]
 fn parse_schema_and_documents() {
@@ -34,6 +41,40 @@ documents:
     );
 }
 
+#[test]
+fn parse_plugins() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        plugins:
+            - nitrogql:graphql-scalars-plugin
+            - name: nitrogql:model-plugin
+              options:
+                  directiveName: entity
+            - name: ./plugin.ts
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config
+            .plugins
+            .iter()
+            .map(PluginConfig::name)
+            .collect::<Vec<_>>(),
+        vec![
+            "nitrogql:graphql-scalars-plugin",
+            "nitrogql:model-plugin",
+            "./plugin.ts"
+        ]
+    );
+    assert_eq!(config.plugins[0].options(), None);
+    assert_eq!(
+        config.plugins[1].options().unwrap()["directiveName"].as_str(),
+        Some("entity")
+    );
+    assert_eq!(config.plugins[2].options(), None);
+}
+
 #[test]
 fn parse_generate_config() {
     let config = r#"
@@ -59,6 +100,211 @@ extensions:
     );
 }
 
+#[test]
+fn parse_enum_style() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.enum_style, None);
+    assert!(!config.generate.emit_enum_values);
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            enumStyle: ts-enum
+            emitEnumValues: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.enum_style, Some(EnumStyle::TsEnum));
+    assert!(config.generate.emit_enum_values);
+}
+
+#[test]
+fn parse_validation_schema_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.validation_schema_output, None);
+    assert_eq!(
+        config.generate.validation_schema_library,
+        ValidationSchemaLibrary::Zod
+    );
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            validationSchemaOutput: src/generated/validation.ts
+            validationSchemaLibrary: valibot
+            type:
+                scalarSchemas:
+                    DateTime: v.pipe(v.string(), v.isoDateTime())
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.validation_schema_output,
+        Some(PathBuf::from("src/generated/validation.ts"))
+    );
+    assert_eq!(
+        config.generate.validation_schema_library,
+        ValidationSchemaLibrary::Valibot
+    );
+    assert_eq!(
+        config.generate.r#type.scalar_schemas.get("DateTime"),
+        Some(&ScalarSchemaConfig::Single(
+            "v.pipe(v.string(), v.isoDateTime())".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn parse_mocks_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.generate.mocks_output, None);
+    assert!(config.generate.r#type.scalar_mocks.is_empty());
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            mocksOutput: src/generated/mocks.ts
+            type:
+                scalarMocks:
+                    DateTime: new Date(0).toISOString()
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(
+        config.generate.mocks_output,
+        Some(PathBuf::from("src/generated/mocks.ts"))
+    );
+    assert_eq!(
+        config.generate.r#type.scalar_mocks.get("DateTime"),
+        Some(&"new Date(0).toISOString()".to_owned())
+    );
+}
+
+#[test]
+fn parse_resolvers_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            resolversOutput: src/generated/resolvers.ts
+            resolvers:
+                resolversTypeName: AppResolvers
+                contextType: ./context#Context
+                mappers:
+                    User: ./models#UserModel
+                optionalFieldResolvers: true
+                subscriptionPayloads:
+                    messageAdded: ./events#MessageEvent
+"#;
+    let config = parse_config(config).unwrap();
+    let resolvers = &config.generate.resolvers;
+    assert_eq!(
+        resolvers.resolvers_type_name,
+        Some("AppResolvers".to_owned())
+    );
+    assert_eq!(resolvers.resolver_output_type_name, None);
+    assert_eq!(resolvers.context_type, Some("./context#Context".to_owned()));
+    assert_eq!(
+        resolvers.mappers.get("User"),
+        Some(&"./models#UserModel".to_owned())
+    );
+    assert!(resolvers.optional_field_resolvers);
+    assert_eq!(
+        resolvers.subscription_payloads.get("messageAdded"),
+        Some(&"./events#MessageEvent".to_owned())
+    );
+}
+
+#[test]
+fn parse_emit_result_schemas() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(!config.generate.emit_result_schemas);
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            mode: standalone-ts-4.0
+            emitResultSchemas: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.emit_result_schemas);
+}
+
+#[test]
+fn parse_cache_fields() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(!config.generate.add_cache_fields);
+    assert!(config.cache_keys.is_empty());
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        cacheKeys:
+            Product: [sku, region]
+        generate:
+            addCacheFields: true
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.generate.add_cache_fields);
+    assert_eq!(
+        config.cache_keys.get("Product"),
+        Some(&vec!["sku".to_owned(), "region".to_owned()])
+    );
+}
+
+#[test]
+fn parse_client_schema() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert!(config.client_schema.is_empty());
+
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        clientSchema: client/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    assert_eq!(config.client_schema, vec!["client/**/*.graphql".to_owned()]);
+}
+
 #[test]
 fn parse_scalar_types() {
     let config = r#"
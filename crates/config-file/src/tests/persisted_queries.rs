@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use crate::{parse_config, DocumentMode};
+
+#[test]
+fn default_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let config = parse_config(config).unwrap();
+    let pq = config.generate.persisted_queries;
+    assert_eq!(pq.manifest_output, None);
+    assert_eq!(pq.document_mode, DocumentMode::Full);
+}
+
+#[test]
+fn persisted_queries_config() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+extensions:
+    nitrogql:
+        generate:
+            persistedQueries:
+                manifestOutput: persisted-queries.json
+                documentMode: persisted-only
+"#;
+    let config = parse_config(config).unwrap();
+    let pq = config.generate.persisted_queries;
+    assert_eq!(
+        pq.manifest_output,
+        Some(PathBuf::from("persisted-queries.json"))
+    );
+    assert_eq!(pq.document_mode, DocumentMode::PersistedOnly);
+}
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use crate::{parse_config, parse_config_projects, GenerateMode, DEFAULT_PROJECT_NAME};
+
+#[test]
+fn single_project() {
+    let config = r#"
+schema: schema.graphql
+documents: src/**/*.graphql
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    assert_eq!(projects.len(), 1);
+    assert_eq!(projects[0].name, DEFAULT_PROJECT_NAME);
+    assert_eq!(projects[0].config.schema, vec!["schema.graphql"]);
+    assert_eq!(projects[0].config.operations, vec!["src/**/*.graphql"]);
+}
+
+#[test]
+fn multiple_projects() {
+    let config = r#"
+projects:
+    web:
+        schema: web/schema.graphql
+        documents: web/**/*.graphql
+    admin:
+        schema: admin/schema.graphql
+        documents: admin/**/*.graphql
+        extensions:
+            nitrogql:
+                generate:
+                    schemaOutput: admin/schema.d.ts
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    assert_eq!(
+        projects.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+        vec!["web", "admin"]
+    );
+    assert_eq!(projects[0].config.schema, vec!["web/schema.graphql"]);
+    assert_eq!(projects[0].config.generate.schema_output, None);
+    assert_eq!(projects[1].config.operations, vec!["admin/**/*.graphql"]);
+    assert_eq!(
+        projects[1].config.generate.schema_output,
+        Some(PathBuf::from("admin/schema.d.ts"))
+    );
+}
+
+#[test]
+fn inherit_root_config() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            mode: standalone-ts-4.0
+            schemaOutput: schema.d.ts
+projects:
+    web:
+        documents: web/**/*.graphql
+    admin:
+        schema: admin/schema.graphql
+        documents: admin/**/*.graphql
+        extensions:
+            nitrogql:
+                generate:
+                    schemaOutput: admin/schema.d.ts
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    let web = &projects[0].config;
+    assert_eq!(web.schema, vec!["schema.graphql"]);
+    assert_eq!(web.operations, vec!["web/**/*.graphql"]);
+    assert_eq!(web.generate.mode, GenerateMode::StandaloneTS4_0);
+    assert_eq!(
+        web.generate.schema_output,
+        Some(PathBuf::from("schema.d.ts"))
+    );
+    let admin = &projects[1].config;
+    assert_eq!(admin.schema, vec!["admin/schema.graphql"]);
+    assert_eq!(admin.generate.mode, GenerateMode::StandaloneTS4_0);
+    assert_eq!(
+        admin.generate.schema_output,
+        Some(PathBuf::from("admin/schema.d.ts"))
+    );
+
+    // parse_config returns the root-level config.
+    let root = parse_config(config).unwrap();
+    assert_eq!(root.schema, vec!["schema.graphql"]);
+    assert!(root.operations.is_empty());
+}
+
+#[test]
+fn invalid_projects() {
+    let config = r#"
+projects:
+    - schema: schema.graphql
+"#;
+    assert!(parse_config_projects(config).is_err());
+}
@@ -12,6 +12,8 @@ documents: []
     let ty = config.generate.r#type;
     assert_eq!(ty.scalar_types, HashMap::new());
     assert!(ty.allow_undefined_as_optional_input);
+    assert!(!ty.fragment_masking);
+    assert!(!ty.readonly_output);
 }
 
 #[test]
@@ -58,3 +60,35 @@ extensions:
     assert_eq!(ty.scalar_types, expected);
     assert!(!ty.allow_undefined_as_optional_input);
 }
+
+#[test]
+fn fragment_masking() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                fragmentMasking: true
+"#;
+    let config = parse_config(config).unwrap();
+    let ty = config.generate.r#type;
+    assert!(ty.fragment_masking);
+}
+
+#[test]
+fn readonly_output() {
+    let config = r#"
+schema: schema.graphql
+documents: []
+extensions:
+    nitrogql:
+        generate:
+            type:
+                readonlyOutput: true
+"#;
+    let config = parse_config(config).unwrap();
+    let ty = config.generate.r#type;
+    assert!(ty.readonly_output);
+}
@@ -0,0 +1,210 @@
+use crate::{
+    check_ineffective_options, check_unknown_keys, parse_config, parse_config_projects, ConfigPos,
+};
+
+#[test]
+fn invalid_enum_value() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            mode: standalone-ts4.0
+"#;
+    let error = parse_config(config).unwrap_err();
+    assert_eq!(
+        error.message,
+        "extensions.nitrogql.generate.mode: invalid value 'standalone-ts4.0', did you mean 'standalone-ts-4.0'?"
+    );
+    assert_eq!(
+        error.position,
+        Some(ConfigPos {
+            line: 5,
+            column: 18
+        })
+    );
+
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            enumStyle: enum
+"#;
+    let error = parse_config(config).unwrap_err();
+    assert_eq!(
+        error.message,
+        "extensions.nitrogql.generate.enumStyle: invalid value 'enum', expected one of 'union', 'const-object', 'ts-enum'"
+    );
+}
+
+#[test]
+fn invalid_type() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        check:
+            complexity:
+                maxAliases: many
+"#;
+    let error = parse_config(config).unwrap_err();
+    assert!(error
+        .message
+        .starts_with("extensions.nitrogql.check.complexity"));
+    assert_eq!(error.position.map(|pos| pos.line), Some(6));
+}
+
+#[test]
+fn invalid_project() {
+    let config = r#"
+projects:
+    web:
+        schema: schema.graphql
+        extensions:
+            nitrogql:
+                generate:
+                    mode: loader
+"#;
+    let error = parse_config_projects(config).unwrap_err();
+    assert!(error
+        .message
+        .starts_with("projects.web.extensions.nitrogql.generate"));
+    assert_eq!(error.position.map(|pos| pos.line), Some(7));
+}
+
+#[test]
+fn unknown_keys() {
+    let config = r#"
+schema: schema.graphql
+document: src/**/*.graphql
+extensions:
+    other-tool:
+        anything: true
+    nitrogql:
+        generate:
+            schemaOuput: schema.d.ts
+            type:
+                scalarTypes:
+                    Date: string
+        check:
+            operationLint:
+                requireIdSelection: true
+                unknownRule: true
+        plugins:
+            - name: plugin.js
+              options:
+                  anything: true
+projects:
+    web:
+        extensions:
+            nitrogql:
+                generate:
+                    emitSchemaRuntim: true
+"#;
+    let warnings = check_unknown_keys(config);
+    assert_eq!(
+        warnings
+            .iter()
+            .map(|warning| (warning.message.as_str(), warning.position))
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                "Unknown key 'document' at the top level. Did you mean 'documents'?",
+                Some(ConfigPos { line: 2, column: 0 })
+            ),
+            (
+                "Unknown key 'schemaOuput' in 'extensions.nitrogql.generate'. Did you mean 'schemaOutput'?",
+                Some(ConfigPos { line: 8, column: 12 })
+            ),
+            (
+                "Unknown key 'unknownRule' in 'extensions.nitrogql.check.operationLint'",
+                Some(ConfigPos { line: 15, column: 16 })
+            ),
+            (
+                "Unknown key 'emitSchemaRuntim' in 'projects.web.extensions.nitrogql.generate'. Did you mean 'emitSchemaRuntime'?",
+                Some(ConfigPos { line: 25, column: 20 })
+            ),
+        ]
+    );
+}
+
+#[test]
+fn ineffective_enum_style() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        generate:
+            enumStyle: ts-enum
+projects:
+    server:
+        extensions:
+            nitrogql:
+                generate:
+                    emitSchemaRuntime: true
+    client:
+        extensions:
+            nitrogql:
+                generate:
+                    schemaOutput: schema.d.ts
+    union:
+        extensions:
+            nitrogql:
+                generate:
+                    enumStyle: union
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    let warnings = check_ineffective_options(&projects);
+    assert_eq!(
+        warnings
+            .iter()
+            .map(|warning| (warning.message.as_str(), warning.position))
+            .collect::<Vec<_>>(),
+        vec![(
+            "project 'client': 'generate.enumStyle' is ignored because 'generate.emitSchemaRuntime' is not enabled. Enums are emitted as unions.",
+            None
+        )]
+    );
+
+    let config = r#"
+schema: schema.graphql
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    assert!(check_ineffective_options(&projects).is_empty());
+}
+
+#[test]
+fn ineffective_cache_keys() {
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        cacheKeys:
+            Product: [sku]
+        generate:
+            schemaOutput: schema.d.ts
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    let warnings = check_ineffective_options(&projects);
+    assert_eq!(
+        warnings
+            .iter()
+            .map(|warning| warning.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["'CacheKeyConfig' is not emitted for 'cacheKeys' because 'generate.emitSchemaRuntime' is not enabled."]
+    );
+
+    let config = r#"
+schema: schema.graphql
+extensions:
+    nitrogql:
+        cacheKeys:
+            Product: [sku]
+        generate:
+            schemaOutput: schema.ts
+            emitSchemaRuntime: true
+"#;
+    let projects = parse_config_projects(config).unwrap();
+    assert!(check_ineffective_options(&projects).is_empty());
+}
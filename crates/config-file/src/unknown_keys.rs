@@ -0,0 +1,214 @@
+use serde::{
+    de::{self, value, Visitor},
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use yaml_rust::{
+    parser::{Event, MarkedEventReceiver, Parser},
+    scanner::Marker,
+};
+
+use crate::{
+    config::{
+        CheckConfig, ComplexityConfig, GenerateConfig, GenerateExportConfig, GenerateNameConfig,
+        GeneratePersistedQueriesConfig, GenerateResolversConfig, GenerateTypeConfig,
+        OperationLintConfig, SchemaLintConfig,
+    },
+    error::{ConfigDiagnostic, ConfigPos},
+    parse_config::{ConfigParser, NitrogqlConfigParser},
+    suggestion::suggest,
+};
+
+/// Finds keys in a config file that are not recognized by nitrogql.
+/// Extensions other than `nitrogql` are not checked.
+pub fn check_unknown_keys(source: &str) -> Vec<ConfigDiagnostic> {
+    let mut receiver = UnknownKeysReceiver {
+        path: vec![],
+        stack: vec![],
+        diagnostics: vec![],
+    };
+    // Syntax errors are reported when the config is parsed.
+    let _ = Parser::new(source.chars()).load(&mut receiver, false);
+    receiver.diagnostics
+}
+
+/// Returns the keys accepted by the mapping at given path.
+/// Returns None if any key is accepted.
+fn known_keys(path: &[String]) -> Option<&'static [&'static str]> {
+    let path = path.iter().map(String::as_str).collect::<Vec<_>>();
+    match path.as_slice() {
+        ["projects", _, rest @ ..] => known_keys_of_project(rest),
+        rest => known_keys_of_project(rest),
+    }
+}
+
+fn known_keys_of_project(path: &[&str]) -> Option<&'static [&'static str]> {
+    match path {
+        [] => Some(struct_fields::<ConfigParser>()),
+        ["extensions", "nitrogql"] => Some(struct_fields::<NitrogqlConfigParser>()),
+        ["extensions", "nitrogql", "generate"] => Some(struct_fields::<GenerateConfig>()),
+        ["extensions", "nitrogql", "generate", "resolvers"] => {
+            Some(struct_fields::<GenerateResolversConfig>())
+        }
+        ["extensions", "nitrogql", "generate", "type"] => {
+            Some(struct_fields::<GenerateTypeConfig>())
+        }
+        ["extensions", "nitrogql", "generate", "name"] => {
+            Some(struct_fields::<GenerateNameConfig>())
+        }
+        ["extensions", "nitrogql", "generate", "export"] => {
+            Some(struct_fields::<GenerateExportConfig>())
+        }
+        ["extensions", "nitrogql", "generate", "persistedQueries"] => {
+            Some(struct_fields::<GeneratePersistedQueriesConfig>())
+        }
+        ["extensions", "nitrogql", "check"] => Some(struct_fields::<CheckConfig>()),
+        ["extensions", "nitrogql", "check", "schemaLint"] => {
+            Some(struct_fields::<SchemaLintConfig>())
+        }
+        ["extensions", "nitrogql", "check", "operationLint"] => {
+            Some(struct_fields::<OperationLintConfig>())
+        }
+        ["extensions", "nitrogql", "check", "complexity"] => {
+            Some(struct_fields::<ComplexityConfig>())
+        }
+        _ => None,
+    }
+}
+
+/// Returns the names of fields accepted by a struct with derived `Deserialize`.
+fn struct_fields<'de, T: Deserialize<'de>>() -> &'static [&'static str] {
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(StructFieldsDeserializer {
+        fields: &mut fields,
+    });
+    fields
+}
+
+/// Deserializer that only records the fields of the struct being deserialized.
+struct StructFieldsDeserializer<'a> {
+    fields: &'a mut &'static [&'static str],
+}
+
+impl<'de> Deserializer<'de> for StructFieldsDeserializer<'_> {
+    type Error = value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        *self.fields = fields;
+        Err(de::Error::custom("fields recorded"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+enum Frame {
+    /// Mapping whose next event is a key.
+    MappingKey,
+    /// Mapping whose next event is a value of the key pushed to the path.
+    MappingValue,
+    Sequence,
+}
+
+struct UnknownKeysReceiver {
+    /// Keys from the root to the current node.
+    /// Sequence items are represented by an empty string.
+    path: Vec<String>,
+    stack: Vec<Frame>,
+    diagnostics: Vec<ConfigDiagnostic>,
+}
+
+impl UnknownKeysReceiver {
+    /// Called before a node starts.
+    fn enter_node(&mut self) {
+        if let Some(Frame::Sequence) = self.stack.last() {
+            self.path.push(String::new());
+        }
+    }
+
+    /// Called after a node ends.
+    fn leave_node(&mut self) {
+        match self.stack.last_mut() {
+            Some(frame @ Frame::MappingValue) => {
+                *frame = Frame::MappingKey;
+                self.path.pop();
+            }
+            Some(Frame::Sequence) => {
+                self.path.pop();
+            }
+            Some(Frame::MappingKey) | None => {}
+        }
+    }
+
+    fn check_key(&mut self, key: &str, mark: Marker) {
+        let Some(known_keys) = known_keys(&self.path) else {
+            return;
+        };
+        if known_keys.contains(&key) {
+            return;
+        }
+        let location = if self.path.is_empty() {
+            "at the top level".to_owned()
+        } else {
+            format!("in '{}'", self.path.join("."))
+        };
+        let message = match suggest(key, known_keys.iter().copied()) {
+            Some(suggestion) => {
+                format!("Unknown key '{key}' {location}. Did you mean '{suggestion}'?")
+            }
+            None => format!("Unknown key '{key}' {location}"),
+        };
+        self.diagnostics.push(ConfigDiagnostic {
+            message,
+            position: Some(ConfigPos {
+                line: mark.line() - 1,
+                column: mark.col(),
+            }),
+        });
+    }
+}
+
+impl MarkedEventReceiver for UnknownKeysReceiver {
+    fn on_event(&mut self, event: Event, mark: Marker) {
+        match event {
+            Event::Scalar(value, ..) => {
+                if let Some(frame @ Frame::MappingKey) = self.stack.last_mut() {
+                    *frame = Frame::MappingValue;
+                    self.check_key(&value, mark);
+                    self.path.push(value);
+                } else {
+                    self.enter_node();
+                    self.leave_node();
+                }
+            }
+            Event::Alias(_) => {
+                self.enter_node();
+                self.leave_node();
+            }
+            Event::MappingStart(_) => {
+                self.enter_node();
+                self.stack.push(Frame::MappingKey);
+            }
+            Event::SequenceStart(_) => {
+                self.enter_node();
+                self.stack.push(Frame::Sequence);
+            }
+            Event::MappingEnd | Event::SequenceEnd => {
+                self.stack.pop();
+                self.leave_node();
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,14 +1,19 @@
 use std::path::{Path, PathBuf};
 
+use globset::{GlobBuilder, GlobSetBuilder};
 use nitrogql_ast::OperationDocument;
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{Config, ProjectConfig};
 use nitrogql_error::{PositionedError, Result};
-use nitrogql_semantics::{resolve_operation_imports, OperationExtension, OperationResolver};
+use nitrogql_semantics::{
+    add_cache_fields, ast_to_type_system, resolve_operation_imports, OperationExtension,
+    OperationResolver,
+};
 use nitrogql_utils::resolve_relative_path;
 use thiserror::Error;
 
 use crate::{
     js_printer::print_js,
+    schema::SchemaFiles,
     tasks::{Task, Tasks},
 };
 
@@ -16,6 +21,58 @@ use crate::{
 pub enum LoaderError {
     #[error("Task not found")]
     TaskNotFound,
+    #[error("Invalid schema glob: {0}")]
+    InvalidSchemaGlob(String),
+    #[error("Schema file '{}' is not supported by the loader. Only GraphQL files can be used as the schema.", .0.display())]
+    UnsupportedSchemaFile(PathBuf),
+    #[error("Schema is not loaded")]
+    SchemaNotLoaded,
+    #[error("Invalid documents glob: {0}")]
+    InvalidDocumentsGlob(String),
+    #[error("File '{}' does not match 'documents' of any project in the config file.", .0.display())]
+    NoProjectForFile(PathBuf),
+    #[error("Plugin '{0}' is not supported by the loader. Only built-in plugins can be used, because other plugins may transform operations in a way the loader cannot reproduce.")]
+    UnsupportedPlugin(String),
+}
+
+/// Finds the config of the project that the root file of given task belongs to.
+/// With multiple projects, the project is chosen by matching its `documents` globs
+/// resolved from `root_dir`.
+pub fn project_config<'a>(
+    tasks: &Tasks,
+    task_id: usize,
+    projects: &'a [ProjectConfig],
+    root_dir: &Path,
+) -> Result<&'a Config> {
+    let task = tasks
+        .get_task(task_id)
+        .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
+    if let [project] = projects {
+        return Ok(&project.config);
+    }
+    for project in projects {
+        let mut builder = GlobSetBuilder::new();
+        for glob in project.config.operations.iter() {
+            let glob = GlobBuilder::new(&root_dir.join(glob).to_string_lossy())
+                .literal_separator(true)
+                .build()
+                .map_err(|err| LoaderError::InvalidDocumentsGlob(err.to_string()))
+                .map_err(|err| PositionedError::new(err.into(), None, vec![]))?;
+            builder.add(glob);
+        }
+        let matcher = builder
+            .build()
+            .map_err(|err| LoaderError::InvalidDocumentsGlob(err.to_string()))
+            .map_err(|err| PositionedError::new(err.into(), None, vec![]))?;
+        if matcher.is_match(&task.root_file_name) {
+            return Ok(&project.config);
+        }
+    }
+    Err(PositionedError::new(
+        LoaderError::NoProjectForFile(task.root_file_name.clone()).into(),
+        None,
+        vec![],
+    ))
 }
 
 /// Initiates a task.
@@ -27,13 +84,65 @@ pub fn initiate_task(tasks: &mut Tasks, file_name: PathBuf, input_source: String
     Ok(task_id)
 }
 
+/// Get the list of directories whose files are required for the given task.
+/// Directories are searched for schema files when the schema is needed.
+/// Schema and client schema globs are resolved from `root_dir`.
+pub fn get_required_directories(
+    tasks: &mut Tasks,
+    task_id: usize,
+    config: &Config,
+    root_dir: &Path,
+) -> Result<Vec<PathBuf>> {
+    let task = tasks
+        .get_task_mut(task_id)
+        .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
+    if !config.generate.add_cache_fields {
+        return Ok(vec![]);
+    }
+    let schema_files = match task.schema_files {
+        Some(ref schema_files) => schema_files,
+        None => {
+            // Client schema may define types whose key fields are added.
+            let globs = config
+                .schema
+                .iter()
+                .chain(config.client_schema.iter())
+                .cloned()
+                .collect::<Vec<_>>();
+            task.schema_files
+                .insert(SchemaFiles::new(root_dir, &globs)?)
+        }
+    };
+    Ok(schema_files.pending_directories().to_vec())
+}
+
+/// Supply the list of files in a directory.
+pub fn load_directory(
+    tasks: &mut Tasks,
+    task_id: usize,
+    directory: PathBuf,
+    files: Vec<PathBuf>,
+) -> Result<()> {
+    let task = tasks
+        .get_task_mut(task_id)
+        .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
+    if let Some(schema_files) = task.schema_files.as_mut() {
+        schema_files.add_directory(&directory, files);
+    }
+    Ok(())
+}
+
 /// Get the list of additionally required files for the given task.
 pub fn get_required_files(tasks: &mut Tasks, task_id: usize) -> Result<Vec<PathBuf>> {
     let task = tasks
         .get_task_mut(task_id)
         .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
 
-    let mut required_files = vec![];
+    let mut required_files = task
+        .schema_files
+        .iter()
+        .flat_map(|schema_files| schema_files.pending_files().cloned())
+        .collect::<Vec<_>>();
     for (from_file, (_, extensions)) in task.iter_loaded_files() {
         for import in extensions.imports.iter() {
             let path = Path::new(import.path.value.as_str());
@@ -63,6 +172,18 @@ pub fn load_file(
 
 /// Emit JavaScript for the given task.
 pub fn emit_js(tasks: &Tasks, task_id: usize, config: &Config) -> Result<String> {
+    // Built-in plugins do not transform operations.
+    if let Some(plugin) = config
+        .plugins
+        .iter()
+        .find(|plugin| !plugin.name().starts_with("nitrogql:"))
+    {
+        return Err(PositionedError::new(
+            LoaderError::UnsupportedPlugin(plugin.name().to_owned()).into(),
+            None,
+            vec![],
+        ));
+    }
     let task = tasks
         .get_task(task_id)
         .ok_or_else(|| PositionedError::new(LoaderError::TaskNotFound.into(), None, vec![]))?;
@@ -71,6 +192,16 @@ pub fn emit_js(tasks: &Tasks, task_id: usize, config: &Config) -> Result<String>
         (&task.root_file_name, document, extensions),
         &TaskOperationResolver(task),
     )?;
+    if !config.generate.add_cache_fields {
+        return Ok(print_js(&document, config));
+    }
+    let schema_document = task
+        .schema_files
+        .as_ref()
+        .ok_or_else(|| PositionedError::new(LoaderError::SchemaNotLoaded.into(), None, vec![]))?
+        .document()?;
+    let schema = ast_to_type_system(&schema_document);
+    let document = add_cache_fields(&document, &schema, &config.cache_keys);
     let js = print_js(&document, config);
     Ok(js)
 }
@@ -88,6 +219,8 @@ impl<'a> OperationResolver<'a> for TaskOperationResolver<'a> {
 #[cfg(test)]
 mod tests {
     use insta::assert_snapshot;
+    use nitrogql_config_file::{parse_config_projects, DocumentMode, GenerateConfig};
+    use nitrogql_printer::document_hash;
 
     use super::*;
 
@@ -151,6 +284,70 @@ mod tests {
         assert_snapshot!(js);
     }
 
+    #[test]
+    fn external_plugin() {
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            query Test {
+                test
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let config = Config {
+            plugins: vec!["nitrogql:model-plugin".into(), "./plugin.mjs".into()],
+            ..Default::default()
+        };
+        let error = emit_js(&tasks, task_id, &config).unwrap_err();
+        assert_eq!(
+            error.into_inner().to_string(),
+            "Plugin './plugin.mjs' is not supported by the loader. Only built-in plugins can be used, because other plugins may transform operations in a way the loader cannot reproduce."
+        );
+    }
+
+    #[test]
+    fn persisted_only() {
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/op.graphql"),
+            r#"
+            #import Frag1 from "./frag1.graphql"
+            query Test {
+                test
+                ...Frag1
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/frag1.graphql"),
+            r#"
+            fragment Frag1 on Query {
+                test2
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config.generate.persisted_queries.document_mode = DocumentMode::PersistedOnly;
+        let js = emit_js(&tasks, task_id, &config).unwrap();
+        // Imported fragments are part of the hashed document
+        let expected_hash = document_hash(
+            "query Test {\n  test\n  ... Frag1\n}\n\nfragment Frag1 on Query {\n  test2\n}\n",
+        );
+        assert!(js.contains(&expected_hash));
+        assert_snapshot!(js);
+    }
+
     #[test]
     fn transitive_import() {
         let mut tasks = Tasks::new();
@@ -208,4 +405,167 @@ mod tests {
         let js = emit_js(&tasks, task_id, &Default::default()).unwrap();
         assert_snapshot!(js);
     }
+
+    #[test]
+    fn add_cache_fields() {
+        let config = Config {
+            schema: vec!["schema/**/*.graphql".to_owned(), "extra.graphql".to_owned()],
+            generate: GenerateConfig {
+                add_cache_fields: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let root_dir = Path::new("/path/to");
+
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/src/op.graphql"),
+            r#"
+            query Test {
+                me { name }
+            }
+            "#
+            .to_string(),
+        )
+        .unwrap();
+        let required_directories =
+            get_required_directories(&mut tasks, task_id, &config, root_dir).unwrap();
+        assert_eq!(required_directories, vec![PathBuf::from("/path/to/schema")]);
+        load_directory(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/schema"),
+            vec![
+                PathBuf::from("/path/to/schema/user.graphql"),
+                PathBuf::from("/path/to/schema/README.md"),
+            ],
+        )
+        .unwrap();
+        let required_directories =
+            get_required_directories(&mut tasks, task_id, &config, root_dir).unwrap();
+        assert_eq!(required_directories.len(), 0);
+
+        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        assert_eq!(
+            required_files,
+            vec![
+                PathBuf::from("/path/to/extra.graphql"),
+                PathBuf::from("/path/to/schema/user.graphql"),
+            ]
+        );
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/schema/user.graphql"),
+            "type User { id: ID! name: String! }".to_string(),
+        )
+        .unwrap();
+        load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/extra.graphql"),
+            "type Query { me: User! }".to_string(),
+        )
+        .unwrap();
+        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        assert_eq!(required_files.len(), 0);
+        let js = emit_js(&tasks, task_id, &config).unwrap();
+        assert_snapshot!(js);
+    }
+
+    #[test]
+    fn unsupported_schema_file() {
+        let config = Config {
+            schema: vec!["schema.json".to_owned()],
+            generate: GenerateConfig {
+                add_cache_fields: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut tasks = Tasks::new();
+        let task_id = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/op.graphql"),
+            "query Test { me { name } }".to_string(),
+        )
+        .unwrap();
+        let required_directories =
+            get_required_directories(&mut tasks, task_id, &config, Path::new("/path/to")).unwrap();
+        assert_eq!(required_directories.len(), 0);
+        let required_files = get_required_files(&mut tasks, task_id).unwrap();
+        assert_eq!(required_files, vec![PathBuf::from("/path/to/schema.json")]);
+        let result = load_file(
+            &mut tasks,
+            task_id,
+            PathBuf::from("/path/to/schema.json"),
+            "{}".to_string(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn project_by_documents() {
+        let projects = parse_config_projects(
+            r#"
+schema: schema.graphql
+extensions:
+  nitrogql:
+    generate:
+      addCacheFields: true
+projects:
+  app:
+    documents: app/**/*.graphql
+  admin:
+    documents: admin/**/*.graphql
+    extensions:
+      nitrogql:
+        clientSchema: admin/client.graphql
+"#,
+        )
+        .unwrap();
+        let root_dir = Path::new("/path/to");
+
+        let mut tasks = Tasks::new();
+        let app_task = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/app/op.graphql"),
+            "query Test { me { name } }".to_string(),
+        )
+        .unwrap();
+        let admin_task = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/admin/op.graphql"),
+            "query Test { me { name } }".to_string(),
+        )
+        .unwrap();
+        let other_task = initiate_task(
+            &mut tasks,
+            PathBuf::from("/path/to/other/op.graphql"),
+            "query Test { me { name } }".to_string(),
+        )
+        .unwrap();
+
+        let config = project_config(&tasks, app_task, &projects, root_dir).unwrap();
+        assert!(config.client_schema.is_empty());
+        let config = project_config(&tasks, admin_task, &projects, root_dir).unwrap();
+        assert_eq!(
+            config.client_schema,
+            vec!["admin/client.graphql".to_owned()]
+        );
+        // Client schema files are searched along with the schema.
+        get_required_directories(&mut tasks, admin_task, config, root_dir).unwrap();
+        let required_files = get_required_files(&mut tasks, admin_task).unwrap();
+        assert_eq!(
+            required_files,
+            vec![
+                PathBuf::from("/path/to/admin/client.graphql"),
+                PathBuf::from("/path/to/schema.graphql"),
+            ]
+        );
+        assert!(project_config(&tasks, other_task, &projects, root_dir).is_err());
+    }
 }
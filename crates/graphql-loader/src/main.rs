@@ -2,18 +2,21 @@
 mod js_printer;
 mod loader;
 mod logger;
+mod schema;
 mod tasks;
 
-use std::{cell::RefCell, slice};
+use std::{cell::RefCell, path::PathBuf, slice};
 
 use log::debug;
-use nitrogql_config_file::Config;
+use nitrogql_config_file::ProjectConfig;
 
 use crate::logger::StringLogger;
 
 thread_local! {
-    /// Loaded config.
-    static CONFIG: RefCell<Config> = RefCell::new(Config::default());
+    /// Configs of projects in loaded config file.
+    static PROJECTS: RefCell<Vec<ProjectConfig>> = const { RefCell::new(Vec::new()) };
+    /// Directory of loaded config file.
+    static ROOT_DIR: RefCell<PathBuf> = const { RefCell::new(PathBuf::new()) };
     /// Result of last operation.
     static RESULT: RefCell<Option<String>> = RefCell::new(None);
     /// Global set of tasks.
@@ -55,10 +58,23 @@ pub unsafe extern "C" fn free_string(ptr: *mut u8, len_bytes: usize) {
     let _ = unsafe { String::from_raw_parts(ptr, 0, len_bytes) };
 }
 
-/// Loads config from given source. Returns true if successful
+/// Loads config from given source. Returns true if successful.
+/// Paths in the config are resolved from the directory of given config file path.
+/// Error message is stored in `RESULT` on failure.
 #[no_mangle]
-pub extern "C" fn load_config(config_file_ptr: *const u8, config_file_len: usize) -> bool {
+pub extern "C" fn load_config(
+    config_file_path_ptr: *const u8,
+    config_file_path_len: usize,
+    config_file_ptr: *const u8,
+    config_file_len: usize,
+) -> bool {
+    let config_file_path = read_str_ptr(config_file_path_ptr, config_file_path_len);
     let config_file = read_str_ptr(config_file_ptr, config_file_len);
+    let root_dir = PathBuf::from(config_file_path)
+        .parent()
+        .map(|dir| dir.to_owned())
+        .unwrap_or_default();
+    ROOT_DIR.with(|cell| cell.replace(root_dir));
     load_config_impl(&config_file)
 }
 
@@ -88,6 +104,67 @@ pub extern "C" fn initiate_task(
     })
 }
 
+/// Get the list of directories whose files are required for the given task.
+/// Returns true if successful.
+/// Result is stored in `RESULT` and can be accessed by `get_result_ptr` and `get_result_size`.
+#[no_mangle]
+pub extern "C" fn get_required_directories(task_id: usize) -> bool {
+    debug!("get_required_directories {task_id}");
+    TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        let result = PROJECTS.with(|projects| {
+            ROOT_DIR.with(|root_dir| {
+                let projects = projects.borrow();
+                let root_dir = root_dir.borrow();
+                let config = loader::project_config(&tasks, task_id, &projects, &root_dir)?;
+                loader::get_required_directories(&mut tasks, task_id, config, &root_dir)
+            })
+        });
+        match result {
+            Ok(required_directories) => {
+                let required_directories = required_directories
+                    .into_iter()
+                    .map(|p| p.to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                RESULT.with(|cell| cell.replace(Some(required_directories)));
+                true
+            }
+            Err(err) => {
+                RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
+                false
+            }
+        }
+    })
+}
+
+/// Supply the list of files in a directory.
+/// Files are given as a newline-separated list of paths.
+/// Returns true if successful.
+#[no_mangle]
+pub extern "C" fn load_directory(
+    task_id: usize,
+    directory_ptr: *const u8,
+    directory_len: usize,
+    files_ptr: *const u8,
+    files_len: usize,
+) -> bool {
+    debug!("load_directory {task_id} {directory_ptr:?} {directory_len} {files_ptr:?} {files_len}");
+    let directory = read_str_ptr(directory_ptr, directory_len);
+    let files = read_str_ptr(files_ptr, files_len);
+    let files = files.lines().map(PathBuf::from).collect();
+    TASKS.with(|tasks| {
+        let mut tasks = tasks.borrow_mut();
+        match loader::load_directory(&mut tasks, task_id, directory.into(), files) {
+            Ok(_) => true,
+            Err(err) => {
+                RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
+                false
+            }
+        }
+    })
+}
+
 /// Get the list of additionally required files for the given task.
 /// Returns true if successful.
 /// Result is stored in `RESULT` and can be accessed by `get_result_ptr` and `get_result_size`.
@@ -148,18 +225,24 @@ pub extern "C" fn emit_js(task_id: usize) -> bool {
     debug!("convert_to_js {task_id}");
     TASKS.with(|tasks| {
         let tasks = tasks.borrow();
-        CONFIG.with(
-            |config| match loader::emit_js(&tasks, task_id, &config.borrow()) {
-                Ok(js) => {
-                    RESULT.with(|cell| cell.replace(Some(js)));
-                    true
-                }
-                Err(err) => {
-                    RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
-                    false
-                }
-            },
-        )
+        let result = PROJECTS.with(|projects| {
+            ROOT_DIR.with(|root_dir| {
+                let projects = projects.borrow();
+                let config =
+                    loader::project_config(&tasks, task_id, &projects, &root_dir.borrow())?;
+                loader::emit_js(&tasks, task_id, config)
+            })
+        });
+        match result {
+            Ok(js) => {
+                RESULT.with(|cell| cell.replace(Some(js)));
+                true
+            }
+            Err(err) => {
+                RESULT.with(|cell| cell.replace(Some(format!("{}", err.into_inner()))));
+                false
+            }
+        }
     })
 }
 
@@ -201,11 +284,14 @@ pub extern "C" fn get_log() {
 }
 
 fn load_config_impl(config_file: &str) -> bool {
-    let config = nitrogql_config_file::parse_config(config_file);
-    match config {
-        None => false,
-        Some(config) => {
-            CONFIG.with(|cell| cell.replace(config));
+    let projects = nitrogql_config_file::parse_config_projects(config_file);
+    match projects {
+        Err(err) => {
+            RESULT.with(|cell| cell.replace(Some(format!("Invalid config file: {err}"))));
+            false
+        }
+        Ok(projects) => {
+            PROJECTS.with(|cell| cell.replace(projects));
             true
         }
     }
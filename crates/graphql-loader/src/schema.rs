@@ -0,0 +1,140 @@
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use nitrogql_ast::{TypeSystemDocument, TypeSystemOrExtensionDocument};
+use nitrogql_error::{PositionedError, Result};
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_semantics::resolve_schema_extensions;
+
+use crate::loader::LoaderError;
+
+/// Schema files searched for a task.
+/// Schema globs are matched against lists of files supplied for their base directories.
+#[derive(Debug)]
+pub struct SchemaFiles {
+    matcher: GlobSet,
+    /// Directories whose files are not supplied yet.
+    pending_directories: Vec<PathBuf>,
+    /// Found schema files and their documents if loaded.
+    files: BTreeMap<PathBuf, Option<TypeSystemOrExtensionDocument<'static>>>,
+}
+
+impl SchemaFiles {
+    /// Creates a new set of schema files from schema globs.
+    /// Globs are resolved from `root_dir`.
+    pub fn new(root_dir: &Path, globs: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        let mut pending_directories = vec![];
+        let mut files = BTreeMap::new();
+        for glob in globs {
+            let path = root_dir.join(glob);
+            let Some(base_directory) = glob_base_directory(&path) else {
+                // Paths without glob patterns need not be searched.
+                files.insert(path, None);
+                continue;
+            };
+            let glob = GlobBuilder::new(&path.to_string_lossy())
+                .literal_separator(true)
+                .build()
+                .map_err(|err| LoaderError::InvalidSchemaGlob(err.to_string()))
+                .map_err(|err| PositionedError::new(err.into(), None, vec![]))?;
+            builder.add(glob);
+            if !pending_directories.contains(&base_directory) {
+                pending_directories.push(base_directory);
+            }
+        }
+        let matcher = builder
+            .build()
+            .map_err(|err| LoaderError::InvalidSchemaGlob(err.to_string()))
+            .map_err(|err| PositionedError::new(err.into(), None, vec![]))?;
+        Ok(Self {
+            matcher,
+            pending_directories,
+            files,
+        })
+    }
+
+    /// Returns directories whose files are required.
+    pub fn pending_directories(&self) -> &[PathBuf] {
+        &self.pending_directories
+    }
+
+    /// Supplies the list of files in a directory.
+    pub fn add_directory(&mut self, directory: &Path, files: impl IntoIterator<Item = PathBuf>) {
+        self.pending_directories.retain(|dir| dir != directory);
+        for file in files {
+            if self.matcher.is_match(&file) {
+                self.files.entry(file).or_insert(None);
+            }
+        }
+    }
+
+    /// Returns schema files that are found but not loaded yet.
+    pub fn pending_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.files
+            .iter()
+            .filter(|(_, document)| document.is_none())
+            .map(|(path, _)| path)
+    }
+
+    /// Checks if the given file is a schema file.
+    pub fn contains(&self, file_name: &Path) -> bool {
+        self.files.contains_key(file_name)
+    }
+
+    /// Registers the source of a schema file.
+    pub fn register(&mut self, file_name: PathBuf, source: &'static str) -> Result<()> {
+        if !matches!(
+            file_name.extension().and_then(|ext| ext.to_str()),
+            Some("graphql" | "gql")
+        ) {
+            return Err(PositionedError::new(
+                LoaderError::UnsupportedSchemaFile(file_name).into(),
+                None,
+                vec![],
+            ));
+        }
+        let document = parse_type_system_document(source)?;
+        self.files.insert(file_name, Some(document));
+        Ok(())
+    }
+
+    /// Returns the schema document made from all schema files.
+    pub fn document(&self) -> Result<TypeSystemDocument<'static>> {
+        let documents = self
+            .files
+            .values()
+            .map(|document| document.clone().ok_or(LoaderError::SchemaNotLoaded))
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .map_err(|err| PositionedError::new(err.into(), None, vec![]))?;
+        let document = resolve_schema_extensions(TypeSystemOrExtensionDocument::merge(documents))?;
+        Ok(document)
+    }
+
+    /// Drops loaded documents.
+    pub fn clear(&mut self) {
+        self.files.clear();
+    }
+}
+
+/// Returns the directory that contains all files matched by given glob.
+/// Returns None if the path does not contain glob patterns.
+fn glob_base_directory(path: &Path) -> Option<PathBuf> {
+    let is_pattern = |component: &Component| {
+        component
+            .as_os_str()
+            .to_string_lossy()
+            .contains(['*', '?', '[', '{'])
+    };
+    if !path.components().any(|component| is_pattern(&component)) {
+        return None;
+    }
+    Some(
+        path.components()
+            .take_while(|component| !is_pattern(component))
+            .collect(),
+    )
+}
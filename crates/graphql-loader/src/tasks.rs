@@ -8,6 +8,8 @@ use nitrogql_error::Result;
 use nitrogql_parser::parse_operation_document;
 use nitrogql_semantics::{resolve_operation_extensions, OperationExtension};
 
+use crate::schema::SchemaFiles;
+
 /// Set of tasks.
 #[derive(Debug)]
 pub struct Tasks {
@@ -56,6 +58,9 @@ pub struct Task {
     /// Set of loaded operation files.
     /// The root file should be present when initiating the task.
     loaded_files: HashMap<PathBuf, (OperationDocument<'static>, OperationExtension<'static>)>,
+    /// Schema files used by this task.
+    /// None if the schema is not needed or not searched yet.
+    pub schema_files: Option<SchemaFiles>,
     source_drop_list: Vec<(*mut u8, usize, usize)>,
 }
 
@@ -65,6 +70,7 @@ impl Task {
         Self {
             root_file_name,
             loaded_files: HashMap::new(),
+            schema_files: None,
             source_drop_list: Vec::new(),
         }
     }
@@ -103,6 +109,13 @@ impl Task {
         let raw_parts = (source.as_mut_ptr(), source.len(), source.capacity());
         let source = Box::leak(source.into_boxed_str());
         self.source_drop_list.push(raw_parts);
+        if let Some(schema_files) = self
+            .schema_files
+            .as_mut()
+            .filter(|schema_files| schema_files.contains(&file_name))
+        {
+            return schema_files.register(file_name, source);
+        }
         let document = parse_operation_document(source)?;
         let (document, extensions) = resolve_operation_extensions(document)?;
         self.loaded_files.insert(file_name, (document, extensions));
@@ -114,6 +127,9 @@ impl Drop for Task {
     fn drop(&mut self) {
         // need to drop loaded_files first
         self.loaded_files.clear();
+        if let Some(schema_files) = self.schema_files.as_mut() {
+            schema_files.clear();
+        }
         // then drop sources
         for (ptr, len, capacity) in self.source_drop_list.drain(..) {
             let _ = unsafe { String::from_raw_parts(ptr, len, capacity) };
@@ -0,0 +1,236 @@
+use std::collections::{HashMap, HashSet};
+
+use nitrogql_ast::{
+    base::Ident,
+    directive::Directive,
+    type_system::{
+        EnumTypeDefinition, FieldDefinition, InputObjectTypeDefinition, InputValueDefinition,
+        InterfaceTypeDefinition, ObjectTypeDefinition, ScalarTypeDefinition, SchemaDefinition,
+        TypeDefinition, TypeSystemDefinition, UnionTypeDefinition,
+    },
+    TypeSystemDocument,
+};
+
+/// Set of schema elements that remain in a document returned by an external plugin.
+/// Applying a shape to a document removes elements that are not in the shape.
+/// Built-in elements are never removed.
+#[derive(Debug, Default)]
+pub struct DocumentShape {
+    /// Directives applied to the schema definition, if the document has one.
+    schema: Option<HashSet<String>>,
+    types: HashMap<String, ElementShape>,
+    directive_definitions: HashSet<String>,
+}
+
+#[derive(Debug, Default)]
+struct ElementShape {
+    /// Names of directives applied to the element.
+    directives: HashSet<String>,
+    /// Fields or enum values of the element.
+    members: HashMap<String, HashSet<String>>,
+}
+
+impl ElementShape {
+    fn add(
+        &mut self,
+        directives: impl IntoIterator<Item = String>,
+        members: impl IntoIterator<Item = (String, Vec<String>)>,
+    ) {
+        self.directives.extend(directives);
+        for (name, directives) in members {
+            self.members.entry(name).or_default().extend(directives);
+        }
+    }
+
+    fn keeps_member(&self, name: &Ident) -> bool {
+        name.position.builtin || self.members.contains_key(name.name)
+    }
+
+    fn member_directives<'src>(
+        &self,
+        name: &Ident,
+        directives: &[Directive<'src>],
+    ) -> Vec<Directive<'src>> {
+        match self.members.get(name.name) {
+            Some(names) => retain_directives(directives, names),
+            None => directives.to_vec(),
+        }
+    }
+
+    fn filter_fields<'src>(&self, fields: &[FieldDefinition<'src>]) -> Vec<FieldDefinition<'src>> {
+        fields
+            .iter()
+            .filter(|field| self.keeps_member(&field.name))
+            .map(|field| FieldDefinition {
+                directives: self.member_directives(&field.name, &field.directives),
+                ..field.clone()
+            })
+            .collect()
+    }
+
+    fn filter_input_fields<'src>(
+        &self,
+        fields: &[InputValueDefinition<'src>],
+    ) -> Vec<InputValueDefinition<'src>> {
+        fields
+            .iter()
+            .filter(|field| self.keeps_member(&field.name))
+            .map(|field| InputValueDefinition {
+                directives: self.member_directives(&field.name, &field.directives),
+                ..field.clone()
+            })
+            .collect()
+    }
+}
+
+impl DocumentShape {
+    /// Builds a shape from a document in the JSON representation of graphql-js AST.
+    pub fn from_json_ast(document: &serde_json::Value) -> Self {
+        let mut shape = DocumentShape::default();
+        let definitions = document["definitions"].as_array().into_iter().flatten();
+        for def in definitions {
+            let kind = def["kind"].as_str().unwrap_or_default();
+            let name = def["name"]["value"].as_str().unwrap_or_default();
+            match kind {
+                "SchemaDefinition" | "SchemaExtension" => {
+                    shape.add_schema(json_directive_names(def));
+                }
+                "DirectiveDefinition" => {
+                    shape.directive_definitions.insert(name.to_owned());
+                }
+                _ if kind.ends_with("TypeDefinition") || kind.ends_with("TypeExtension") => {
+                    let members = ["fields", "values"]
+                        .into_iter()
+                        .flat_map(|key| def[key].as_array().into_iter().flatten())
+                        .map(|member| {
+                            (
+                                member["name"]["value"]
+                                    .as_str()
+                                    .unwrap_or_default()
+                                    .to_owned(),
+                                json_directive_names(member).collect(),
+                            )
+                        });
+                    shape.add_type(name, json_directive_names(def), members);
+                }
+                _ => {}
+            }
+        }
+        shape
+    }
+
+    fn add_schema(&mut self, directives: impl IntoIterator<Item = String>) {
+        self.schema
+            .get_or_insert_with(HashSet::new)
+            .extend(directives);
+    }
+
+    fn add_type(
+        &mut self,
+        name: &str,
+        directives: impl IntoIterator<Item = String>,
+        members: impl IntoIterator<Item = (String, Vec<String>)>,
+    ) {
+        self.types
+            .entry(name.to_owned())
+            .or_default()
+            .add(directives, members);
+    }
+
+    /// Removes elements that are not in this shape from given document.
+    pub fn filter<'src>(&self, document: &TypeSystemDocument<'src>) -> TypeSystemDocument<'src> {
+        let definitions = document
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                // Schema definition is kept as it determines root types.
+                TypeSystemDefinition::SchemaDefinition(def) => {
+                    Some(TypeSystemDefinition::SchemaDefinition(match &self.schema {
+                        Some(directives) => SchemaDefinition {
+                            directives: retain_directives(&def.directives, directives),
+                            ..def.clone()
+                        },
+                        None => def.clone(),
+                    }))
+                }
+                TypeSystemDefinition::DirectiveDefinition(def) => (def.position.builtin
+                    || self.directive_definitions.contains(def.name.name))
+                .then(|| TypeSystemDefinition::DirectiveDefinition(def.clone())),
+                TypeSystemDefinition::TypeDefinition(def) => {
+                    if def.name().position.builtin {
+                        return Some(TypeSystemDefinition::TypeDefinition(def.clone()));
+                    }
+                    let shape = self.types.get(def.name().name)?;
+                    Some(TypeSystemDefinition::TypeDefinition(
+                        self.filter_type(def, shape),
+                    ))
+                }
+            })
+            .collect();
+        TypeSystemDocument { definitions }
+    }
+
+    fn filter_type<'src>(
+        &self,
+        def: &TypeDefinition<'src>,
+        shape: &ElementShape,
+    ) -> TypeDefinition<'src> {
+        match def {
+            TypeDefinition::Scalar(def) => TypeDefinition::Scalar(ScalarTypeDefinition {
+                directives: retain_directives(&def.directives, &shape.directives),
+                ..def.clone()
+            }),
+            TypeDefinition::Object(def) => TypeDefinition::Object(ObjectTypeDefinition {
+                directives: retain_directives(&def.directives, &shape.directives),
+                fields: shape.filter_fields(&def.fields),
+                ..def.clone()
+            }),
+            TypeDefinition::Interface(def) => TypeDefinition::Interface(InterfaceTypeDefinition {
+                directives: retain_directives(&def.directives, &shape.directives),
+                fields: shape.filter_fields(&def.fields),
+                ..def.clone()
+            }),
+            TypeDefinition::Union(def) => TypeDefinition::Union(UnionTypeDefinition {
+                directives: retain_directives(&def.directives, &shape.directives),
+                ..def.clone()
+            }),
+            TypeDefinition::Enum(def) => TypeDefinition::Enum(EnumTypeDefinition {
+                directives: retain_directives(&def.directives, &shape.directives),
+                values: def
+                    .values
+                    .iter()
+                    .filter(|value| shape.keeps_member(&value.name))
+                    .cloned()
+                    .collect(),
+                ..def.clone()
+            }),
+            TypeDefinition::InputObject(def) => {
+                TypeDefinition::InputObject(InputObjectTypeDefinition {
+                    directives: retain_directives(&def.directives, &shape.directives),
+                    fields: shape.filter_input_fields(&def.fields),
+                    ..def.clone()
+                })
+            }
+        }
+    }
+}
+
+fn json_directive_names(node: &serde_json::Value) -> impl Iterator<Item = String> + '_ {
+    node["directives"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|directive| directive["name"]["value"].as_str())
+        .map(|name| name.to_owned())
+}
+
+fn retain_directives<'src>(
+    directives: &[Directive<'src>],
+    names: &HashSet<String>,
+) -> Vec<Directive<'src>> {
+    directives
+        .iter()
+        .filter(|directive| directive.position.builtin || names.contains(directive.name.name))
+        .cloned()
+        .collect()
+}
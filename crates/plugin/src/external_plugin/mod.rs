@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use nitrogql_ast::{
+    base::Pos,
+    type_system::{TypeDefinition, TypeSystemDefinition},
+    TypeSystemDocument,
+};
+use nitrogql_printer::ts_types::TSType;
+use serde::Deserialize;
+use sourcemap_writer::JustWriter;
+use thiserror::Error;
+
+use crate::{PluginCheckError, PluginCheckResult, PluginOutputContent, PluginOutputFile};
+
+pub(crate) use self::document_shape::DocumentShape;
+pub use self::operation::{external_operation_check_result, parse_external_operation_document};
+pub use self::type_system::{
+    parse_external_type_system_document, print_external_type_system_document,
+};
+
+mod document_shape;
+mod operation;
+mod tests;
+mod type_system;
+
+/// Error reported by an external plugin.
+#[derive(Debug, Deserialize)]
+pub struct ExternalPluginCheckError {
+    /// Error message.
+    pub message: String,
+    /// Coordinate of the element that the error is about.
+    /// Schema coordinates for the schema (e.g. `User`, `User.name` or `@auth`)
+    /// and operation coordinates for operations (e.g. `GetUser.user.name`).
+    pub coordinate: Option<String>,
+}
+
+/// File generated by an external plugin.
+#[derive(Debug, Deserialize)]
+pub struct ExternalPluginOutputFile {
+    /// Path of the file, relative to the configuration file.
+    pub path: String,
+    pub content: String,
+}
+
+impl From<ExternalPluginOutputFile> for PluginOutputFile {
+    fn from(file: ExternalPluginOutputFile) -> Self {
+        PluginOutputFile {
+            path: file.path.into(),
+            content: PluginOutputContent::Text(file.content),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ExternalPluginOutputError {
+    #[error("Plugin returned an invalid document: {0}")]
+    InvalidDocument(String),
+}
+
+/// Converts errors that an external plugin reported for the schema.
+pub fn external_schema_check_result(
+    schema: &TypeSystemDocument,
+    errors: &[ExternalPluginCheckError],
+) -> PluginCheckResult {
+    PluginCheckResult {
+        errors: errors
+            .iter()
+            .map(|error| PluginCheckError {
+                position: error
+                    .coordinate
+                    .as_deref()
+                    .and_then(|coordinate| coordinate_position(schema, coordinate))
+                    .unwrap_or_else(Pos::builtin),
+                message: error.message.clone(),
+                additional_info: vec![],
+            })
+            .collect(),
+    }
+}
+
+/// Prints resolver output types so that they can be given to an external plugin.
+pub fn print_resolver_output_types<'src>(
+    types: &HashMap<&'src str, TSType>,
+) -> HashMap<&'src str, String> {
+    types
+        .iter()
+        .map(|(type_name, ts_type)| {
+            let mut source = String::new();
+            ts_type.print_type(&mut JustWriter::new(&mut source));
+            (*type_name, source)
+        })
+        .collect()
+}
+
+/// Replaces resolver output types with TypeScript types given by an external plugin.
+pub fn apply_resolver_output_types<'src>(
+    types: &HashMap<String, String>,
+    mut base: HashMap<&'src str, TSType>,
+) -> HashMap<&'src str, TSType> {
+    for (type_name, ts_type) in types.iter() {
+        if let Some(base_type) = base.get_mut(type_name.as_str()) {
+            *base_type = TSType::Raw(ts_type.clone());
+        }
+    }
+    base
+}
+
+/// Finds the position of the schema element referred by given schema coordinate.
+fn coordinate_position(schema: &TypeSystemDocument, coordinate: &str) -> Option<Pos> {
+    if let Some(directive_name) = coordinate.strip_prefix('@') {
+        return schema.definitions.iter().find_map(|def| match def {
+            TypeSystemDefinition::DirectiveDefinition(def) if def.name.name == directive_name => {
+                Some(def.name.position)
+            }
+            _ => None,
+        });
+    }
+    let (type_name, member_name) = match coordinate.split_once('.') {
+        Some((type_name, member_name)) => (type_name, Some(member_name)),
+        None => (coordinate, None),
+    };
+    let def = schema.definitions.iter().find_map(|def| match def {
+        TypeSystemDefinition::TypeDefinition(def) if def.name().name == type_name => Some(def),
+        _ => None,
+    })?;
+    let Some(member_name) = member_name else {
+        return Some(def.name().position);
+    };
+    let member_names: Vec<_> = match def {
+        TypeDefinition::Object(def) => def.fields.iter().map(|field| &field.name).collect(),
+        TypeDefinition::Interface(def) => def.fields.iter().map(|field| &field.name).collect(),
+        TypeDefinition::InputObject(def) => def.fields.iter().map(|field| &field.name).collect(),
+        TypeDefinition::Enum(def) => def.values.iter().map(|value| &value.name).collect(),
+        TypeDefinition::Scalar(_) | TypeDefinition::Union(_) => vec![],
+    };
+    member_names
+        .into_iter()
+        .find(|name| name.name == member_name)
+        .map(|name| name.position)
+}
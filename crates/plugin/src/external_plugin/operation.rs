@@ -0,0 +1,128 @@
+use nitrogql_ast::{
+    base::Pos,
+    operation::{ExecutableDefinition, OperationDocument},
+    selection_set::{Field, Selection, SelectionSet},
+    set_current_file_of_pos,
+};
+use nitrogql_parser::parse_operation_document;
+use nitrogql_semantics::resolve_operation_extensions;
+
+use crate::{PluginCheckError, PluginCheckResult};
+
+use super::{ExternalPluginCheckError, ExternalPluginOutputError};
+
+/// Converts errors that an external plugin reported for an operation document.
+/// Coordinates of errors name an operation or a fragment, optionally followed by
+/// response keys of selected fields (e.g. `GetUser` or `GetUser.user.name`).
+/// Errors without a known coordinate point to the document.
+pub fn external_operation_check_result(
+    operation: &OperationDocument,
+    errors: &[ExternalPluginCheckError],
+) -> PluginCheckResult {
+    PluginCheckResult {
+        errors: errors
+            .iter()
+            .map(|error| PluginCheckError {
+                position: error
+                    .coordinate
+                    .as_deref()
+                    .and_then(|coordinate| operation_coordinate_position(operation, coordinate))
+                    .unwrap_or(operation.position),
+                message: error.message.clone(),
+                additional_info: vec![],
+            })
+            .collect(),
+    }
+}
+
+/// Parses an operation document that an external plugin returned as GraphQL source.
+/// Definitions also found in `original` keep the file they come from,
+/// so that fragments imported from other files are still treated as imported.
+pub fn parse_external_operation_document<'src>(
+    original: &OperationDocument<'src>,
+    source: String,
+) -> Result<OperationDocument<'src>, ExternalPluginOutputError> {
+    // Documents borrow their source, which lives as long as loaded files.
+    let source: &'static str = Box::leak(source.into_boxed_str());
+    set_current_file_of_pos(original.position.file);
+    let document = parse_operation_document(source)
+        .map_err(|err| ExternalPluginOutputError::InvalidDocument(err.into_message()))?;
+    let (mut document, _) = resolve_operation_extensions(document)
+        .map_err(|err| ExternalPluginOutputError::InvalidDocument(err.message.to_string()))?;
+    for def in document.definitions.iter_mut() {
+        let Some(file) = original
+            .definitions
+            .iter()
+            .find(|original_def| same_definition(original_def, def))
+            .map(|original_def| definition_position(original_def).file)
+        else {
+            continue;
+        };
+        match def {
+            ExecutableDefinition::OperationDefinition(def) => def.position.file = file,
+            ExecutableDefinition::FragmentDefinition(def) => def.position.file = file,
+        }
+    }
+    Ok(document)
+}
+
+fn same_definition(left: &ExecutableDefinition, right: &ExecutableDefinition) -> bool {
+    match (left, right) {
+        (
+            ExecutableDefinition::OperationDefinition(left),
+            ExecutableDefinition::OperationDefinition(right),
+        ) => left.name.as_ref().map(|name| name.name) == right.name.as_ref().map(|name| name.name),
+        (
+            ExecutableDefinition::FragmentDefinition(left),
+            ExecutableDefinition::FragmentDefinition(right),
+        ) => left.name.name == right.name.name,
+        _ => false,
+    }
+}
+
+fn definition_position(def: &ExecutableDefinition) -> Pos {
+    match def {
+        ExecutableDefinition::OperationDefinition(def) => def.position,
+        ExecutableDefinition::FragmentDefinition(def) => def.position,
+    }
+}
+
+/// Finds the position of the selection referred by given operation coordinate.
+fn operation_coordinate_position(document: &OperationDocument, coordinate: &str) -> Option<Pos> {
+    let mut keys = coordinate.split('.');
+    let definition_name = keys.next()?;
+    let (mut position, selection_set) = document.definitions.iter().find_map(|def| match def {
+        ExecutableDefinition::OperationDefinition(def) => def
+            .name
+            .as_ref()
+            .filter(|name| name.name == definition_name)
+            .map(|name| (name.position, &def.selection_set)),
+        ExecutableDefinition::FragmentDefinition(def) => {
+            (def.name.name == definition_name).then_some((def.name.position, &def.selection_set))
+        }
+    })?;
+    let mut selection_set = Some(selection_set);
+    for key in keys {
+        let field = find_field(selection_set?, key)?;
+        position = field.alias.as_ref().unwrap_or(&field.name).position;
+        selection_set = field.selection_set.as_ref();
+    }
+    Some(position)
+}
+
+/// Finds a field selected with given response key, including ones in inline fragments.
+fn find_field<'a, 'src>(
+    selection_set: &'a SelectionSet<'src>,
+    key: &str,
+) -> Option<&'a Field<'src>> {
+    selection_set
+        .selections
+        .iter()
+        .find_map(|selection| match selection {
+            Selection::Field(field) => {
+                (field.alias.as_ref().unwrap_or(&field.name).name == key).then_some(field)
+            }
+            Selection::FragmentSpread(_) => None,
+            Selection::InlineFragment(fragment) => find_field(&fragment.selection_set, key),
+        })
+}
@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+use std::collections::HashMap;
+
+use graphql_builtins::generate_builtins;
+use insta::assert_snapshot;
+use nitrogql_ast::{
+    base::HasPos,
+    type_system::{ObjectTypeDefinition, TypeDefinition, TypeSystemDefinition},
+    TypeSystemDocument,
+};
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_printer::ts_types::TSType;
+use nitrogql_semantics::resolve_schema_extensions;
+
+use crate::{
+    apply_resolver_output_types, external_schema_check_result, parse_external_type_system_document,
+    print_external_type_system_document, print_resolver_output_types, ExternalPluginCheckError,
+    ExternalPluginOutputError,
+};
+
+#[test]
+fn check_errors_with_coordinates() {
+    let doc = parse_to_type_system_document(
+        "
+directive @auth on FIELD_DEFINITION
+
+type User {
+    id: ID!
+    name: String! @auth
+}
+",
+    );
+    let errors: Vec<ExternalPluginCheckError> = serde_json::from_str(
+        r#"[
+    { "message": "type error", "coordinate": "User" },
+    { "message": "field error", "coordinate": "User.name" },
+    { "message": "directive error", "coordinate": "@auth" },
+    { "message": "unknown coordinate", "coordinate": "Post" },
+    { "message": "no coordinate" }
+]"#,
+    )
+    .unwrap();
+    let errors = external_schema_check_result(&doc, &errors)
+        .errors
+        .into_iter()
+        .map(|error| {
+            (
+                error.message,
+                (!error.position.builtin).then_some((error.position.line, error.position.column)),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("type error".to_owned(), Some((3, 5))),
+            ("field error".to_owned(), Some((5, 4))),
+            ("directive error".to_owned(), Some((1, 11))),
+            ("unknown coordinate".to_owned(), None),
+            ("no coordinate".to_owned(), None),
+        ]
+    );
+}
+
+#[test]
+fn resolver_output_types() {
+    let base = HashMap::from([
+        ("User", TSType::TypeVariable("User".into())),
+        ("Post", TSType::TypeVariable("Post".into())),
+    ]);
+    let printed = print_resolver_output_types(&base);
+    assert_eq!(printed["User"], "User");
+    let output = HashMap::from([
+        ("User".to_owned(), "import('./model').User".to_owned()),
+        ("Unknown".to_owned(), "never".to_owned()),
+    ]);
+    let result = apply_resolver_output_types(&output, base);
+    assert_eq!(result.len(), 2);
+    assert!(matches!(&result["User"], TSType::Raw(t) if t == "import('./model').User"));
+    assert!(matches!(&result["Post"], TSType::TypeVariable(_)));
+}
+
+#[test]
+fn document_transform() {
+    let doc = parse_to_type_system_document(
+        r#"
+directive @model on FIELD_DEFINITION
+
+type Query {
+    me: User!
+}
+
+type User {
+    id: ID! @model
+    name: String! @model
+    posts: [Post!]!
+}
+
+type Post {
+    id: ID!
+}
+"#,
+    );
+    let source = print_external_type_system_document(&doc);
+    assert!(!source.contains("scalar String"));
+    let transformed = parse_external_type_system_document(
+        &doc,
+        "type Query { me: User! }\ntype User { posts: [Post!]! }\nextend type User { postCount: Int! }\ntype Post { id: ID! }\ntype PostConnection { nodes: [Post!]! }".to_owned(),
+    )
+    .unwrap();
+    assert_snapshot!(print_external_type_system_document(&transformed));
+    let user = find_object_type(&transformed, "User");
+    // Positions of elements in the original document are kept.
+    assert_eq!(
+        user.name.position,
+        find_object_type(&doc, "User").name.position
+    );
+    assert_eq!(
+        user.fields[0].name.position,
+        find_object_type(&doc, "User").fields[2].name.position
+    );
+    // Elements added by the plugin have no position in source files.
+    assert!(user.fields[1].name.position.builtin);
+    assert!(
+        find_object_type(&transformed, "PostConnection")
+            .name
+            .position
+            .builtin
+    );
+    // Built-in definitions are taken from the original document.
+    assert!(transformed
+        .definitions
+        .iter()
+        .any(|def| def.position().builtin && def.name() == Some("String")));
+}
+
+#[test]
+fn invalid_document() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let result = parse_external_type_system_document(&doc, "type {".to_owned());
+    assert!(matches!(
+        result,
+        Err(ExternalPluginOutputError::InvalidDocument(_))
+    ));
+    let result =
+        parse_external_type_system_document(&doc, "extend type User { id: ID! }".to_owned());
+    assert!(matches!(
+        result,
+        Err(ExternalPluginOutputError::InvalidDocument(_))
+    ));
+}
+
+fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
+    let mut doc = parse_type_system_document(source).unwrap();
+    doc.extend(generate_builtins());
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn find_object_type<'a, 'src>(
+    document: &'a TypeSystemDocument<'src>,
+    name: &str,
+) -> &'a ObjectTypeDefinition<'src> {
+    document
+        .definitions
+        .iter()
+        .find_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def))
+                if def.name.name == name =>
+            {
+                Some(def)
+            }
+            _ => None,
+        })
+        .unwrap()
+}
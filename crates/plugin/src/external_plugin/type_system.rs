@@ -0,0 +1,181 @@
+use nitrogql_ast::{
+    base::{HasPos, Ident, Pos},
+    set_current_file_of_pos,
+    type_system::{FieldDefinition, InputValueDefinition, TypeDefinition, TypeSystemDefinition},
+    TypeSystemDocument,
+};
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_printer::GraphQLPrinter;
+use nitrogql_semantics::resolve_schema_extensions;
+use sourcemap_writer::JustWriter;
+
+use super::ExternalPluginOutputError;
+
+/// Prints a schema document for an external plugin.
+/// Built-in definitions are omitted.
+pub fn print_external_type_system_document(document: &TypeSystemDocument) -> String {
+    let document = TypeSystemDocument {
+        definitions: document
+            .definitions
+            .iter()
+            .filter(|def| !def.position().builtin)
+            .cloned()
+            .collect(),
+    };
+    let mut result = String::new();
+    document.print_graphql(&mut JustWriter::new(&mut result));
+    result
+}
+
+/// Parses a schema document that an external plugin returned as GraphQL source.
+/// The source is expected to omit built-in definitions, which are taken from `original`.
+/// Names also found in `original` keep their positions so that generated code maps
+/// to the schema files. Other names are treated as built-in because they have no source.
+pub fn parse_external_type_system_document<'src>(
+    original: &TypeSystemDocument<'src>,
+    source: String,
+) -> Result<TypeSystemDocument<'src>, ExternalPluginOutputError> {
+    // Documents borrow their source, which lives as long as loaded files.
+    let source: &'static str = Box::leak(source.into_boxed_str());
+    if let Some(def) = original
+        .definitions
+        .iter()
+        .find(|def| !def.position().builtin)
+    {
+        set_current_file_of_pos(def.position().file);
+    }
+    let document = parse_type_system_document(source)
+        .map_err(|err| ExternalPluginOutputError::InvalidDocument(err.into_message()))?;
+    let mut document = resolve_schema_extensions(document)
+        .map_err(|err| ExternalPluginOutputError::InvalidDocument(err.message.to_string()))?;
+    for def in document.definitions.iter_mut() {
+        match def {
+            TypeSystemDefinition::SchemaDefinition(_) => {}
+            TypeSystemDefinition::TypeDefinition(def) => {
+                let original_def =
+                    original
+                        .definitions
+                        .iter()
+                        .find_map(|original_def| match original_def {
+                            TypeSystemDefinition::TypeDefinition(original_def)
+                                if original_def.name().name == def.name().name =>
+                            {
+                                Some(original_def)
+                            }
+                            _ => None,
+                        });
+                restore_type_positions(def, original_def);
+            }
+            TypeSystemDefinition::DirectiveDefinition(def) => {
+                let original_def =
+                    original
+                        .definitions
+                        .iter()
+                        .find_map(|original_def| match original_def {
+                            TypeSystemDefinition::DirectiveDefinition(original_def)
+                                if original_def.name.name == def.name.name =>
+                            {
+                                Some(original_def)
+                            }
+                            _ => None,
+                        });
+                restore_name(&mut def.name, original_def.map(|def| &def.name));
+                restore_input_values(
+                    def.arguments
+                        .iter_mut()
+                        .flat_map(|arguments| arguments.input_values.iter_mut()),
+                    original_def
+                        .and_then(|def| def.arguments.as_ref())
+                        .map_or(&[], |arguments| &arguments.input_values),
+                );
+            }
+        }
+    }
+    document.definitions.extend(
+        original
+            .definitions
+            .iter()
+            .filter(|def| def.position().builtin)
+            .cloned(),
+    );
+    Ok(document)
+}
+
+fn restore_type_positions(def: &mut TypeDefinition, original: Option<&TypeDefinition>) {
+    let original_name = original.map(|def| def.name());
+    match def {
+        TypeDefinition::Scalar(def) => restore_name(&mut def.name, original_name),
+        TypeDefinition::Union(def) => restore_name(&mut def.name, original_name),
+        TypeDefinition::Object(def) => {
+            restore_name(&mut def.name, original_name);
+            let original_fields = match original {
+                Some(TypeDefinition::Object(original)) => &original.fields[..],
+                _ => &[],
+            };
+            restore_fields(&mut def.fields, original_fields);
+        }
+        TypeDefinition::Interface(def) => {
+            restore_name(&mut def.name, original_name);
+            let original_fields = match original {
+                Some(TypeDefinition::Interface(original)) => &original.fields[..],
+                _ => &[],
+            };
+            restore_fields(&mut def.fields, original_fields);
+        }
+        TypeDefinition::Enum(def) => {
+            restore_name(&mut def.name, original_name);
+            let original_values = match original {
+                Some(TypeDefinition::Enum(original)) => &original.values[..],
+                _ => &[],
+            };
+            for value in def.values.iter_mut() {
+                let original_value = original_values
+                    .iter()
+                    .find(|original_value| original_value.name.name == value.name.name);
+                restore_name(&mut value.name, original_value.map(|value| &value.name));
+            }
+        }
+        TypeDefinition::InputObject(def) => {
+            restore_name(&mut def.name, original_name);
+            let original_fields = match original {
+                Some(TypeDefinition::InputObject(original)) => &original.fields[..],
+                _ => &[],
+            };
+            restore_input_values(def.fields.iter_mut(), original_fields);
+        }
+    }
+}
+
+fn restore_fields(fields: &mut [FieldDefinition], original: &[FieldDefinition]) {
+    for field in fields.iter_mut() {
+        let original_field = original
+            .iter()
+            .find(|original_field| original_field.name.name == field.name.name);
+        restore_name(&mut field.name, original_field.map(|field| &field.name));
+        restore_input_values(
+            field
+                .arguments
+                .iter_mut()
+                .flat_map(|arguments| arguments.input_values.iter_mut()),
+            original_field
+                .and_then(|field| field.arguments.as_ref())
+                .map_or(&[], |arguments| &arguments.input_values),
+        );
+    }
+}
+
+fn restore_input_values<'a, 'src: 'a>(
+    values: impl Iterator<Item = &'a mut InputValueDefinition<'src>>,
+    original: &[InputValueDefinition],
+) {
+    for value in values {
+        let original_value = original
+            .iter()
+            .find(|original_value| original_value.name.name == value.name.name);
+        restore_name(&mut value.name, original_value.map(|value| &value.name));
+    }
+}
+
+fn restore_name(name: &mut Ident, original: Option<&Ident>) {
+    name.position = original.map_or_else(Pos::builtin, |original| original.position);
+}
@@ -1,9 +1,21 @@
+mod external_plugin;
 mod graphql_scalars_plugin;
 mod model_plugin;
 mod plugin;
 mod plugin_v1;
+mod wasm_plugin;
 
+pub use external_plugin::{
+    apply_resolver_output_types, external_operation_check_result, external_schema_check_result,
+    parse_external_operation_document, parse_external_type_system_document,
+    print_external_type_system_document, print_resolver_output_types, ExternalPluginCheckError,
+    ExternalPluginOutputError, ExternalPluginOutputFile,
+};
 pub use graphql_scalars_plugin::GraphQLScalarsPlugin;
-pub use model_plugin::ModelPlugin;
+pub use model_plugin::{ModelPlugin, ModelPluginOptions};
 pub use plugin::{Plugin, PluginHost};
-pub use plugin_v1::{PluginCheckError, PluginCheckResult, PluginSchemaExtensions, PluginV1Beta};
+pub use plugin_v1::{
+    PluginCheckError, PluginCheckResult, PluginFuture, PluginGenerateInput, PluginOutputContent,
+    PluginOutputFile, PluginPrepareFuture, PluginSchemaExtensions, PluginV1Beta,
+};
+pub use wasm_plugin::{WasmPlugin, WasmPluginError};
@@ -10,32 +10,57 @@ use nitrogql_printer::{
     ts_types::{ts_types_util::ts_union, TSType},
     ResolverTypePrinterOptions,
 };
+use serde::Deserialize;
 
 use crate::{
     plugin_v1::{PluginCheckResult, PluginV1Beta},
-    PluginCheckError,
+    PluginCheckError, PluginFuture,
 };
 
 mod tests;
 
 /// Plugin that adds a @model directive to the schema.
-#[derive(Debug)]
-pub struct ModelPlugin {}
+#[derive(Debug, Default)]
+pub struct ModelPlugin {
+    options: ModelPluginOptions,
+}
+
+/// Options of the model plugin.
+#[derive(Debug, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModelPluginOptions {
+    /// Name of the directive provided by the plugin.
+    pub directive_name: String,
+}
+
+impl Default for ModelPluginOptions {
+    fn default() -> Self {
+        Self {
+            directive_name: "model".to_owned(),
+        }
+    }
+}
+
+impl ModelPlugin {
+    pub fn new(options: ModelPluginOptions) -> Self {
+        Self { options }
+    }
+}
 
 impl PluginV1Beta for ModelPlugin {
     fn name(&self) -> &str {
         "nitrogql:model-plugin"
     }
     fn schema_addition(&self) -> Option<String> {
-        Some(
+        Some(format!(
             r#"
-directive @model(
+directive @{}(
   # TypeScript type of this object. Only applicable for whole objects.
   type: String
 ) on OBJECT | FIELD_DEFINITION
-"#
-            .into(),
-        )
+"#,
+            self.options.directive_name
+        ))
     }
     fn check_schema(&self, schema: &TypeSystemDocument) -> PluginCheckResult {
         // Check usage of the model directive
@@ -49,7 +74,7 @@ directive @model(
                     let model_directive = def
                         .directives
                         .iter()
-                        .find(|directive| directive.name.name == "model");
+                        .find(|directive| directive.name.name == self.options.directive_name);
 
                     if let Some(directive) = model_directive {
                         // Check type argument
@@ -79,13 +104,13 @@ directive @model(
                         let model_directive = field
                             .directives
                             .iter()
-                            .find(|directive| directive.name.name == "model");
+                            .find(|directive| directive.name.name == self.options.directive_name);
 
                         if let Some(directive) = model_directive {
                             if has_object_model_directive {
                                 errors.push(PluginCheckError {
                                     position: directive.position,
-                                    message: "model directive cannot be used on fields if it is already used on the object".into(),
+                                    message: format!("{} directive cannot be used on fields if it is already used on the object", self.options.directive_name),
                                     additional_info: vec![],
                                 });
                             }
@@ -111,12 +136,15 @@ directive @model(
                         let model_directive = field
                             .directives
                             .iter()
-                            .find(|directive| directive.name.name == "model");
+                            .find(|directive| directive.name.name == self.options.directive_name);
 
                         if let Some(directive) = model_directive {
                             errors.push(PluginCheckError {
                                 position: directive.position,
-                                message: "model directive cannot be used on interfaces".into(),
+                                message: format!(
+                                    "{} directive cannot be used on interfaces",
+                                    self.options.directive_name
+                                ),
                                 additional_info: vec![],
                             });
                         }
@@ -127,152 +155,168 @@ directive @model(
         }
         PluginCheckResult { errors }
     }
-    fn transform_resolver_output_types<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-        options: &ResolverTypePrinterOptions,
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        options: &'a ResolverTypePrinterOptions,
         mut base: HashMap<&'src str, TSType>,
-    ) -> HashMap<&'src str, TSType> {
-        for def in document.definitions.iter() {
-            if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
-                let model_directive = def
-                    .directives
-                    .iter()
-                    .find(|directive| directive.name.name == "model");
-                if let Some(d) = model_directive {
-                    let type_arg = d
-                        .arguments
+    ) -> PluginFuture<'a, Result<HashMap<&'src str, TSType>, String>> {
+        Box::pin(async move {
+            for def in document.definitions.iter() {
+                if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
+                    let model_directive = def
+                        .directives
                         .iter()
-                        .flatten()
-                        .find(|(arg, _)| arg.name == "type");
-                    let Some((_, value)) = type_arg else {
-                        panic!("'type' argument is required");
-                    };
-                    let Value::StringValue(value) = value else {
+                        .find(|directive| directive.name.name == self.options.directive_name);
+                    if let Some(d) = model_directive {
+                        let type_arg = d
+                            .arguments
+                            .iter()
+                            .flatten()
+                            .find(|(arg, _)| arg.name == "type");
+                        let Some((_, value)) = type_arg else {
+                            panic!("'type' argument is required");
+                        };
+                        let Value::StringValue(value) = value else {
+                            continue;
+                        };
+                        // if @model(type: "...") is applied to a whole object,
+                        // then we need to replace the type of the object with the specified type
+                        *base.get_mut(&def.name.name).expect("object not found") =
+                            TSType::Raw(value.value.clone());
                         continue;
-                    };
-                    // if @model(type: "...") is applied to a whole object,
-                    // then we need to replace the type of the object with the specified type
-                    *base.get_mut(&def.name.name).expect("object not found") =
-                        TSType::Raw(value.value.clone());
-                    continue;
-                }
+                    }
 
-                let model_field_names = def.fields.iter().filter_map(|field| {
-                    field
-                        .directives
-                        .iter()
-                        .any(|directive| directive.name.name == "model")
-                        .then_some(field.name.name)
-                });
-                let obj_type = TSType::TypeFunc(
-                    Box::new(TSType::TypeVariable("Pick".into())),
-                    vec![
-                        TSType::NamespaceMember3(
-                            options.schema_root_namespace.clone(),
-                            TypeTarget::ResolverOutput.to_string(),
-                            def.name.name.into(),
-                        ),
-                        ts_union(model_field_names.map(|n| TSType::StringLiteral(n.into()))),
-                    ],
-                );
-                base.insert(def.name.name, obj_type);
+                    let model_field_names = def.fields.iter().filter_map(|field| {
+                        field
+                            .directives
+                            .iter()
+                            .any(|directive| directive.name.name == self.options.directive_name)
+                            .then_some(field.name.name)
+                    });
+                    let obj_type = TSType::TypeFunc(
+                        Box::new(TSType::TypeVariable("Pick".into())),
+                        vec![
+                            TSType::NamespaceMember3(
+                                options.schema_root_namespace.clone(),
+                                TypeTarget::ResolverOutput.to_string(),
+                                def.name.name.into(),
+                            ),
+                            ts_union(model_field_names.map(|n| TSType::StringLiteral(n.into()))),
+                        ],
+                    );
+                    base.insert(def.name.name, obj_type);
+                }
             }
-        }
-        base
+            Ok(base)
+        })
     }
-    fn transform_document_for_resolvers<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
-        let definitions = document.definitions.iter().map(|def| {
-            if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
-                let model_directive = def
-                    .directives
-                    .iter()
-                    .find(|directive| directive.name.name == "model");
-                if model_directive.is_some() {
-                    // If whole object is @model-ed, then you need to define
-                    // resolvers for all fields.
-                    return TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(
-                        def.clone(),
-                    ));
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(async move {
+            let definitions = document.definitions.iter().filter_map(|def| {
+                if let TypeSystemDefinition::DirectiveDefinition(def) = def {
+                    if def.name.name == self.options.directive_name {
+                        // @model is not implemented by servers.
+                        return None;
+                    }
                 }
-
-                let fields = def.fields.iter().filter_map(|field| {
-                    let model_directive = field
+                if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
+                    let model_directive = def
                         .directives
                         .iter()
-                        .find(|directive| directive.name.name == "model");
-                    if model_directive.is_none() {
-                        return Some(field.clone());
+                        .find(|directive| directive.name.name == self.options.directive_name);
+                    if model_directive.is_some() {
+                        // If whole object is @model-ed, then you need to define
+                        // resolvers for all fields.
+                        return Some(TypeSystemDefinition::TypeDefinition(
+                            TypeDefinition::Object(def.clone()),
+                        ));
                     }
-                    None
-                });
-                TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(ObjectTypeDefinition {
-                    fields: fields.collect(),
-                    ..def.clone()
-                }))
-            } else {
-                def.clone()
-            }
-        });
 
-        Some(TypeSystemDocument {
-            definitions: definitions.collect(),
+                    let fields = def.fields.iter().filter_map(|field| {
+                        let model_directive = field
+                            .directives
+                            .iter()
+                            .find(|directive| directive.name.name == self.options.directive_name);
+                        if model_directive.is_none() {
+                            return Some(field.clone());
+                        }
+                        None
+                    });
+                    Some(TypeSystemDefinition::TypeDefinition(
+                        TypeDefinition::Object(ObjectTypeDefinition {
+                            fields: fields.collect(),
+                            ..def.clone()
+                        }),
+                    ))
+                } else {
+                    Some(def.clone())
+                }
+            });
+
+            Ok(Some(TypeSystemDocument {
+                definitions: definitions.collect(),
+            }))
         })
     }
 
-    fn transform_document_for_runtime_server<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
-        // removes @model directives
-        let definitions = document.definitions.iter().flat_map(|def| {
-            if let TypeSystemDefinition::DirectiveDefinition(def) = def {
-                if def.name.name == "model" {
-                    return None;
+    fn transform_document_for_runtime_server<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(async move {
+            // removes @model directives
+            let definitions = document.definitions.iter().flat_map(|def| {
+                if let TypeSystemDefinition::DirectiveDefinition(def) = def {
+                    if def.name.name == self.options.directive_name {
+                        return None;
+                    }
                 }
-            }
-            if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
-                let directives = def
-                    .directives
-                    .iter()
-                    .filter(|directive| directive.name.name != "model")
-                    .cloned()
-                    .collect();
+                if let TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def)) = def {
+                    let directives = def
+                        .directives
+                        .iter()
+                        .filter(|directive| directive.name.name != self.options.directive_name)
+                        .cloned()
+                        .collect();
 
-                let fields = def
-                    .fields
-                    .iter()
-                    .map(|field| {
-                        let directives = field
-                            .directives
-                            .iter()
-                            .filter(|directive| directive.name.name != "model")
-                            .cloned()
-                            .collect();
-                        FieldDefinition {
-                            directives,
-                            ..field.clone()
-                        }
-                    })
-                    .collect();
+                    let fields = def
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            let directives = field
+                                .directives
+                                .iter()
+                                .filter(|directive| {
+                                    directive.name.name != self.options.directive_name
+                                })
+                                .cloned()
+                                .collect();
+                            FieldDefinition {
+                                directives,
+                                ..field.clone()
+                            }
+                        })
+                        .collect();
 
-                Some(TypeSystemDefinition::TypeDefinition(
-                    TypeDefinition::Object(ObjectTypeDefinition {
-                        directives,
-                        fields,
-                        ..def.clone()
-                    }),
-                ))
-            } else {
-                Some(def.clone())
-            }
-        });
+                    Some(TypeSystemDefinition::TypeDefinition(
+                        TypeDefinition::Object(ObjectTypeDefinition {
+                            directives,
+                            fields,
+                            ..def.clone()
+                        }),
+                    ))
+                } else {
+                    Some(def.clone())
+                }
+            });
 
-        Some(TypeSystemDocument {
-            definitions: definitions.collect(),
+            Ok(Some(TypeSystemDocument {
+                definitions: definitions.collect(),
+            }))
         })
     }
 }
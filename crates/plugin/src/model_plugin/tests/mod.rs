@@ -113,7 +113,7 @@ type User @model(type: "string") {
     }
 
     fn check(doc: &TypeSystemDocument) -> Vec<CheckError> {
-        let model_plugin = Plugin::new(Box::new(ModelPlugin {}));
+        let model_plugin = Plugin::new(Box::new(ModelPlugin::default()));
         let mut result = check_type_system_document(doc);
         result.extend(
             model_plugin
@@ -137,6 +137,7 @@ type User @model(type: "string") {
 }
 
 mod resolvers {
+    use futures::executor::block_on;
     use insta::assert_snapshot;
     use nitrogql_ast::TypeSystemDocument;
     use nitrogql_printer::{ResolverTypePrinter, ResolverTypePrinterOptions};
@@ -172,20 +173,88 @@ type Post {
         document: &TypeSystemDocument,
         options: ResolverTypePrinterOptions,
     ) -> String {
-        let plugins = [Plugin::new(Box::new(ModelPlugin {}))];
+        let plugins = [Plugin::new(Box::new(ModelPlugin::default()))];
         let mut result = String::new();
         let mut writer = JustWriter::new(&mut result);
         let mut printer = ResolverTypePrinter::new(options, &mut writer);
-        printer.print_document(document, &plugins).unwrap();
+        block_on(printer.print_document(document, &plugins)).unwrap();
         result
     }
 }
 
+mod options {
+    use futures::executor::block_on;
+    use insta::assert_snapshot;
+    use nitrogql_ast::base::HasPos;
+    use nitrogql_printer::{GraphQLPrinter, ResolverTypePrinter, ResolverTypePrinterOptions};
+    use sourcemap_writer::JustWriter;
+
+    use crate::{ModelPlugin, ModelPluginOptions, Plugin};
+
+    use super::parse_with_plugin;
+
+    #[test]
+    fn custom_directive_name() {
+        let plugin = Plugin::new(Box::new(ModelPlugin::new(ModelPluginOptions {
+            directive_name: "entity".into(),
+        })));
+        let doc = parse_with_plugin(
+            "
+type User {
+    id: ID! @entity
+    name: String!
+}
+        ",
+            &plugin,
+        );
+        assert!(plugin.check_schema(&doc).errors.is_empty());
+
+        let mut resolvers = String::new();
+        let mut writer = JustWriter::new(&mut resolvers);
+        let mut printer =
+            ResolverTypePrinter::new(ResolverTypePrinterOptions::default(), &mut writer);
+        block_on(printer.print_document(&doc, &[plugin])).unwrap();
+        assert_snapshot!(resolvers);
+    }
+
+    #[test]
+    fn directive_removed_for_runtime_server() {
+        let plugin = Plugin::new(Box::new(ModelPlugin::new(ModelPluginOptions {
+            directive_name: "entity".into(),
+        })));
+        let doc = parse_with_plugin(
+            "
+type User {
+    id: ID! @entity
+    name: String!
+}
+        ",
+            &plugin,
+        );
+        let doc = block_on(plugin.transform_document_for_runtime_server(&doc))
+            .unwrap()
+            .unwrap();
+        let mut printed = String::new();
+        let mut writer = JustWriter::new(&mut printed);
+        for def in doc.definitions.iter().filter(|def| !def.position().builtin) {
+            def.print_graphql(&mut writer);
+        }
+        assert_eq!(printed.trim(), "type User {\n  id: ID!\n  name: String!\n}");
+    }
+}
+
 fn parse_to_type_system_document(source: &str) -> TypeSystemDocument {
+    let model_plugin = Plugin::new(Box::new(ModelPlugin::default()));
+    parse_with_plugin(source, &model_plugin)
+}
+
+fn parse_with_plugin<'src>(
+    source: &'src str,
+    model_plugin: &Plugin<'src>,
+) -> TypeSystemDocument<'src> {
     let mut doc = parse_type_system_document(source).unwrap();
     doc.extend(generate_builtins());
 
-    let model_plugin = Plugin::new(Box::new(ModelPlugin {}));
     let mut host = TestHost {};
     doc.extend(
         model_plugin
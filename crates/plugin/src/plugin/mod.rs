@@ -1,10 +1,18 @@
-use std::{cell::RefCell, collections::HashMap};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap};
 
-use nitrogql_ast::{TypeSystemDocument, TypeSystemOrExtensionDocument};
+use graphql_type_system::Schema;
+use nitrogql_ast::{
+    base::Pos, OperationDocument, TypeSystemDocument, TypeSystemOrExtensionDocument,
+};
 use nitrogql_parser::{parse_type_system_document, ParseError};
-use nitrogql_printer::{ts_types::TSType, ResolverTypePrinterOptions, ResolverTypePrinterPlugin};
+use nitrogql_printer::{
+    ts_types::TSType, OperationTypePrinterPlugin, ResolverTypePrinterOptions,
+    ResolverTypePrinterPlugin, ResolverTypePrinterPluginFuture,
+};
 
-use crate::{plugin_v1::PluginSchemaExtensions, PluginV1Beta};
+use crate::{
+    plugin_v1::PluginSchemaExtensions, PluginGenerateInput, PluginOutputFile, PluginV1Beta,
+};
 
 pub use crate::PluginCheckResult;
 
@@ -40,6 +48,11 @@ impl<'src> Plugin<'src> {
         self.raw.load_schema_extensions(extensions)
     }
 
+    /// Prepares the plugin for given schema.
+    pub async fn prepare(&mut self, schema_source: &str) -> Result<(), String> {
+        self.raw.prepare(schema_source).await
+    }
+
     /// Returns additional schema definition provided by the plugin.
     pub fn schema_addition(
         &self,
@@ -65,28 +78,83 @@ impl<'src> Plugin<'src> {
         self.raw.check_schema(schema)
     }
 
-    pub fn transform_document_for_runtime_server<'s>(
+    pub async fn transform_document_for_runtime_server<'s>(
         &self,
         document: &TypeSystemDocument<'s>,
-    ) -> Option<TypeSystemDocument<'s>> {
-        self.raw.transform_document_for_runtime_server(document)
+    ) -> Result<Option<TypeSystemDocument<'s>>, String> {
+        self.raw
+            .transform_document_for_runtime_server(document)
+            .await
+    }
+
+    /// Checks operation document.
+    pub async fn check_operation(
+        &self,
+        schema: &Schema<Cow<'_, str>, Pos>,
+        operation: &OperationDocument<'_>,
+    ) -> PluginCheckResult {
+        self.raw.check_operation(schema, operation).await
+    }
+
+    /// Transforms operation document.
+    pub async fn transform_operation_document<'s>(
+        &self,
+        schema: &Schema<Cow<'_, str>, Pos>,
+        operation: &OperationDocument<'s>,
+    ) -> Result<Option<OperationDocument<'s>>, String> {
+        self.raw
+            .transform_operation_document(schema, operation)
+            .await
+            .map_err(|message| self.failure_message(&message))
+    }
+
+    /// Returns a message that describes a failure of this plugin.
+    fn failure_message(&self, message: &str) -> String {
+        format!("Plugin '{}' failed: {message}", self.name())
+    }
+
+    /// Generates additional output files.
+    pub async fn generate_files(
+        &self,
+        input: &PluginGenerateInput<'_, '_>,
+    ) -> Result<Vec<PluginOutputFile>, String> {
+        self.raw.generate_files(input).await
     }
 }
 
 impl ResolverTypePrinterPlugin for Plugin<'_> {
-    fn transform_resolver_output_types<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-        options: &ResolverTypePrinterOptions,
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        options: &'a ResolverTypePrinterOptions,
         base: HashMap<&'src str, TSType>,
-    ) -> HashMap<&'src str, TSType> {
-        self.raw
-            .transform_resolver_output_types(document, options, base)
+    ) -> ResolverTypePrinterPluginFuture<'a, HashMap<&'src str, TSType>> {
+        Box::pin(async move {
+            self.raw
+                .transform_resolver_output_types(document, options, base)
+                .await
+                .map_err(|message| self.failure_message(&message))
+        })
     }
-    fn transform_document_for_resolvers<'src>(
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> ResolverTypePrinterPluginFuture<'a, Option<TypeSystemDocument<'src>>> {
+        Box::pin(async move {
+            self.raw
+                .transform_document_for_resolvers(document)
+                .await
+                .map_err(|message| self.failure_message(&message))
+        })
+    }
+}
+
+impl OperationTypePrinterPlugin for Plugin<'_> {
+    fn transform_operation_output_types<'src>(
         &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
-        self.raw.transform_document_for_resolvers(document)
+        document: &OperationDocument<'src>,
+        base: HashMap<&'src str, TSType>,
+    ) -> HashMap<&'src str, TSType> {
+        self.raw.transform_operation_output_types(document, base)
     }
 }
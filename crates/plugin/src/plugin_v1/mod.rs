@@ -1,15 +1,30 @@
-use std::collections::HashMap;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+};
 
-use nitrogql_ast::{base::Pos, TypeSystemDocument};
+use graphql_type_system::Schema;
+use nitrogql_ast::{base::Pos, OperationDocument, TypeSystemDocument};
 use nitrogql_printer::{ts_types::TSType, ResolverTypePrinterOptions};
+use sourcemap_writer::SourceWriterBuffers;
 
 /// Interface of a naked plugin.
+/// Errors returned from hooks are messages that describe the failure.
 #[allow(unused_variables)]
 pub trait PluginV1Beta: std::fmt::Debug {
     /// Name of the plugin.
     fn name(&self) -> &str;
     /// Load schema extensions.
     fn load_schema_extensions(&mut self, extensions: PluginSchemaExtensions) {}
+    /// Prepares the plugin before schema is checked.
+    /// `schema_source` is the whole schema printed as GraphQL.
+    /// Plugins that cannot compute results synchronously do their work here.
+    fn prepare<'a>(&'a mut self, schema_source: &'a str) -> PluginPrepareFuture<'a> {
+        Box::pin(std::future::ready(Ok(())))
+    }
     /// Returns additional schema definition provided by the plugin.
     fn schema_addition(&self) -> Option<String> {
         None
@@ -19,32 +34,72 @@ pub trait PluginV1Beta: std::fmt::Debug {
         PluginCheckResult::success()
     }
     /// Transforms resolver output types.
-    fn transform_resolver_output_types<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-        options: &ResolverTypePrinterOptions,
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        options: &'a ResolverTypePrinterOptions,
         base: HashMap<&'src str, TSType>,
-    ) -> HashMap<&'src str, TSType> {
-        base
+    ) -> PluginFuture<'a, Result<HashMap<&'src str, TSType>, String>> {
+        Box::pin(std::future::ready(Ok(base)))
     }
     /// Transforms document so that it represents which fields
     /// have resolvers.
-    fn transform_document_for_resolvers<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
-        None
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(std::future::ready(Ok(None)))
     }
     /// Transforms document so that it represents the runtime
     /// schema used by a GraphQL server.
-    fn transform_document_for_runtime_server<'src>(
+    fn transform_document_for_runtime_server<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(std::future::ready(Ok(None)))
+    }
+    /// Checks operation document.
+    /// Called only for documents that passed built-in checks.
+    fn check_operation<'a>(
+        &'a self,
+        schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument,
+    ) -> PluginFuture<'a, PluginCheckResult> {
+        Box::pin(std::future::ready(PluginCheckResult::success()))
+    }
+    /// Transforms operation document after it is checked.
+    /// Transformed document is used for generating code.
+    fn transform_operation_document<'a, 'src>(
+        &'a self,
+        schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<OperationDocument<'src>>, String>> {
+        Box::pin(std::future::ready(Ok(None)))
+    }
+    /// Transforms output types of operations and fragments.
+    /// Keys are names of operations and fragments in the document.
+    fn transform_operation_output_types<'src>(
         &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
-        None
+        operation: &OperationDocument<'src>,
+        base: HashMap<&'src str, TSType>,
+    ) -> HashMap<&'src str, TSType> {
+        base
+    }
+    /// Generates additional output files.
+    fn generate_files<'a>(
+        &'a self,
+        input: &'a PluginGenerateInput,
+    ) -> PluginFuture<'a, Result<Vec<PluginOutputFile>, String>> {
+        Box::pin(std::future::ready(Ok(vec![])))
     }
 }
 
+/// Result of a hook that may not complete synchronously.
+pub type PluginFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+
+/// Result of preparing a plugin. Error is a message that describes the failure.
+pub type PluginPrepareFuture<'a> = PluginFuture<'a, Result<(), String>>;
+
 pub struct PluginSchemaExtensions<'a> {
     /// Collection of extensions for type.
     pub type_extensions: &'a HashMap<String, HashMap<String, serde_yaml::Value>>,
@@ -65,3 +120,25 @@ pub struct PluginCheckError {
     pub message: String,
     pub additional_info: Vec<(Pos, String)>,
 }
+
+/// Input to the `generate_files` hook.
+pub struct PluginGenerateInput<'a, 'src> {
+    /// The resolved schema.
+    pub schema: &'a Schema<Cow<'src, str>, Pos>,
+    /// Checked operation documents along with their paths.
+    pub operations: Vec<(&'a Path, &'a OperationDocument<'src>)>,
+}
+
+/// File generated by a plugin.
+pub struct PluginOutputFile {
+    /// Path of the file, relative to the configuration file.
+    pub path: PathBuf,
+    pub content: PluginOutputContent,
+}
+
+pub enum PluginOutputContent {
+    /// Plain text written as is.
+    Text(String),
+    /// Output of a `SourceWriter`. A source map is written next to the file.
+    SourceMapped(SourceWriterBuffers),
+}
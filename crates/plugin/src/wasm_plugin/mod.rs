@@ -0,0 +1,401 @@
+use std::{borrow::Cow, cell::RefCell, collections::HashMap, fmt};
+
+use graphql_type_system::Schema;
+use nitrogql_ast::{base::Pos, OperationDocument, TypeSystemDocument};
+use nitrogql_printer::{print_to_json_string, ts_types::TSType, ResolverTypePrinterOptions};
+use nitrogql_semantics::type_system_to_ast;
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+use wasmi::{Config, Engine, Instance, Linker, Memory, Module, Store};
+
+use crate::{
+    apply_resolver_output_types, external_operation_check_result, external_plugin::DocumentShape,
+    external_schema_check_result, parse_external_operation_document, print_resolver_output_types,
+    ExternalPluginCheckError, ExternalPluginOutputFile, PluginCheckError, PluginCheckResult,
+    PluginFuture, PluginGenerateInput, PluginOutputFile, PluginV1Beta,
+};
+
+mod tests;
+
+/// Prefix of functions exported by plugin modules.
+const EXPORT_PREFIX: &str = "nitrogql_plugin_v1_";
+
+/// Fuel given to each call of a plugin function.
+/// A function that runs out of fuel fails instead of running forever.
+const FUEL_PER_CALL: u64 = 10_000_000_000;
+
+/// Plugin implemented as a WebAssembly module.
+///
+/// The module runs in a sandbox without any imports.
+/// Inputs and outputs of its functions are UTF-8 JSON strings placed in its linear memory.
+/// Documents are represented in the JSON representation of graphql-js AST.
+pub struct WasmPlugin {
+    name: String,
+    schema_addition: Option<String>,
+    runtime: RefCell<WasmPluginRuntime>,
+}
+
+struct WasmPluginRuntime {
+    store: Store<()>,
+    instance: Instance,
+    memory: Memory,
+    fuel_per_call: u64,
+}
+
+#[derive(Debug, Error)]
+pub enum WasmPluginError {
+    #[error("Failed to load plugin module: {0}")]
+    Load(#[from] wasmi::Error),
+    #[error("Plugin module must not import anything, but imports '{0}'")]
+    HasImports(String),
+    #[error("Plugin module does not export '{0}'")]
+    MissingExport(String),
+    #[error("Plugin function '{name}' failed: {message}")]
+    CallFailed { name: String, message: String },
+    #[error("Plugin function '{name}' returned invalid output: {message}")]
+    InvalidOutput { name: String, message: String },
+    #[error("Invalid plugin options: {0}")]
+    InvalidOptions(String),
+}
+
+impl WasmPlugin {
+    /// Loads a plugin from the binary of a WebAssembly module.
+    /// Options other than null are passed to the module's `configure` function.
+    pub fn new(binary: &[u8], options: &serde_json::Value) -> Result<Self, WasmPluginError> {
+        Self::load(binary, options, FUEL_PER_CALL)
+    }
+
+    fn load(
+        binary: &[u8],
+        options: &serde_json::Value,
+        fuel_per_call: u64,
+    ) -> Result<Self, WasmPluginError> {
+        let engine = Engine::new(Config::default().consume_fuel(true));
+        let module = Module::new(&engine, binary)?;
+        if let Some(import) = module.imports().next() {
+            return Err(WasmPluginError::HasImports(format!(
+                "{}.{}",
+                import.module(),
+                import.name()
+            )));
+        }
+        let mut store = Store::new(&engine, ());
+        // The start function also runs with limited fuel.
+        store.add_fuel(fuel_per_call).map_err(wasmi::Error::from)?;
+        let instance = Linker::<()>::new(&engine)
+            .instantiate(&mut store, &module)?
+            .start(&mut store)?;
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| WasmPluginError::MissingExport("memory".to_owned()))?;
+        let mut runtime = WasmPluginRuntime {
+            store,
+            instance,
+            memory,
+            fuel_per_call,
+        };
+        if !options.is_null() || runtime.has_function("configure") {
+            let error: Option<String> = runtime.call("configure", Some(&options.to_string()))?;
+            if let Some(error) = error {
+                return Err(WasmPluginError::InvalidOptions(error));
+            }
+        }
+        let name = runtime.call("name", None)?;
+        let schema_addition = if runtime.has_function("schema_addition") {
+            runtime.call("schema_addition", None)?
+        } else {
+            None
+        };
+        Ok(WasmPlugin {
+            name,
+            schema_addition,
+            runtime: RefCell::new(runtime),
+        })
+    }
+
+    /// Calls an optional function of the plugin.
+    /// Returns None if the plugin does not implement it.
+    fn call_optional<T: DeserializeOwned>(
+        &self,
+        function: &str,
+        input: &str,
+    ) -> Result<Option<T>, WasmPluginError> {
+        let mut runtime = self.runtime.borrow_mut();
+        if !runtime.has_function(function) {
+            return Ok(None);
+        }
+        runtime.call(function, Some(input))
+    }
+
+    fn implements(&self, function: &str) -> bool {
+        self.runtime.borrow().has_function(function)
+    }
+
+    fn transform_document<'src>(
+        &self,
+        function: &str,
+        document: &TypeSystemDocument<'src>,
+    ) -> Result<Option<TypeSystemDocument<'src>>, String> {
+        let output: Option<Option<serde_json::Value>> = self
+            .call_optional(function, &print_to_json_string(document))
+            .map_err(|err| err.to_string())?;
+        Ok(output
+            .flatten()
+            .map(|output| DocumentShape::from_json_ast(&output).filter(document)))
+    }
+
+    fn call_transform_resolver_output_types<'src>(
+        &self,
+        document: &TypeSystemDocument<'src>,
+        base: HashMap<&'src str, TSType>,
+    ) -> Result<HashMap<&'src str, TSType>, String> {
+        let input = format!(
+            r#"{{"schema":{},"types":{}}}"#,
+            print_to_json_string(document),
+            serde_json::to_string(&print_resolver_output_types(&base))
+                .expect("failed to serialize types")
+        );
+        let output: Option<HashMap<String, String>> = self
+            .call_optional("transform_resolver_output_types", &input)
+            .map_err(|err| err.to_string())?;
+        Ok(match output {
+            Some(output) => apply_resolver_output_types(&output, base),
+            None => base,
+        })
+    }
+
+    /// Input of functions that receive an operation document.
+    fn operation_input(schema: &Schema<Cow<str>, Pos>, operation: &OperationDocument) -> String {
+        format!(
+            r#"{{"schema":{},"document":{}}}"#,
+            print_to_json_string(&type_system_to_ast(schema)),
+            print_to_json_string(operation)
+        )
+    }
+
+    fn call_generate_files(
+        &self,
+        input: &PluginGenerateInput,
+    ) -> Result<Vec<PluginOutputFile>, String> {
+        if !self.implements("generate_files") {
+            return Ok(vec![]);
+        }
+        let operations = input
+            .operations
+            .iter()
+            .map(|(path, document)| {
+                format!(
+                    r#"{{"path":{},"document":{}}}"#,
+                    serde_json::to_string(&path.to_string_lossy())
+                        .expect("failed to serialize path"),
+                    print_to_json_string(*document)
+                )
+            })
+            .collect::<Vec<_>>();
+        let input = format!(
+            r#"{{"schema":{},"operations":[{}]}}"#,
+            print_to_json_string(&type_system_to_ast(input.schema)),
+            operations.join(",")
+        );
+        let output: Option<Vec<ExternalPluginOutputFile>> = self
+            .call_optional("generate_files", &input)
+            .map_err(|err| err.to_string())?;
+        Ok(output
+            .unwrap_or_default()
+            .into_iter()
+            .map(PluginOutputFile::from)
+            .collect())
+    }
+}
+
+impl fmt::Debug for WasmPlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmPlugin")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PluginV1Beta for WasmPlugin {
+    fn name(&self) -> &str {
+        &self.name
+    }
+    fn schema_addition(&self) -> Option<String> {
+        self.schema_addition.clone()
+    }
+    fn check_schema(&self, schema: &TypeSystemDocument) -> PluginCheckResult {
+        let errors: Result<Option<Vec<ExternalPluginCheckError>>, _> =
+            self.call_optional("check_schema", &print_to_json_string(schema));
+        match errors {
+            Ok(errors) => external_schema_check_result(schema, &errors.unwrap_or_default()),
+            Err(err) => PluginCheckResult {
+                errors: vec![PluginCheckError {
+                    position: Pos::builtin(),
+                    message: err.to_string(),
+                    additional_info: vec![],
+                }],
+            },
+        }
+    }
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        _options: &'a ResolverTypePrinterOptions,
+        base: HashMap<&'src str, TSType>,
+    ) -> PluginFuture<'a, Result<HashMap<&'src str, TSType>, String>> {
+        Box::pin(std::future::ready(
+            self.call_transform_resolver_output_types(document, base),
+        ))
+    }
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(std::future::ready(self.transform_document(
+            "transform_document_for_resolvers",
+            document,
+        )))
+    }
+    fn transform_document_for_runtime_server<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<TypeSystemDocument<'src>>, String>> {
+        Box::pin(std::future::ready(self.transform_document(
+            "transform_document_for_runtime_server",
+            document,
+        )))
+    }
+    fn check_operation<'a>(
+        &'a self,
+        schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument,
+    ) -> PluginFuture<'a, PluginCheckResult> {
+        // Avoid serializing the schema for plugins that do not check operations.
+        if !self.implements("check_operation") {
+            return Box::pin(std::future::ready(PluginCheckResult::success()));
+        }
+        let errors: Result<Option<Vec<ExternalPluginCheckError>>, _> =
+            self.call_optional("check_operation", &Self::operation_input(schema, operation));
+        let result = match errors {
+            Ok(errors) => external_operation_check_result(operation, &errors.unwrap_or_default()),
+            Err(err) => PluginCheckResult {
+                errors: vec![PluginCheckError {
+                    position: operation.position,
+                    message: err.to_string(),
+                    additional_info: vec![],
+                }],
+            },
+        };
+        Box::pin(std::future::ready(result))
+    }
+    fn transform_operation_document<'a, 'src>(
+        &'a self,
+        schema: &'a Schema<Cow<str>, Pos>,
+        operation: &'a OperationDocument<'src>,
+    ) -> PluginFuture<'a, Result<Option<OperationDocument<'src>>, String>> {
+        if !self.implements("transform_operation_document") {
+            return Box::pin(std::future::ready(Ok(None)));
+        }
+        let output: Result<Option<Option<String>>, _> = self.call_optional(
+            "transform_operation_document",
+            &Self::operation_input(schema, operation),
+        );
+        let result = output.map_err(|err| err.to_string()).and_then(|output| {
+            output
+                .flatten()
+                .map(|source| {
+                    parse_external_operation_document(operation, source)
+                        .map_err(|err| err.to_string())
+                })
+                .transpose()
+        });
+        Box::pin(std::future::ready(result))
+    }
+    fn generate_files<'a>(
+        &'a self,
+        input: &'a PluginGenerateInput,
+    ) -> PluginFuture<'a, Result<Vec<PluginOutputFile>, String>> {
+        Box::pin(std::future::ready(self.call_generate_files(input)))
+    }
+}
+
+impl WasmPluginRuntime {
+    fn has_function(&self, function: &str) -> bool {
+        self.instance
+            .get_func(&self.store, &format!("{EXPORT_PREFIX}{function}"))
+            .is_some()
+    }
+
+    /// Calls given function of the plugin.
+    /// Input is written to memory allocated by the plugin's `alloc` function.
+    /// Output is returned as a pointer and a length packed into an i64.
+    fn call<T: DeserializeOwned>(
+        &mut self,
+        function: &str,
+        input: Option<&str>,
+    ) -> Result<T, WasmPluginError> {
+        let name = format!("{EXPORT_PREFIX}{function}");
+        let call_failed = |message: String| WasmPluginError::CallFailed {
+            name: name.clone(),
+            message,
+        };
+        self.refuel().map_err(|err| call_failed(err.to_string()))?;
+        let output = match input {
+            None => self
+                .instance
+                .get_typed_func::<(), i64>(&self.store, &name)
+                .map_err(|_| WasmPluginError::MissingExport(name.clone()))?
+                .call(&mut self.store, ())
+                .map_err(|err| call_failed(err.to_string()))?,
+            Some(input) => {
+                let (ptr, len) = self.write_input(input)?;
+                self.instance
+                    .get_typed_func::<(i32, i32), i64>(&self.store, &name)
+                    .map_err(|_| WasmPluginError::MissingExport(name.clone()))?
+                    .call(&mut self.store, (ptr, len))
+                    .map_err(|err| call_failed(err.to_string()))?
+            }
+        };
+        let ptr = (output as u64 >> 32) as usize;
+        let len = (output as u64 & 0xffff_ffff) as usize;
+        let mut buffer = vec![0; len];
+        self.memory
+            .read(&self.store, ptr, &mut buffer)
+            .map_err(|err| call_failed(err.to_string()))?;
+        serde_json::from_slice(&buffer).map_err(|err| WasmPluginError::InvalidOutput {
+            name: name.clone(),
+            message: err.to_string(),
+        })
+    }
+
+    /// Resets remaining fuel to the amount given to each call.
+    fn refuel(&mut self) -> Result<(), wasmi::errors::FuelError> {
+        let remaining = self.store.consume_fuel(0)?;
+        self.store
+            .add_fuel(self.fuel_per_call.saturating_sub(remaining))
+    }
+
+    fn write_input(&mut self, input: &str) -> Result<(i32, i32), WasmPluginError> {
+        let name = format!("{EXPORT_PREFIX}alloc");
+        let len = i32::try_from(input.len()).map_err(|err| WasmPluginError::CallFailed {
+            name: name.clone(),
+            message: err.to_string(),
+        })?;
+        let ptr = self
+            .instance
+            .get_typed_func::<i32, i32>(&self.store, &name)
+            .map_err(|_| WasmPluginError::MissingExport(name.clone()))?
+            .call(&mut self.store, len)
+            .map_err(|err| WasmPluginError::CallFailed {
+                name: name.clone(),
+                message: err.to_string(),
+            })?;
+        self.memory
+            .write(&mut self.store, ptr as usize, input.as_bytes())
+            .map_err(|err| WasmPluginError::CallFailed {
+                name,
+                message: err.to_string(),
+            })?;
+        Ok((ptr, len))
+    }
+}
@@ -0,0 +1,492 @@
+#![cfg(test)]
+
+use std::{collections::HashMap, path::Path};
+
+use futures::executor::block_on;
+use graphql_builtins::generate_builtins;
+use insta::assert_snapshot;
+use nitrogql_ast::{base::HasPos, OperationDocument, TypeSystemDocument};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_printer::{ts_types::TSType, GraphQLPrinter, ResolverTypePrinterOptions};
+use nitrogql_semantics::{
+    ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+use sourcemap_writer::JustWriter;
+
+use crate::{PluginGenerateInput, PluginOutputContent, PluginV1Beta, WasmPlugin, WasmPluginError};
+
+/// Output of a function of the test plugin.
+enum Output<'a> {
+    /// Returns given JSON.
+    Const(&'a str),
+    /// Returns the input as is.
+    Echo,
+    /// Traps.
+    Trap,
+    /// Never returns.
+    Loop,
+}
+
+#[test]
+fn name_and_schema_addition() {
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "schema_addition",
+            Output::Const(r#""directive @internal on FIELD_DEFINITION""#),
+        ),
+    ])
+    .unwrap();
+    assert_eq!(plugin.name(), "wasm-plugin");
+    assert_eq!(
+        plugin.schema_addition().as_deref(),
+        Some("directive @internal on FIELD_DEFINITION")
+    );
+}
+
+#[test]
+fn check_errors_with_coordinates() {
+    let doc = parse_to_type_system_document(
+        "
+type User {
+    id: ID!
+    name: String!
+}
+",
+    );
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "check_schema",
+            Output::Const(
+                r#"[{ "message": "field error", "coordinate": "User.name" }, { "message": "no coordinate" }]"#,
+            ),
+        ),
+    ])
+    .unwrap();
+    let errors = plugin
+        .check_schema(&doc)
+        .errors
+        .into_iter()
+        .map(|error| {
+            (
+                error.message,
+                (!error.position.builtin).then_some((error.position.line, error.position.column)),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("field error".to_owned(), Some((3, 4))),
+            ("no coordinate".to_owned(), None),
+        ]
+    );
+}
+
+#[test]
+fn invalid_check_output() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "check_schema",
+            Output::Const(r#"{ "message": "not an array" }"#),
+        ),
+    ])
+    .unwrap();
+    let errors = plugin.check_schema(&doc).errors;
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0]
+        .message
+        .contains("nitrogql_plugin_v1_check_schema"));
+}
+
+#[test]
+fn resolver_output_types() {
+    let doc = parse_to_type_system_document("type User { id: ID! }\ntype Post { id: ID! }");
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "transform_resolver_output_types",
+            Output::Const(r#"{ "User": "import('./model').User" }"#),
+        ),
+    ])
+    .unwrap();
+    let base = HashMap::from([
+        ("User", TSType::TypeVariable("User".into())),
+        ("Post", TSType::TypeVariable("Post".into())),
+    ]);
+    let options = ResolverTypePrinterOptions::default();
+    let result = block_on(plugin.transform_resolver_output_types(&doc, &options, base)).unwrap();
+    assert_eq!(result.len(), 2);
+    assert!(matches!(&result["User"], TSType::Raw(t) if t == "import('./model').User"));
+    assert!(matches!(&result["Post"], TSType::TypeVariable(_)));
+}
+
+#[test]
+fn document_transforms() {
+    let doc = parse_to_type_system_document(
+        r#"
+directive @auth on FIELD_DEFINITION
+
+type Query {
+    me: User! @auth
+}
+
+type User {
+    id: ID!
+    name: String!
+}
+"#,
+    );
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "transform_document_for_resolvers",
+            Output::Const(
+                r#"{
+    "kind": "Document",
+    "definitions": [
+        {
+            "kind": "ObjectTypeDefinition",
+            "name": { "kind": "Name", "value": "Query" },
+            "fields": [
+                { "kind": "FieldDefinition", "name": { "kind": "Name", "value": "me" }, "directives": [] }
+            ],
+            "directives": []
+        },
+        {
+            "kind": "ObjectTypeDefinition",
+            "name": { "kind": "Name", "value": "User" },
+            "fields": [
+                { "kind": "FieldDefinition", "name": { "kind": "Name", "value": "name" } }
+            ]
+        }
+    ]
+}"#,
+            ),
+        ),
+        ("transform_document_for_runtime_server", Output::Echo),
+    ])
+    .unwrap();
+    let for_resolvers = block_on(plugin.transform_document_for_resolvers(&doc))
+        .unwrap()
+        .unwrap();
+    let for_runtime_server = block_on(plugin.transform_document_for_runtime_server(&doc))
+        .unwrap()
+        .unwrap();
+    assert_snapshot!(format!(
+        "{}\n---\n{}",
+        print_user_definitions(&for_resolvers),
+        print_user_definitions(&for_runtime_server)
+    ));
+}
+
+#[test]
+fn no_document_transforms() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let plugin = load_plugin(&[("name", Output::Const(r#""wasm-plugin""#))]).unwrap();
+    assert!(plugin.schema_addition().is_none());
+    assert!(plugin.check_schema(&doc).errors.is_empty());
+    assert!(block_on(plugin.transform_document_for_resolvers(&doc))
+        .unwrap()
+        .is_none());
+    assert!(block_on(plugin.transform_document_for_runtime_server(&doc))
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn generate_files() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let schema = ast_to_type_system(&doc);
+    let operation = parse_to_operation_document("query Me { me }");
+    let input = PluginGenerateInput {
+        schema: &schema,
+        operations: vec![(Path::new("/app/me.graphql"), &operation)],
+    };
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "generate_files",
+            Output::Const(r#"[{ "path": "generated/hooks.ts", "content": "export {};\n" }]"#),
+        ),
+    ])
+    .unwrap();
+    let files = block_on(plugin.generate_files(&input)).unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].path, Path::new("generated/hooks.ts"));
+    assert!(
+        matches!(&files[0].content, PluginOutputContent::Text(content) if content == "export {};\n")
+    );
+
+    let plugin = load_plugin(&[("name", Output::Const(r#""wasm-plugin""#))]).unwrap();
+    assert!(block_on(plugin.generate_files(&input)).unwrap().is_empty());
+}
+
+#[test]
+fn operation_hooks() {
+    let doc = parse_to_type_system_document("type Query { me: User }\ntype User { id: ID! }");
+    let schema = ast_to_type_system(&doc);
+    let operation = parse_to_operation_document(
+        "
+query Me {
+    user: me {
+        id
+    }
+}
+",
+    );
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "check_operation",
+            Output::Const(
+                r#"[{ "message": "field error", "coordinate": "Me.user.id" }, { "message": "no coordinate" }]"#,
+            ),
+        ),
+        (
+            "transform_operation_document",
+            Output::Const(r#""query Me { me { id } }""#),
+        ),
+    ])
+    .unwrap();
+    let errors = block_on(plugin.check_operation(&schema, &operation))
+        .errors
+        .into_iter()
+        .map(|error| (error.message, (error.position.line, error.position.column)))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        errors,
+        vec![
+            ("field error".to_owned(), (3, 8)),
+            ("no coordinate".to_owned(), (0, 0)),
+        ]
+    );
+    let transformed = block_on(plugin.transform_operation_document(&schema, &operation))
+        .unwrap()
+        .unwrap();
+    let mut result = String::new();
+    transformed.print_graphql(&mut JustWriter::new(&mut result));
+    assert_snapshot!(result);
+
+    let plugin = load_plugin(&[("name", Output::Const(r#""wasm-plugin""#))]).unwrap();
+    assert!(block_on(plugin.check_operation(&schema, &operation))
+        .errors
+        .is_empty());
+    assert!(
+        block_on(plugin.transform_operation_document(&schema, &operation))
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn invalid_operation_transform_output() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let schema = ast_to_type_system(&doc);
+    let operation = parse_to_operation_document("query Me { me }");
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        (
+            "transform_operation_document",
+            Output::Const(r#""query Me {""#),
+        ),
+    ])
+    .unwrap();
+    let error = block_on(plugin.transform_operation_document(&schema, &operation)).unwrap_err();
+    assert!(
+        error.starts_with("Plugin returned an invalid document"),
+        "{error}"
+    );
+}
+
+#[test]
+fn failing_functions() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let plugin = load_plugin(&[
+        ("name", Output::Const(r#""wasm-plugin""#)),
+        ("transform_document_for_resolvers", Output::Trap),
+        ("transform_document_for_runtime_server", Output::Const("{")),
+    ])
+    .unwrap();
+    let error = block_on(plugin.transform_document_for_resolvers(&doc)).unwrap_err();
+    assert!(
+        error.starts_with(
+            "Plugin function 'nitrogql_plugin_v1_transform_document_for_resolvers' failed"
+        ),
+        "{error}"
+    );
+    let error = block_on(plugin.transform_document_for_runtime_server(&doc)).unwrap_err();
+    assert!(
+        error.starts_with("Plugin function 'nitrogql_plugin_v1_transform_document_for_runtime_server' returned invalid output"),
+        "{error}"
+    );
+}
+
+#[test]
+fn fuel_limit() {
+    let doc = parse_to_type_system_document("type Query { me: String }");
+    let plugin = load_plugin_with_fuel(
+        &[
+            ("name", Output::Const(r#""wasm-plugin""#)),
+            ("transform_document_for_resolvers", Output::Loop),
+            ("transform_document_for_runtime_server", Output::Echo),
+        ],
+        100_000,
+    )
+    .unwrap();
+    let error = block_on(plugin.transform_document_for_resolvers(&doc)).unwrap_err();
+    assert!(error.contains("all fuel consumed"), "{error}");
+    // Fuel is refilled for each call.
+    assert!(block_on(plugin.transform_document_for_runtime_server(&doc))
+        .unwrap()
+        .is_some());
+}
+
+#[test]
+fn options() {
+    let options = serde_json::json!({ "prefix": "App" });
+    let plugin = load_plugin_with_options(
+        &[
+            ("name", Output::Const(r#""wasm-plugin""#)),
+            ("configure", Output::Const("null")),
+        ],
+        options.clone(),
+    )
+    .unwrap();
+    assert_eq!(plugin.name(), "wasm-plugin");
+
+    let result = load_plugin_with_options(
+        &[
+            ("name", Output::Const(r#""wasm-plugin""#)),
+            ("configure", Output::Const(r#""unknown option 'prefix'""#)),
+        ],
+        options.clone(),
+    );
+    assert!(
+        matches!(result, Err(WasmPluginError::InvalidOptions(message)) if message == "unknown option 'prefix'")
+    );
+
+    let result = load_plugin_with_options(&[("name", Output::Const(r#""wasm-plugin""#))], options);
+    assert!(
+        matches!(result, Err(WasmPluginError::MissingExport(name)) if name == "nitrogql_plugin_v1_configure")
+    );
+}
+
+#[test]
+fn missing_name() {
+    let result = load_plugin(&[]);
+    assert!(
+        matches!(result, Err(WasmPluginError::MissingExport(name)) if name == "nitrogql_plugin_v1_name")
+    );
+}
+
+#[test]
+fn module_with_imports() {
+    let binary = wat::parse_str(
+        r#"(module
+    (import "env" "log" (func))
+    (memory (export "memory") 1)
+)"#,
+    )
+    .unwrap();
+    let result = WasmPlugin::new(&binary, &serde_json::Value::Null);
+    assert!(matches!(result, Err(WasmPluginError::HasImports(name)) if name == "env.log"));
+}
+
+/// Builds a plugin module whose functions return given outputs.
+fn load_plugin(outputs: &[(&str, Output)]) -> Result<WasmPlugin, WasmPluginError> {
+    load_plugin_with_options(outputs, serde_json::Value::Null)
+}
+
+fn load_plugin_with_options(
+    outputs: &[(&str, Output)],
+    options: serde_json::Value,
+) -> Result<WasmPlugin, WasmPluginError> {
+    WasmPlugin::new(&build_module(outputs), &options)
+}
+
+fn load_plugin_with_fuel(
+    outputs: &[(&str, Output)],
+    fuel_per_call: u64,
+) -> Result<WasmPlugin, WasmPluginError> {
+    WasmPlugin::load(
+        &build_module(outputs),
+        &serde_json::Value::Null,
+        fuel_per_call,
+    )
+}
+
+fn build_module(outputs: &[(&str, Output)]) -> Vec<u8> {
+    let mut data = String::new();
+    let mut funcs = String::new();
+    let mut offset = 0;
+    for (name, output) in outputs {
+        let params = if matches!(*name, "name" | "schema_addition") {
+            ""
+        } else {
+            "(param i32 i32)"
+        };
+        let body = match output {
+            Output::Const(json) => {
+                data.push_str(&format!(
+                    "(data (i32.const {offset}) \"{}\")\n",
+                    json.bytes().map(|b| format!("\\{b:02x}")).collect::<String>()
+                ));
+                let packed = (offset << 32) | json.len() as i64;
+                offset += json.len() as i64;
+                format!("(i64.const {packed})")
+            }
+            Output::Echo => "(i64.or (i64.shl (i64.extend_i32_u (local.get 0)) (i64.const 32)) (i64.extend_i32_u (local.get 1)))".to_owned(),
+            Output::Trap => "(unreachable)".to_owned(),
+            Output::Loop => "(loop $loop (br $loop)) (unreachable)".to_owned(),
+        };
+        funcs.push_str(&format!(
+            "(func (export \"nitrogql_plugin_v1_{name}\") {params} (result i64) {body})\n"
+        ));
+    }
+    let source = format!(
+        r#"(module
+    (memory (export "memory") 4)
+    (global $heap (mut i32) (i32.const 65536))
+    (func (export "nitrogql_plugin_v1_alloc") (param $size i32) (result i32)
+        (local $ptr i32)
+        (local.set $ptr (global.get $heap))
+        (global.set $heap (i32.add (global.get $heap) (local.get $size)))
+        (local.get $ptr)
+    )
+    {funcs}
+    {data}
+)"#
+    );
+    wat::parse_str(source).unwrap()
+}
+
+fn parse_to_type_system_document(source: &str) -> TypeSystemDocument<'_> {
+    let mut doc = parse_type_system_document(source).unwrap();
+    doc.extend(generate_builtins());
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn parse_to_operation_document(source: &str) -> OperationDocument<'_> {
+    let (doc, _) = resolve_operation_extensions(parse_operation_document(source).unwrap()).unwrap();
+    doc
+}
+
+/// Prints given document without built-in definitions.
+fn print_user_definitions(document: &TypeSystemDocument) -> String {
+    let document = TypeSystemDocument {
+        definitions: document
+            .definitions
+            .iter()
+            .filter(|def| !def.position().builtin)
+            .cloned()
+            .collect(),
+    };
+    let mut result = String::new();
+    let mut writer = JustWriter::new(&mut result);
+    document.print_graphql(&mut writer);
+    result
+}
@@ -4,7 +4,7 @@ use nitrogql_ast::{
     variable::{Variable, VariableDefinition, VariablesDefinition},
 };
 
-use crate::GraphQLPrinter;
+use crate::{utils::UNMASK_DIRECTIVE, GraphQLPrinter};
 
 use {
     nitrogql_ast::{
@@ -86,11 +86,21 @@ impl GraphQLPrinter for VariablesDefinition<'_> {
     }
 }
 
+// Default values and directives change the meaning of operations,
+// so they must be kept in persisted query documents.
 impl GraphQLPrinter for VariableDefinition<'_> {
     fn print_graphql(&self, writer: &mut impl SourceMapWriter) {
         self.name.print_graphql(writer);
         writer.write(": ");
         self.r#type.print_graphql(writer);
+        if let Some(ref default_value) = self.default_value {
+            writer.write(" = ");
+            default_value.print_graphql(writer);
+        }
+        for d in self.directives.iter() {
+            writer.write(" ");
+            d.print_graphql(writer);
+        }
     }
 }
 
@@ -172,7 +182,12 @@ impl GraphQLPrinter for Selection<'_> {
             Selection::FragmentSpread(spread) => {
                 writer.write("... ");
                 spread.fragment_name.print_graphql(writer);
-                for d in spread.directives.iter() {
+                // @unmask only affects generated types and is not sent to the server.
+                for d in spread
+                    .directives
+                    .iter()
+                    .filter(|d| d.name.name != UNMASK_DIRECTIVE)
+                {
                     writer.write(" ");
                     d.print_graphql(writer);
                 }
@@ -1,11 +1,11 @@
 use json_writer::JSONObjectWriter;
-use to_json::JsonPrintable;
 
 mod helpers;
 mod tests;
 mod to_json;
+mod type_system;
 
-pub use to_json::ExecutableDefinitionRef;
+pub use to_json::{ExecutableDefinitionRef, JsonPrintable};
 
 pub fn print_to_json_string<T: JsonPrintable + ?Sized>(ast: &T) -> String {
     let mut buf = String::new();
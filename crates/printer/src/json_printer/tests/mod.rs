@@ -349,6 +349,37 @@ mod selection_set {
     }
 }
 
+mod type_system {
+    use insta::assert_snapshot;
+    use nitrogql_parser::parse_type_system_document;
+    use nitrogql_semantics::resolve_schema_extensions;
+
+    use super::super::print_to_json_string;
+
+    #[test]
+    fn type_system_document() {
+        let doc = parse_type_system_document(
+            r#"
+schema { query: Query }
+"Directive"
+directive @auth(role: String = "user") repeatable on OBJECT | FIELD_DEFINITION
+scalar Date @specifiedBy(url: "https://example.com")
+type Query implements Node @auth {
+    "Field"
+    user(id: ID!): User
+}
+interface Node { id: ID! }
+union User = Query
+enum Color { RED @deprecated, GREEN }
+input Filter { color: Color = RED, tags: [String!] }
+"#,
+        )
+        .unwrap();
+        let doc = resolve_schema_extensions(doc).unwrap();
+        assert_snapshot!(print_to_json_string(&doc));
+    }
+}
+
 fn print_json_to_string<V>(value: V) -> String
 where
     V: JsonPrintable,
@@ -10,6 +10,7 @@ use nitrogql_ast::{
 };
 
 use super::helpers::{Argument, JSONValue, Name, Variable};
+use crate::utils::UNMASK_DIRECTIVE;
 
 /// Value that can be printed into JSON.
 pub trait JsonPrintable {
@@ -271,7 +272,12 @@ impl JsonPrintable for FragmentSpread<'_> {
         writer.value("kind", "FragmentSpread");
         writer.value("name", JSONValue(&Name(self.fragment_name.name)));
         let mut directives_writer = writer.array("directives");
-        for d in self.directives.iter() {
+        // @unmask only affects generated types and is not sent to the server.
+        for d in self
+            .directives
+            .iter()
+            .filter(|d| d.name.name != UNMASK_DIRECTIVE)
+        {
             d.print_json(&mut directives_writer.object());
         }
     }
@@ -0,0 +1,197 @@
+use json_writer::JSONObjectWriter;
+
+use nitrogql_ast::{
+    base::Ident,
+    directive::Directive,
+    r#type::{NamedType, Type},
+    type_system::{
+        ArgumentsDefinition, DirectiveDefinition, EnumValueDefinition, FieldDefinition,
+        InputValueDefinition, SchemaDefinition, TypeDefinition, TypeSystemDefinition,
+    },
+    value::StringValue,
+    TypeSystemDocument,
+};
+
+use super::{
+    helpers::{JSONValue, Name},
+    to_json::JsonPrintable,
+};
+
+impl JsonPrintable for TypeSystemDocument<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "Document");
+
+        let mut definitions_writer = writer.array("definitions");
+        for d in &self.definitions {
+            d.print_json(&mut definitions_writer.object());
+        }
+    }
+}
+
+impl JsonPrintable for TypeSystemDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        match self {
+            TypeSystemDefinition::SchemaDefinition(def) => def.print_json(writer),
+            TypeSystemDefinition::TypeDefinition(def) => def.print_json(writer),
+            TypeSystemDefinition::DirectiveDefinition(def) => def.print_json(writer),
+        }
+    }
+}
+
+impl JsonPrintable for SchemaDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "SchemaDefinition");
+        write_description(&self.description, writer);
+        write_directives(&self.directives, writer);
+        let mut operation_types_writer = writer.array("operationTypes");
+        for (operation_type, type_name) in self.definitions.iter() {
+            let mut operation_type_writer = operation_types_writer.object();
+            operation_type_writer.value("kind", "OperationTypeDefinition");
+            operation_type_writer.value("operation", operation_type.as_str());
+            operation_type_writer.value("type", JSONValue(&named_type(type_name)));
+        }
+    }
+}
+
+impl JsonPrintable for TypeDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        match self {
+            TypeDefinition::Scalar(def) => {
+                writer.value("kind", "ScalarTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_directives(&def.directives, writer);
+            }
+            TypeDefinition::Object(def) => {
+                writer.value("kind", "ObjectTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_named_types("interfaces", &def.implements, writer);
+                write_directives(&def.directives, writer);
+                write_fields(&def.fields, writer);
+            }
+            TypeDefinition::Interface(def) => {
+                writer.value("kind", "InterfaceTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_named_types("interfaces", &def.implements, writer);
+                write_directives(&def.directives, writer);
+                write_fields(&def.fields, writer);
+            }
+            TypeDefinition::Union(def) => {
+                writer.value("kind", "UnionTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_directives(&def.directives, writer);
+                write_named_types("types", &def.members, writer);
+            }
+            TypeDefinition::Enum(def) => {
+                writer.value("kind", "EnumTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_directives(&def.directives, writer);
+                let mut values_writer = writer.array("values");
+                for value in def.values.iter() {
+                    value.print_json(&mut values_writer.object());
+                }
+            }
+            TypeDefinition::InputObject(def) => {
+                writer.value("kind", "InputObjectTypeDefinition");
+                write_description(&def.description, writer);
+                writer.value("name", JSONValue(&Name(def.name.name)));
+                write_directives(&def.directives, writer);
+                let mut fields_writer = writer.array("fields");
+                for field in def.fields.iter() {
+                    field.print_json(&mut fields_writer.object());
+                }
+            }
+        }
+    }
+}
+
+impl JsonPrintable for FieldDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "FieldDefinition");
+        write_description(&self.description, writer);
+        writer.value("name", JSONValue(&Name(self.name.name)));
+        write_arguments(&self.arguments, writer);
+        writer.value("type", JSONValue(&self.r#type));
+        write_directives(&self.directives, writer);
+    }
+}
+
+impl JsonPrintable for InputValueDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "InputValueDefinition");
+        write_description(&self.description, writer);
+        writer.value("name", JSONValue(&Name(self.name.name)));
+        writer.value("type", JSONValue(&self.r#type));
+        if let Some(ref value) = self.default_value {
+            value.print_json(&mut writer.object("defaultValue"));
+        }
+        write_directives(&self.directives, writer);
+    }
+}
+
+impl JsonPrintable for EnumValueDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "EnumValueDefinition");
+        write_description(&self.description, writer);
+        writer.value("name", JSONValue(&Name(self.name.name)));
+        write_directives(&self.directives, writer);
+    }
+}
+
+impl JsonPrintable for DirectiveDefinition<'_> {
+    fn print_json(&self, writer: &mut JSONObjectWriter) {
+        writer.value("kind", "DirectiveDefinition");
+        write_description(&self.description, writer);
+        writer.value("name", JSONValue(&Name(self.name.name)));
+        write_arguments(&self.arguments, writer);
+        writer.value("repeatable", self.repeatable.is_some());
+        let mut locations_writer = writer.array("locations");
+        for location in self.locations.iter() {
+            Name(location.name).print_json(&mut locations_writer.object());
+        }
+    }
+}
+
+fn named_type<'a>(name: &Ident<'a>) -> Type<'a> {
+    Type::Named(NamedType { name: *name })
+}
+
+fn write_description(description: &Option<StringValue>, writer: &mut JSONObjectWriter) {
+    if let Some(description) = description {
+        let mut description_writer = writer.object("description");
+        description_writer.value("kind", "StringValue");
+        description_writer.value("value", &description.value);
+    }
+}
+
+fn write_directives(directives: &[Directive], writer: &mut JSONObjectWriter) {
+    let mut directives_writer = writer.array("directives");
+    for d in directives {
+        d.print_json(&mut directives_writer.object());
+    }
+}
+
+fn write_named_types(key: &str, names: &[Ident], writer: &mut JSONObjectWriter) {
+    let mut types_writer = writer.array(key);
+    for name in names {
+        named_type(name).print_json(&mut types_writer.object());
+    }
+}
+
+fn write_fields(fields: &[FieldDefinition], writer: &mut JSONObjectWriter) {
+    let mut fields_writer = writer.array("fields");
+    for field in fields {
+        field.print_json(&mut fields_writer.object());
+    }
+}
+
+fn write_arguments(arguments: &Option<ArgumentsDefinition>, writer: &mut JSONObjectWriter) {
+    let mut arguments_writer = writer.array("arguments");
+    for argument in arguments.iter().flat_map(|args| args.input_values.iter()) {
+        argument.print_json(&mut arguments_writer.object());
+    }
+}
@@ -1,28 +1,42 @@
 mod graphql_printer;
 mod jsdoc;
 mod json_printer;
+mod mocks_printer;
 mod operation_base_printer;
 mod operation_js_printer;
 mod operation_type_printer;
+mod persisted_query;
 mod resolver_type_printer;
 mod schema;
 mod schema_type_printer;
 pub mod ts_types;
 mod utils;
+mod validation_schema_printer;
 
 pub use graphql_printer::GraphQLPrinter;
+pub use json_printer::{print_to_json_string, JsonPrintable};
+pub use mocks_printer::{options::MocksPrinterOptions, printer::MocksPrinter, MocksPrinterWarning};
 pub use schema_type_printer::printer::{SchemaTypePrinter, SchemaTypePrinterOptions};
 
 pub use resolver_type_printer::{
     options::ResolverTypePrinterOptions,
-    plugin::ResolverTypePrinterPlugin,
+    plugin::{ResolverTypePrinterPlugin, ResolverTypePrinterPluginFuture},
     printer::{ResolverTypePrinter, ResolverTypePrinterContext},
 };
 
 pub use operation_type_printer::{
-    print_types_for_operation_document, visitor::OperationTypePrinterOptions,
+    plugin::OperationTypePrinterPlugin, print_types_for_operation_document,
+    visitor::OperationTypePrinterOptions,
 };
 
 pub use operation_js_printer::{
     options::OperationJSPrinterOptions, print_js_for_operation_document,
 };
+
+pub use persisted_query::{
+    document_hash, persisted_operations, print_persisted_query_manifest, PersistedOperation,
+};
+
+pub use validation_schema_printer::{
+    options::ValidationSchemaPrinterOptions, printer::ValidationSchemaPrinter,
+};
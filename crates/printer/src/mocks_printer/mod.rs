@@ -0,0 +1,11 @@
+pub mod options;
+pub mod printer;
+mod tests;
+mod warning;
+
+pub use warning::MocksPrinterWarning;
+
+/// Name of the function that generates a mock value of given type.
+pub fn mock_function_name(type_name: &str) -> String {
+    format!("mock{type_name}")
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use nitrogql_config_file::{Config, EnumStyle};
+
+use crate::SchemaTypePrinterOptions;
+
+#[derive(Debug)]
+pub struct MocksPrinterOptions {
+    /// Mock value of each scalar. Provided as raw TypeScript code.
+    pub scalar_mocks: HashMap<String, String>,
+    /// Source of schema type to import from.
+    pub schema_source: String,
+    /// Name of the root TypeScript namespace that contains schema types.
+    pub schema_root_namespace: String,
+    /// Style of enums emitted in the schema file.
+    pub enum_style: EnumStyle,
+}
+
+impl Default for MocksPrinterOptions {
+    fn default() -> Self {
+        MocksPrinterOptions {
+            scalar_mocks: HashMap::new(),
+            schema_source: "".into(),
+            schema_root_namespace: "Schema".into(),
+            enum_style: EnumStyle::Union,
+        }
+    }
+}
+
+impl MocksPrinterOptions {
+    pub fn from_config(config: &Config) -> Self {
+        MocksPrinterOptions {
+            scalar_mocks: config.generate.r#type.scalar_mocks.clone(),
+            // Enum values must be in the same shape as the schema file.
+            enum_style: SchemaTypePrinterOptions::from_config(config).effective_enum_style(),
+            ..MocksPrinterOptions::default()
+        }
+    }
+}
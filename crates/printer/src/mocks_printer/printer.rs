@@ -0,0 +1,304 @@
+use std::collections::{HashMap, HashSet};
+
+use nitrogql_ast::{
+    base::Ident,
+    r#type::Type,
+    type_system::{
+        EnumTypeDefinition, FieldDefinition, ObjectTypeDefinition, ScalarTypeDefinition,
+        TypeDefinition, TypeSystemDefinition,
+    },
+    TypeSystemDocument,
+};
+use nitrogql_config_file::{EnumStyle, TypeTarget};
+use sourcemap_writer::SourceMapWriter;
+
+use super::{mock_function_name, options::MocksPrinterOptions, MocksPrinterWarning};
+
+pub struct MocksPrinter<'a, Writer> {
+    options: MocksPrinterOptions,
+    writer: &'a mut Writer,
+}
+
+impl<'a, Writer> MocksPrinter<'a, Writer>
+where
+    Writer: SourceMapWriter,
+{
+    pub fn new(options: MocksPrinterOptions, writer: &'a mut Writer) -> Self {
+        Self { options, writer }
+    }
+
+    /// Prints mock factories for all output types in given document.
+    /// Returns warnings about factories that could not be printed as configured.
+    pub fn print_document(&mut self, document: &TypeSystemDocument) -> Vec<MocksPrinterWarning> {
+        // TypeScript enums are referred to as values.
+        let import_type = if self.options.enum_style == EnumStyle::TsEnum {
+            ""
+        } else {
+            "type "
+        };
+        write!(
+            self.writer,
+            "import {import_type}* as {} from \"{}\";\n\n",
+            self.options.schema_root_namespace, self.options.schema_source,
+        );
+
+        let types = MockTypes::new(document);
+        let mut warnings = vec![];
+        for def in document.definitions.iter() {
+            let TypeSystemDefinition::TypeDefinition(def) = def else {
+                continue;
+            };
+            match def {
+                TypeDefinition::Scalar(def) => warnings.extend(self.print_scalar(def)),
+                TypeDefinition::Enum(def) => self.print_enum(def),
+                TypeDefinition::Object(def) => self.print_object(def, &types),
+                TypeDefinition::Interface(def) => {
+                    self.print_abstract(def.name.name, &def.name, &types)
+                }
+                TypeDefinition::Union(def) => self.print_abstract(def.name.name, &def.name, &types),
+                TypeDefinition::InputObject(_) => {}
+            }
+        }
+        warnings
+    }
+
+    fn print_scalar(&mut self, def: &ScalarTypeDefinition) -> Option<MocksPrinterWarning> {
+        let value = self
+            .options
+            .scalar_mocks
+            .get(def.name.name)
+            .map(|value| value.as_str())
+            .or_else(|| builtin_scalar_mock(def.name.name))
+            .map(|value| value.to_owned());
+        self.print_function_start(def.name.name, &def.name, false);
+        let warning = match value {
+            Some(value) => {
+                writeln!(self.writer, "return {value};");
+                None
+            }
+            None => {
+                // Placeholder so that factories of other types can still be used.
+                let output_type = self.output_type(def.name.name);
+                writeln!(self.writer, "return null as unknown as {output_type};");
+                Some(MocksPrinterWarning::ScalarMockNotProvided {
+                    position: def.position,
+                    name: def.name.to_string(),
+                })
+            }
+        };
+        self.print_function_end();
+        warning
+    }
+
+    fn print_enum(&mut self, def: &EnumTypeDefinition) {
+        let Some(first_value) = def.values.first() else {
+            return;
+        };
+        let value = match self.options.enum_style {
+            EnumStyle::Union | EnumStyle::ConstObject => format!("\"{}\"", first_value.name.name),
+            EnumStyle::TsEnum => format!(
+                "{}.{}.{}",
+                self.options.schema_root_namespace, def.name.name, first_value.name.name
+            ),
+        };
+        self.print_function_start(def.name.name, &def.name, false);
+        writeln!(self.writer, "return {value};");
+        self.print_function_end();
+    }
+
+    fn print_object(&mut self, def: &ObjectTypeDefinition, types: &MockTypes) {
+        self.print_function_start(def.name.name, &def.name, true);
+        self.writer.write("return {\n");
+        self.writer.indent();
+        writeln!(self.writer, "__typename: \"{}\",", def.name.name);
+        for field in def.fields.iter() {
+            let value = field_mock(&field.r#type);
+            if types.is_cyclic_field(def.name.name, field) {
+                // Evaluated lazily so that factories do not call each other infinitely.
+                self.writer.write("get ");
+                self.writer.write_for(field.name.name, &field.name);
+                self.writer.write("() {\n");
+                self.writer.indent();
+                writeln!(self.writer, "return {value};");
+                self.writer.dedent();
+                self.writer.write("},\n");
+            } else {
+                self.writer.write_for(field.name.name, &field.name);
+                writeln!(self.writer, ": {value},");
+            }
+        }
+        self.writer.write("...overrides,\n");
+        self.writer.dedent();
+        self.writer.write("};\n");
+        self.print_function_end();
+    }
+
+    fn print_abstract(&mut self, type_name: &str, name: &Ident, types: &MockTypes) {
+        self.print_function_start(type_name, name, false);
+        match types.concrete_type(type_name) {
+            Some(concrete_type) => {
+                writeln!(
+                    self.writer,
+                    "return {}();",
+                    mock_function_name(concrete_type)
+                );
+            }
+            None => {
+                writeln!(
+                    self.writer,
+                    "throw new Error(\"No object type is possible for '{type_name}'\");"
+                );
+            }
+        }
+        self.print_function_end();
+    }
+
+    fn output_type(&self, type_name: &str) -> String {
+        format!(
+            "{}.{}.{}",
+            self.options.schema_root_namespace,
+            TypeTarget::OperationOutput,
+            type_name
+        )
+    }
+
+    fn print_function_start(&mut self, type_name: &str, name: &Ident, has_overrides: bool) {
+        let output_type = self.output_type(type_name);
+        self.writer.write("export function ");
+        self.writer.write_for(&mock_function_name(type_name), name);
+        if has_overrides {
+            writeln!(
+                self.writer,
+                "(overrides: Partial<{output_type}> = {{}}): {output_type} {{"
+            );
+        } else {
+            writeln!(self.writer, "(): {output_type} {{");
+        }
+        self.writer.indent();
+    }
+
+    fn print_function_end(&mut self) {
+        self.writer.dedent();
+        self.writer.write("}\n\n");
+    }
+}
+
+/// Default mock value of built-in scalars.
+fn builtin_scalar_mock(name: &str) -> Option<&'static str> {
+    match name {
+        "ID" => Some("\"id\""),
+        "String" => Some("\"\""),
+        "Int" | "Float" => Some("0"),
+        "Boolean" => Some("false"),
+        _ => None,
+    }
+}
+
+/// Default value of a field of given type.
+fn field_mock(ty: &Type) -> String {
+    match ty {
+        Type::NonNull(inner) => match inner.r#type {
+            Type::Named(ref name) => format!("{}()", mock_function_name(name.name.name)),
+            Type::List(_) | Type::NonNull(_) => "[]".to_owned(),
+        },
+        Type::Named(_) | Type::List(_) => "null".to_owned(),
+    }
+}
+
+/// Relationship between output types that affects generated factories.
+struct MockTypes<'a, 'src> {
+    objects: HashMap<&'src str, &'a ObjectTypeDefinition<'src>>,
+    /// Object type that is mocked for each abstract type.
+    concrete_types: HashMap<&'src str, &'src str>,
+}
+
+impl<'a, 'src> MockTypes<'a, 'src> {
+    fn new(document: &'a TypeSystemDocument<'src>) -> Self {
+        let type_defs = document.definitions.iter().filter_map(|def| match def {
+            TypeSystemDefinition::TypeDefinition(def) => Some(def),
+            _ => None,
+        });
+        let objects: HashMap<_, _> = type_defs
+            .clone()
+            .filter_map(|def| match def {
+                TypeDefinition::Object(def) => Some((def.name.name, def)),
+                _ => None,
+            })
+            .collect();
+        // The first possible type in the document is chosen.
+        let concrete_types = type_defs
+            .filter_map(|def| match def {
+                TypeDefinition::Union(def) => def
+                    .members
+                    .iter()
+                    .find(|member| objects.contains_key(member.name))
+                    .map(|member| (def.name.name, member.name)),
+                TypeDefinition::Interface(def) => document
+                    .definitions
+                    .iter()
+                    .find_map(|other| match other {
+                        TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(object))
+                            if object
+                                .implements
+                                .iter()
+                                .any(|interface| interface.name == def.name.name) =>
+                        {
+                            Some(object.name.name)
+                        }
+                        _ => None,
+                    })
+                    .map(|object| (def.name.name, object)),
+                _ => None,
+            })
+            .collect();
+        MockTypes {
+            objects,
+            concrete_types,
+        }
+    }
+
+    /// Returns the object type that is mocked for given type.
+    fn concrete_type(&self, type_name: &str) -> Option<&'src str> {
+        match self.objects.get_key_value(type_name) {
+            Some((name, _)) => Some(name),
+            None => self.concrete_types.get(type_name).copied(),
+        }
+    }
+
+    /// Object type that the default value of given field refers to.
+    fn field_object_type(&self, field: &FieldDefinition) -> Option<&'src str> {
+        match field.r#type {
+            Type::NonNull(ref inner) => match inner.r#type {
+                Type::Named(ref name) => self.concrete_type(name.name.name),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Checks whether the default value of given field refers back to the parent type.
+    fn is_cyclic_field(&self, parent_type: &str, field: &FieldDefinition) -> bool {
+        let Some(field_type) = self.field_object_type(field) else {
+            return false;
+        };
+        let mut visited = HashSet::new();
+        let mut stack = vec![field_type];
+        while let Some(type_name) = stack.pop() {
+            if type_name == parent_type {
+                return true;
+            }
+            if !visited.insert(type_name) {
+                continue;
+            }
+            if let Some(object) = self.objects.get(type_name) {
+                stack.extend(
+                    object
+                        .fields
+                        .iter()
+                        .filter_map(|field| self.field_object_type(field)),
+                );
+            }
+        }
+        false
+    }
+}
@@ -0,0 +1,121 @@
+#![cfg(test)]
+
+use insta::assert_snapshot;
+use nitrogql_ast::type_system::TypeSystemDocument;
+use nitrogql_config_file::EnumStyle;
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_semantics::resolve_schema_extensions;
+use sourcemap_writer::JustWriter;
+
+use crate::mocks_printer::{
+    options::MocksPrinterOptions, printer::MocksPrinter, MocksPrinterWarning,
+};
+
+fn type_system() -> TypeSystemDocument<'static> {
+    let doc = parse_type_system_document(
+        "
+        scalar ID
+        scalar String
+        scalar Int
+        scalar Boolean
+        scalar Date
+
+        enum UserType {
+            NormalUser
+            PremiumUser
+        }
+
+        interface HasID {
+            id: ID!
+        }
+
+        type User implements HasID {
+            id: ID!
+            name: String!
+            type: UserType!
+            age: Int
+            createdAt: Date!
+            posts: [Post!]!
+            bestFriend: User
+            pinnedPost: Post!
+        }
+
+        type Post implements HasID {
+            id: ID!
+            title: String!
+            author: User!
+        }
+
+        union SearchResult = User | Post
+
+        input UserSearchQuery {
+            name: String
+        }
+
+        type Query {
+            me: User!
+            node: HasID
+            search: [SearchResult!]!
+            latestPost: Post!
+        }
+        ",
+    )
+    .unwrap();
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn print_document(
+    document: &TypeSystemDocument,
+    options: MocksPrinterOptions,
+) -> (String, Vec<MocksPrinterWarning>) {
+    let mut result = String::new();
+    let mut writer = JustWriter::new(&mut result);
+    let mut printer = MocksPrinter::new(options, &mut writer);
+    let warnings = printer.print_document(document);
+    (result, warnings)
+}
+
+fn default_options() -> MocksPrinterOptions {
+    MocksPrinterOptions {
+        schema_source: "./schema".into(),
+        scalar_mocks: vec![("Date".into(), "new Date(0).toISOString()".into())]
+            .into_iter()
+            .collect(),
+        ..MocksPrinterOptions::default()
+    }
+}
+
+#[test]
+fn mock_factories() {
+    let (printed, warnings) = print_document(&type_system(), default_options());
+    assert_snapshot!(printed);
+    assert!(warnings.is_empty());
+}
+
+#[test]
+fn ts_enum_mocks() {
+    let (printed, _) = print_document(
+        &type_system(),
+        MocksPrinterOptions {
+            enum_style: EnumStyle::TsEnum,
+            ..default_options()
+        },
+    );
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn scalar_mock_not_provided() {
+    let (printed, warnings) = print_document(
+        &type_system(),
+        MocksPrinterOptions {
+            scalar_mocks: Default::default(),
+            ..default_options()
+        },
+    );
+    assert!(printed.contains("return null as unknown as Schema.__OperationOutput.Date;"));
+    assert!(matches!(
+        warnings.as_slice(),
+        [MocksPrinterWarning::ScalarMockNotProvided { name, .. }] if name == "Date"
+    ));
+}
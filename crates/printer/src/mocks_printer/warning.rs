@@ -0,0 +1,18 @@
+use thiserror::Error;
+
+use nitrogql_ast::base::Pos;
+
+#[derive(Error, Debug)]
+pub enum MocksPrinterWarning {
+    #[error("Mock value for scalar '{name}' is not provided. Its mock factory returns null")]
+    ScalarMockNotProvided { position: Pos, name: String },
+}
+
+impl MocksPrinterWarning {
+    /// Position of the definition that the warning is about.
+    pub fn position(&self) -> Pos {
+        match self {
+            MocksPrinterWarning::ScalarMockNotProvided { position, .. } => *position,
+        }
+    }
+}
@@ -1,6 +1,9 @@
+use std::{borrow::Cow, collections::HashMap};
+
 use nitrogql_ast::operation::{
-    ExecutableDefinition, OperationDefinition, OperationDocument, OperationType,
+    ExecutableDefinition, FragmentDefinition, OperationDefinition, OperationDocument, OperationType,
 };
+use nitrogql_semantics::remove_client_fields;
 use nitrogql_utils::capitalize;
 use sourcemap_writer::SourceMapWriter;
 
@@ -42,18 +45,24 @@ where
             .iter()
             .filter(|def| matches!(def, ExecutableDefinition::OperationDefinition(_)))
             .count();
-        let fragments = document
+        // Runtime values are printed from the document sent to the server.
+        let runtime_document = if self.options.remove_client_fields {
+            Cow::Owned(remove_client_fields(document))
+        } else {
+            Cow::Borrowed(document)
+        };
+        let fragments = fragment_map(&runtime_document);
+
+        for (d, runtime_d) in document
             .definitions
             .iter()
-            .filter_map(|def| match def {
-                ExecutableDefinition::FragmentDefinition(def) => Some((def.name.name, def)),
-                _ => None,
-            })
-            .collect();
-
-        for d in document.definitions.iter() {
-            match d {
-                ExecutableDefinition::OperationDefinition(ref def) => {
+            .zip(runtime_document.definitions.iter())
+        {
+            match (d, runtime_d) {
+                (
+                    ExecutableDefinition::OperationDefinition(ref def),
+                    ExecutableDefinition::OperationDefinition(ref runtime_def),
+                ) => {
                     let operation_names = operation_variable_name(&self.options, def);
                     let context = PrintOperationContext {
                         operation_names: &operation_names,
@@ -62,6 +71,7 @@ where
                         export_result_type: self.options.export_result_type,
                         operation: def,
                         fragments: &fragments,
+                        runtime_operation: runtime_def,
                     };
                     self.visitor
                         .print_operation_definition(context, self.writer);
@@ -71,7 +81,10 @@ where
                             .print_default_exported_operation_definition(context, self.writer);
                     }
                 }
-                ExecutableDefinition::FragmentDefinition(ref def) => {
+                (
+                    ExecutableDefinition::FragmentDefinition(ref def),
+                    ExecutableDefinition::FragmentDefinition(ref runtime_def),
+                ) => {
                     // do not export fragment definitions imported from other files.
                     let exported = document.position.file == def.position.file;
                     let var_name =
@@ -81,9 +94,11 @@ where
                         exported,
                         fragment: def,
                         fragments: &fragments,
+                        runtime_fragment: runtime_def,
                     };
                     self.visitor.print_fragment_definition(context, self.writer);
                 }
+                _ => unreachable!("removing client fields does not change definitions"),
             }
         }
 
@@ -91,6 +106,19 @@ where
     }
 }
 
+fn fragment_map<'a>(
+    document: &'a OperationDocument<'a>,
+) -> HashMap<&'a str, &'a FragmentDefinition<'a>> {
+    document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            ExecutableDefinition::FragmentDefinition(def) => Some((def.name.name, def)),
+            _ => None,
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct OperationNames {
     /// The (possibly capitalized) name of the operation.
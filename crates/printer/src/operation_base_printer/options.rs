@@ -22,6 +22,8 @@ pub struct OperationBasePrinterOptions {
     pub subscription_variable_suffix: String,
     /// Suffix for variable of fragment.
     pub fragment_variable_suffix: String,
+    /// Whether selections marked with `@client` are removed from runtime documents.
+    pub remove_client_fields: bool,
 }
 
 impl Default for OperationBasePrinterOptions {
@@ -36,6 +38,7 @@ impl Default for OperationBasePrinterOptions {
             mutation_variable_suffix: "Mutation".to_owned(),
             subscription_variable_suffix: "Subscription".to_owned(),
             fragment_variable_suffix: "".to_owned(),
+            remove_client_fields: false,
         }
     }
 }
@@ -49,6 +52,7 @@ impl OperationBasePrinterOptions {
             named_export_for_operation: !config.generate.export.default_export_for_operation,
             export_input_type: config.generate.export.variables_type,
             export_result_type: config.generate.export.operation_result_type,
+            remove_client_fields: !config.client_schema.is_empty(),
             ..Self::default()
         };
         clone_into(
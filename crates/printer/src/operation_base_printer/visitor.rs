@@ -42,8 +42,11 @@ pub struct PrintOperationContext<'a> {
     pub export_result_type: bool,
     /// Operation definition.
     pub operation: &'a OperationDefinition<'a>,
-    /// Map from fragment names to fragment definitions.
+    /// Map from fragment names to fragment definitions printed as runtime values.
     pub fragments: &'a HashMap<&'a str, &'a FragmentDefinition<'a>>,
+    /// Operation definition printed as the runtime value.
+    /// Client-only selections are removed if configured.
+    pub runtime_operation: &'a OperationDefinition<'a>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -54,6 +57,9 @@ pub struct PrintFragmentContext<'a> {
     pub exported: bool,
     /// Fragment definition.
     pub fragment: &'a FragmentDefinition<'a>,
-    /// Map from fragment names to fragment definitions.
+    /// Map from fragment names to fragment definitions printed as runtime values.
     pub fragments: &'a HashMap<&'a str, &'a FragmentDefinition<'a>>,
+    /// Fragment definition printed as the runtime value.
+    /// Client-only selections are removed if configured.
+    pub runtime_fragment: &'a FragmentDefinition<'a>,
 }
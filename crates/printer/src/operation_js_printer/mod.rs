@@ -10,7 +10,10 @@ mod printers;
 mod tests;
 pub mod visitor;
 
-pub use printers::{print_fragment_runtime, print_operation_runtime};
+pub use printers::{
+    operation_document_definitions, print_fragment_runtime, print_operation_runtime,
+    print_persisted_operation_runtime,
+};
 
 /// Print a JavaScript module for given operation document.
 pub fn print_js_for_operation_document(
@@ -19,7 +22,7 @@ pub fn print_js_for_operation_document(
     writer: &mut impl SourceMapWriter,
 ) {
     let base_options = options.base_options;
-    let visitor = OperationJSPrinterVisitor::new();
+    let visitor = OperationJSPrinterVisitor::new(options.document_mode);
     let mut printer = OperationPrinter::new(base_options, visitor, writer);
     printer.print_document(operation);
 }
@@ -1,10 +1,12 @@
-use nitrogql_config_file::Config;
+use nitrogql_config_file::{Config, DocumentMode};
 
 use crate::operation_base_printer::options::OperationBasePrinterOptions;
 
 #[derive(Clone, Debug, Default)]
 pub struct OperationJSPrinterOptions {
     pub base_options: OperationBasePrinterOptions,
+    /// What is printed as the runtime value of operations.
+    pub document_mode: DocumentMode,
 }
 
 impl OperationJSPrinterOptions {
@@ -12,6 +14,7 @@ impl OperationJSPrinterOptions {
     pub fn from_config(config: &Config) -> Self {
         Self {
             base_options: OperationBasePrinterOptions::from_config(config),
+            document_mode: config.generate.persisted_queries.document_mode,
         }
     }
 }
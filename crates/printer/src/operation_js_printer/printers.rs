@@ -2,8 +2,10 @@ use std::collections::HashMap;
 
 use crate::{
     json_printer::{print_to_json_string, ExecutableDefinitionRef},
+    persisted_query::persisted_operation,
     utils::fragment_names_in_selection_set,
 };
+use json_writer::JSONObjectWriter;
 use nitrogql_ast::operation::{FragmentDefinition, OperationDefinition};
 use sourcemap_writer::SourceMapWriter;
 
@@ -13,6 +15,35 @@ pub fn print_operation_runtime(
     operation: &OperationDefinition,
     fragments: &HashMap<&str, &FragmentDefinition>,
 ) {
+    let this_document = operation_document_definitions(operation, fragments);
+    writer.write(&print_to_json_string(&this_document[..]));
+}
+
+/// Print the runtime value of given operation without the document.
+/// Only the hash of the document and metadata of the operation are included.
+pub fn print_persisted_operation_runtime(
+    writer: &mut impl SourceMapWriter,
+    operation: &OperationDefinition,
+    fragments: &HashMap<&str, &FragmentDefinition>,
+) {
+    let persisted = persisted_operation(operation, fragments);
+    let mut buffer = String::new();
+    let mut json_writer = JSONObjectWriter::new(&mut buffer);
+    json_writer.value("documentId", &persisted.hash);
+    let mut meta_writer = json_writer.object("__meta__");
+    meta_writer.value("operationName", operation.name.map(|name| name.name));
+    meta_writer.value("operationType", operation.operation_type.as_str());
+    meta_writer.end();
+    json_writer.end();
+    writer.write(&buffer);
+}
+
+/// Collects definitions that constitute the document of given operation.
+/// The operation comes first, followed by all fragments used by the operation.
+pub fn operation_document_definitions<'a>(
+    operation: &'a OperationDefinition<'a>,
+    fragments: &'a HashMap<&'a str, &'a FragmentDefinition<'a>>,
+) -> Vec<ExecutableDefinitionRef<'a>> {
     let fragments_to_include = fragment_names_in_selection_set(&operation.selection_set, |name| {
         fragments.get(name).copied()
     })
@@ -22,11 +53,10 @@ pub fn print_operation_runtime(
             fragments.get(name).expect("fragment not found"),
         )
     });
-    let this_document = vec![ExecutableDefinitionRef::OperationDefinition(operation)]
+    vec![ExecutableDefinitionRef::OperationDefinition(operation)]
         .into_iter()
         .chain(fragments_to_include)
-        .collect::<Vec<_>>();
-    writer.write(&print_to_json_string(&this_document[..]));
+        .collect()
 }
 
 /// Print the runtime value of given fragment.
@@ -2,11 +2,15 @@
 
 use insta::assert_snapshot;
 use nitrogql_ast::OperationDocument;
+use nitrogql_config_file::DocumentMode;
 use nitrogql_parser::parse_operation_document;
 use nitrogql_semantics::resolve_operation_extensions;
 use sourcemap_writer::JustWriter;
 
-use crate::{print_js_for_operation_document, OperationJSPrinterOptions};
+use crate::{
+    operation_base_printer::options::OperationBasePrinterOptions, persisted_operations,
+    print_js_for_operation_document, OperationJSPrinterOptions,
+};
 
 #[test]
 fn print_query() {
@@ -167,6 +171,93 @@ fn can_print_mutually_recursive_fragments() {
     assert_snapshot!(print_js(&document));
 }
 
+#[test]
+fn print_persisted_only_query() {
+    let document = parse(
+        r#"
+        query MyQuery {
+            user {
+                ...Foo
+            }
+        }
+        mutation UpdateUser {
+            updateUser {
+                id
+            }
+        }
+
+        fragment Foo on User {
+            id name
+        }
+    "#,
+    );
+    let options = OperationJSPrinterOptions {
+        document_mode: DocumentMode::PersistedOnly,
+        ..OperationJSPrinterOptions::default()
+    };
+    let printed = print_js_with_options(&document, options);
+    // The id must be the same as the one in the persisted query manifest
+    for op in persisted_operations(&document) {
+        assert!(printed.contains(&op.hash));
+    }
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn omit_unmask_directive() {
+    let document = parse(
+        r#"
+        query MyQuery {
+            user {
+                ...Foo @unmask
+                ...Bar @include(if: true)
+            }
+        }
+
+        fragment Foo on User {
+            id
+        }
+        fragment Bar on User {
+            name
+        }
+    "#,
+    );
+    let printed = print_js(&document);
+    assert!(!printed.contains("unmask"));
+    assert!(printed.contains("include"));
+}
+
+#[test]
+fn remove_client_fields() {
+    let document = parse(
+        r#"
+        query MyQuery {
+            isLoggedIn @client
+            user {
+                id
+                ...Foo
+            }
+        }
+
+        fragment Foo on User {
+            name
+            isSelected @client
+        }
+    "#,
+    );
+    let options = OperationJSPrinterOptions {
+        base_options: OperationBasePrinterOptions {
+            remove_client_fields: true,
+            ..OperationBasePrinterOptions::default()
+        },
+        ..OperationJSPrinterOptions::default()
+    };
+    let printed = print_js_with_options(&document, options);
+    assert!(!printed.contains("isLoggedIn"));
+    assert!(!printed.contains("isSelected"));
+    assert_snapshot!(printed);
+}
+
 fn parse(str: &str) -> OperationDocument {
     let doc = parse_operation_document(str).unwrap();
     let (document, _) = resolve_operation_extensions(doc).unwrap();
@@ -174,9 +265,15 @@ fn parse(str: &str) -> OperationDocument {
 }
 
 fn print_js(document: &OperationDocument) -> String {
+    print_js_with_options(document, OperationJSPrinterOptions::default())
+}
+
+fn print_js_with_options(
+    document: &OperationDocument,
+    options: OperationJSPrinterOptions,
+) -> String {
     let mut buffer = String::new();
     let mut writer = JustWriter::new(&mut buffer);
-    let options = OperationJSPrinterOptions::default();
     print_js_for_operation_document(options, document, &mut writer);
     buffer
 }
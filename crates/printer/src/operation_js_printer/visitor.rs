@@ -1,16 +1,21 @@
+use nitrogql_config_file::DocumentMode;
 use sourcemap_writer::SourceMapWriter;
 
 use crate::operation_base_printer::{
     OperationPrinterVisitor, PrintFragmentContext, PrintOperationContext,
 };
 
-use super::printers::{print_fragment_runtime, print_operation_runtime};
+use super::printers::{
+    print_fragment_runtime, print_operation_runtime, print_persisted_operation_runtime,
+};
 
-pub struct OperationJSPrinterVisitor {}
+pub struct OperationJSPrinterVisitor {
+    document_mode: DocumentMode,
+}
 
 impl OperationJSPrinterVisitor {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(document_mode: DocumentMode) -> Self {
+        Self { document_mode }
     }
 }
 
@@ -32,7 +37,18 @@ impl OperationPrinterVisitor for OperationJSPrinterVisitor {
             &operation.name_pos(),
         );
         writer.write(" = ");
-        print_operation_runtime(writer, operation, context.fragments);
+        match self.document_mode {
+            DocumentMode::Full => {
+                print_operation_runtime(writer, context.runtime_operation, context.fragments);
+            }
+            DocumentMode::PersistedOnly => {
+                print_persisted_operation_runtime(
+                    writer,
+                    context.runtime_operation,
+                    context.fragments,
+                );
+            }
+        }
         writer.write(";\n\n");
     }
 
@@ -50,7 +66,7 @@ impl OperationPrinterVisitor for OperationJSPrinterVisitor {
 
         writer.write_for(context.var_name, fragment);
         writer.write(" = ");
-        print_fragment_runtime(writer, fragment, context.fragments);
+        print_fragment_runtime(writer, context.runtime_fragment, context.fragments);
         writer.write(";\n\n");
     }
     fn print_default_exported_operation_definition(
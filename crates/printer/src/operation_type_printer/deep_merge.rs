@@ -4,7 +4,7 @@ use graphql_type_system::Text;
 
 use super::selection_tree::{
     SelectionTree, SelectionTreeBranch, SelectionTreeEmptyLeaf, SelectionTreeField,
-    SelectionTreeObject,
+    SelectionTreeFragmentRefs, SelectionTreeObject,
 };
 
 /// Merges selection tree fields of same name into one.
@@ -70,6 +70,18 @@ fn merge_fields<'src, S: Text<'src>>(
         (SelectionTreeField::Object(left), SelectionTreeField::Empty(_)) => {
             SelectionTreeField::Object(left)
         }
+        (SelectionTreeField::FragmentRefs(left), SelectionTreeField::FragmentRefs(right)) => {
+            let mut fragments = left.fragments;
+            for fragment in right.fragments {
+                if !fragments.contains(&fragment) {
+                    fragments.push(fragment);
+                }
+            }
+            SelectionTreeField::FragmentRefs(SelectionTreeFragmentRefs {
+                name: left.name,
+                fragments,
+            })
+        }
         (left, right) => panic!(
             "Cannot merge fields of different types\nleft: {:?}\nright: {:?}",
             left, right
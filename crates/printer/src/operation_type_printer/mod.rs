@@ -6,10 +6,14 @@ use sourcemap_writer::SourceMapWriter;
 
 use crate::operation_base_printer::OperationPrinter;
 
-use self::visitor::{OperationTypePrinterOptions, OperationTypePrinterVisitor};
+use self::{
+    plugin::OperationTypePrinterPlugin,
+    visitor::{OperationTypePrinterOptions, OperationTypePrinterVisitor},
+};
 
 mod branching;
 mod deep_merge;
+pub mod plugin;
 mod selection_set_visitor;
 mod selection_tree;
 #[cfg(test)]
@@ -22,10 +26,12 @@ pub fn print_types_for_operation_document(
     options: OperationTypePrinterOptions,
     schema: &Schema<Cow<str>, Pos>,
     operation: &OperationDocument,
+    plugins: &[impl OperationTypePrinterPlugin],
     writer: &mut impl SourceMapWriter,
 ) {
     let base_options = options.base_options.clone();
-    let visitor = OperationTypePrinterVisitor::new(options, schema, operation);
+    let mut visitor = OperationTypePrinterVisitor::new(options, schema, operation);
+    visitor.apply_plugins(plugins);
     let mut printer = OperationPrinter::new(base_options, visitor, writer);
     printer.print_document(operation);
 }
@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use nitrogql_ast::OperationDocument;
+
+use crate::ts_types::TSType;
+
+/// A plugin that can transform output types of operations.
+pub trait OperationTypePrinterPlugin {
+    /// Transform output types of operations and fragments.
+    /// Keys are names of operations and fragments in the document.
+    fn transform_operation_output_types<'src>(
+        &self,
+        document: &OperationDocument<'src>,
+        base: HashMap<&'src str, TSType>,
+    ) -> HashMap<&'src str, TSType>;
+}
@@ -1,9 +1,10 @@
 use graphql_type_system::Text;
 use nitrogql_ast::selection_set::{Selection, SelectionSet};
 
-use super::type_printer::QueryTypePrinterContext;
+use super::type_printer::{is_masked_fragment_spread, QueryTypePrinterContext};
 
-/// Visits all fields in selection set. Nested fragments are visited as well.
+/// Visits all fields in selection set. Nested fragments are visited as well,
+/// except for masked ones.
 pub fn visit_fields_in_selection_set<'src, S: Text<'src>>(
     context: &QueryTypePrinterContext<'_, 'src, S>,
     selection_set: &SelectionSet<'src>,
@@ -23,6 +24,9 @@ fn visit_fields_in_selection_set_impl<'src, S: Text<'src>>(
         match sel {
             Selection::Field(_) => {}
             Selection::FragmentSpread(ref fragment) => {
+                if is_masked_fragment_spread(context, fragment) {
+                    continue;
+                }
                 if seen_fragments.contains(&fragment.fragment_name.name) {
                     continue;
                 }
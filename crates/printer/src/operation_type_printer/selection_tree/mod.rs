@@ -4,8 +4,12 @@
 use graphql_type_system::Type;
 use nitrogql_ast::base::Pos;
 
+mod to_mock;
+mod to_schema;
 mod to_ts;
 
+pub use to_mock::{generate_branch_mock_fields, GenerateSelectionTreeMockContext};
+pub use to_schema::{generate_selection_tree_schema, GenerateSelectionTreeSchemaContext};
 pub use to_ts::{generate_selection_tree_type, GenerateSelectionTreeTypeContext};
 
 #[derive(Debug, Clone)]
@@ -30,6 +34,7 @@ pub enum SelectionTreeField<S> {
     Empty(SelectionTreeEmptyLeaf<S>),
     Leaf(SelectionTreeLeaf<S>),
     Object(SelectionTreeObject<S>),
+    FragmentRefs(SelectionTreeFragmentRefs<S>),
 }
 
 impl<S> SelectionTreeField<S> {
@@ -38,6 +43,7 @@ impl<S> SelectionTreeField<S> {
             SelectionTreeField::Empty(empty) => &empty.name,
             SelectionTreeField::Leaf(leaf) => &leaf.name,
             SelectionTreeField::Object(object) => &object.name,
+            SelectionTreeField::FragmentRefs(refs) => &refs.name,
         }
     }
 }
@@ -66,3 +72,12 @@ pub struct SelectionTreeObject<S> {
     /// Selection for the field.
     pub selection: SelectionTree<S>,
 }
+
+/// Marker of masked fragments in a selection.
+#[derive(Debug, Clone)]
+pub struct SelectionTreeFragmentRefs<S> {
+    /// Name of the marker field.
+    pub name: S,
+    /// Names of fragments spread into the selection.
+    pub fragments: Vec<String>,
+}
@@ -0,0 +1,81 @@
+use graphql_type_system::{Text, Type};
+
+use crate::mocks_printer::mock_function_name;
+
+use super::{SelectionTree, SelectionTreeBranch, SelectionTreeField};
+
+#[derive(Debug, Copy, Clone)]
+pub struct GenerateSelectionTreeMockContext<'a> {
+    /// Name of the namespace that contains mock factories.
+    pub mocks_namespace: &'a str,
+}
+
+/// Generate fields of a mock value of given branch.
+/// Each item is a pair of the field name and the TypeScript expression of its value.
+pub fn generate_branch_mock_fields<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeMockContext,
+    branch: &SelectionTreeBranch<S>,
+) -> Vec<(String, String)> {
+    let unaliased_fields = branch
+        .unaliased_fields
+        .iter()
+        .filter_map(|field| match field {
+            SelectionTreeField::Leaf(leaf) if leaf.name == "__typename" => {
+                Some((leaf.name.to_string(), format!("\"{}\"", branch.type_name)))
+            }
+            _ => field_mock(context, field).map(|value| (field.name().to_string(), value)),
+        });
+    let aliased_fields = branch.aliased_fields.iter().filter_map(|field| {
+        field_mock(context, field).map(|value| (field.name().to_string(), value))
+    });
+    unaliased_fields.chain(aliased_fields).collect()
+}
+
+/// Generates a mock value of given field.
+/// Returns None for fields that do not exist in the response.
+fn field_mock<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeMockContext,
+    field: &SelectionTreeField<S>,
+) -> Option<String> {
+    match field {
+        SelectionTreeField::Empty(_) | SelectionTreeField::FragmentRefs(_) => None,
+        SelectionTreeField::Leaf(leaf) => Some(match leaf.r#type {
+            Type::NonNull(ref inner) => match inner.as_inner() {
+                Type::Named(name) => {
+                    format!("{}.{}()", context.mocks_namespace, mock_function_name(name))
+                }
+                Type::List(_) | Type::NonNull(_) => "[]".to_owned(),
+            },
+            Type::Named(_) | Type::List(_) => "null".to_owned(),
+        }),
+        SelectionTreeField::Object(object) => Some(tree_mock(context, &object.selection)),
+    }
+}
+
+fn tree_mock<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeMockContext,
+    tree: &SelectionTree<S>,
+) -> String {
+    let SelectionTree::NonNull(inner) = tree else {
+        return "null".to_owned();
+    };
+    match **inner {
+        SelectionTree::Object(ref branches) => match branches.first() {
+            Some(branch) => object_literal(&generate_branch_mock_fields(context, branch)),
+            None => "null".to_owned(),
+        },
+        SelectionTree::List(_) | SelectionTree::NonNull(_) => "[]".to_owned(),
+    }
+}
+
+fn object_literal(fields: &[(String, String)]) -> String {
+    if fields.is_empty() {
+        return "{}".to_owned();
+    }
+    let fields = fields
+        .iter()
+        .map(|(name, value)| format!("{name}: {value}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    format!("{{ {fields} }}")
+}
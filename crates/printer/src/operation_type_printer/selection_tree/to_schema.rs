@@ -0,0 +1,202 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use graphql_type_system::{Schema, Text, Type, TypeDefinition};
+use itertools::Itertools;
+use nitrogql_ast::base::Pos;
+use nitrogql_config_file::{EnumStyle, TypeTarget, ValidationSchemaLibrary};
+
+use crate::validation_schema_printer::library::LibrarySyntax;
+
+use super::{SelectionTree, SelectionTreeBranch, SelectionTreeField};
+
+pub struct GenerateSelectionTreeSchemaContext<'a, S> {
+    pub schema: &'a Schema<S, Pos>,
+    pub schema_root_namespace: &'a str,
+    /// Library used by generated schemas.
+    pub library: ValidationSchemaLibrary,
+    /// Validation schema of each scalar.
+    pub scalar_schemas: &'a HashMap<String, String>,
+    /// Style of enums emitted in the schema file.
+    pub enum_style: EnumStyle,
+}
+
+/// Generate a runtime validation schema of the selection tree.
+pub fn generate_selection_tree_schema<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    tree: &SelectionTree<S>,
+) -> String {
+    generate_selection_tree_schema_impl(context, tree, false)
+}
+
+fn generate_selection_tree_schema_impl<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    tree: &SelectionTree<S>,
+    is_non_null: bool,
+) -> String {
+    let library = context.library;
+    let schema = match tree {
+        SelectionTree::NonNull(inner) => {
+            return generate_selection_tree_schema_impl(context, inner, true);
+        }
+        SelectionTree::List(inner) => {
+            library.array(&generate_selection_tree_schema_impl(context, inner, false))
+        }
+        SelectionTree::Object(branches) => {
+            let schemas = branches
+                .iter()
+                .map(|branch| branch_schema(context, branch))
+                .collect::<Vec<_>>();
+            if is_discriminated_by_typename(branches) {
+                let schemas = schemas
+                    .into_iter()
+                    .map(|(_, schema)| schema)
+                    .collect::<Vec<_>>();
+                match schemas.len() {
+                    0 => library.never().to_owned(),
+                    1 => schemas.into_iter().next().unwrap(),
+                    _ => library.discriminated_union("__typename", &schemas),
+                }
+            } else {
+                // Unions pick the first matching schema and strip unknown fields,
+                // so schemas with more fields are tried first.
+                let schemas = schemas
+                    .into_iter()
+                    .unique_by(|(_, schema)| schema.clone())
+                    .sorted_by_key(|(field_count, _)| Reverse(*field_count))
+                    .map(|(_, schema)| schema)
+                    .collect::<Vec<_>>();
+                match schemas.len() {
+                    0 => library.never().to_owned(),
+                    1 => schemas.into_iter().next().unwrap(),
+                    _ => library.union(&schemas),
+                }
+            }
+        }
+    };
+    if is_non_null {
+        schema
+    } else {
+        library.nullable(&schema)
+    }
+}
+
+/// Checks whether each branch can be selected by the value of `__typename`.
+fn is_discriminated_by_typename<'src, S: Text<'src>>(branches: &[SelectionTreeBranch<S>]) -> bool {
+    let all_select_typename = branches.iter().all(|branch| {
+        branch.unaliased_fields.iter().any(
+            |field| matches!(field, SelectionTreeField::Leaf(leaf) if leaf.name == "__typename"),
+        )
+    });
+    // Branches are also split by boolean variables,
+    // so the same object type may appear more than once.
+    let type_names_are_unique = branches.iter().map(|branch| &branch.type_name).all_unique();
+    all_select_typename && type_names_are_unique
+}
+
+/// Generates a schema for given branch.
+/// Also returns the number of fields in the branch.
+fn branch_schema<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    branch: &SelectionTreeBranch<S>,
+) -> (usize, String) {
+    let library = context.library;
+    let unaliased_fields = branch
+        .unaliased_fields
+        .iter()
+        .filter_map(|field| match field {
+            SelectionTreeField::Leaf(leaf) if leaf.name == "__typename" => {
+                Some((&leaf.name, library.literal(&branch.type_name)))
+            }
+            _ => field_schema(context, field).map(|schema| (field.name(), schema)),
+        });
+    let aliased_fields = branch
+        .aliased_fields
+        .iter()
+        .filter_map(|field| field_schema(context, field).map(|schema| (field.name(), schema)));
+    let fields = unaliased_fields
+        .chain(aliased_fields)
+        .map(|(name, schema)| format!("{name}: {schema}"))
+        .collect::<Vec<_>>();
+    let schema = if fields.is_empty() {
+        format!("{}{}", library.object_start(), library.object_end())
+    } else {
+        format!(
+            "{} {} {}",
+            library.object_start(),
+            fields.join(", "),
+            library.object_end()
+        )
+    };
+    (fields.len(), schema)
+}
+
+/// Generates a schema for given field.
+/// Returns None for fields that are not validated.
+fn field_schema<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    field: &SelectionTreeField<S>,
+) -> Option<String> {
+    match field {
+        // Omitted fields are checked so that unions do not pick a wrong branch.
+        SelectionTreeField::Empty(_) => Some(context.library.absent().to_owned()),
+        // Fragment references only exist in types.
+        SelectionTreeField::FragmentRefs(_) => None,
+        SelectionTreeField::Leaf(leaf) => Some(leaf_schema(context, &leaf.r#type)),
+        SelectionTreeField::Object(object) => {
+            Some(generate_selection_tree_schema(context, &object.selection))
+        }
+    }
+}
+
+fn leaf_schema<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    ty: &Type<S, Pos>,
+) -> String {
+    let library = context.library;
+    match ty {
+        Type::NonNull(inner) => match inner.as_inner() {
+            Type::Named(name) => named_schema(context, name),
+            Type::List(inner) => library.array(&leaf_schema(context, inner)),
+            Type::NonNull(_) => leaf_schema(context, inner.as_inner()),
+        },
+        Type::List(inner) => library.nullable(&library.array(&leaf_schema(context, inner))),
+        Type::Named(name) => library.nullable(&named_schema(context, name)),
+    }
+}
+
+fn named_schema<'src, S: Text<'src>>(
+    context: &GenerateSelectionTreeSchemaContext<S>,
+    type_name: &str,
+) -> String {
+    let library = context.library;
+    let output_type = || {
+        format!(
+            "{}.{}.{}",
+            context.schema_root_namespace,
+            TypeTarget::OperationOutput,
+            type_name
+        )
+    };
+    match context.schema.get_type(type_name).map(|def| &**def) {
+        Some(TypeDefinition::Enum(def)) => match context.enum_style {
+            EnumStyle::Union | EnumStyle::ConstObject => {
+                let values = def
+                    .members
+                    .iter()
+                    .map(|member| member.name.borrow())
+                    .collect::<Vec<_>>();
+                library.string_enum(&values)
+            }
+            EnumStyle::TsEnum => {
+                library.native_enum(&format!("{}.{}", context.schema_root_namespace, type_name))
+            }
+        },
+        _ => context
+            .scalar_schemas
+            .get(type_name)
+            .map(|schema| schema.as_str())
+            .or_else(|| library.builtin_scalar(type_name, TypeTarget::OperationOutput))
+            .map(|schema| schema.to_owned())
+            .unwrap_or_else(|| library.custom(&output_type())),
+    }
+}
@@ -8,6 +8,10 @@ use super::{SelectionTree, SelectionTreeField};
 #[derive(Debug, Copy, Clone)]
 pub struct GenerateSelectionTreeTypeContext<'a> {
     pub schema_root_namespace: &'a str,
+    /// Suffix for type of fragment.
+    pub fragment_type_suffix: &'a str,
+    /// Whether fields and lists are readonly.
+    pub readonly_output: bool,
 }
 
 /// Generate a TypeScript representation of the selection tree.
@@ -26,9 +30,12 @@ fn generate_selection_tree_type_impl<'src, S: Text<'src>>(
     match tree {
         SelectionTree::NonNull(inner) => generate_selection_tree_type_impl(context, inner, true),
         SelectionTree::List(inner) => {
-            let list_type = TSType::Array(Box::new(generate_selection_tree_type_impl(
-                context, inner, false,
-            )));
+            let item_type = Box::new(generate_selection_tree_type_impl(context, inner, false));
+            let list_type = if context.readonly_output {
+                TSType::ReadonlyArray(item_type)
+            } else {
+                TSType::Array(item_type)
+            };
             if is_non_null {
                 list_type
             } else {
@@ -61,10 +68,19 @@ fn generate_selection_tree_type_impl<'src, S: Text<'src>>(
                         .map(|field| field_to_type(context, field))
                         .collect(),
                 );
-                TSType::TypeFunc(
+                let selection_set = TSType::TypeFunc(
                     Box::new(selection_set_utility),
                     vec![schema_type, unaliased_object, aliased_object],
-                )
+                );
+                if context.readonly_output {
+                    // __SelectionSet does not preserve modifiers of given fields.
+                    TSType::TypeFunc(
+                        Box::new(TSType::TypeVariable("Readonly".into())),
+                        vec![selection_set],
+                    )
+                } else {
+                    selection_set
+                }
             }));
             if is_non_null {
                 branches_type
@@ -85,7 +101,7 @@ fn field_to_type<'src, S: Text<'src>>(
             r#type: TSType::Never,
             description: None,
             optional: true,
-            readonly: false,
+            readonly: context.readonly_output,
         },
         SelectionTreeField::Leaf(leaf) => {
             let field_type = if leaf.name == "__typename" {
@@ -100,12 +116,17 @@ fn field_to_type<'src, S: Text<'src>>(
                     )
                 })
             };
+            let field_type = if context.readonly_output {
+                field_type.into_readonly()
+            } else {
+                field_type
+            };
             ObjectField {
                 key: leaf.name.to_string().into(),
                 r#type: field_type,
                 description: None,
                 optional: false,
-                readonly: false,
+                readonly: context.readonly_output,
             }
         }
         SelectionTreeField::Object(object) => ObjectField {
@@ -113,7 +134,24 @@ fn field_to_type<'src, S: Text<'src>>(
             r#type: generate_selection_tree_type_impl(context, &object.selection, false),
             description: None,
             optional: false,
-            readonly: false,
+            readonly: context.readonly_output,
+        },
+        SelectionTreeField::FragmentRefs(refs) => ObjectField {
+            key: refs.name.to_string().into(),
+            r#type: TSType::object(refs.fragments.iter().map(|fragment| {
+                (
+                    fragment.as_str(),
+                    TSType::TypeVariable(
+                        format!("{}{}", fragment, context.fragment_type_suffix)
+                            .as_str()
+                            .into(),
+                    ),
+                    None,
+                )
+            })),
+            description: None,
+            optional: false,
+            readonly: context.readonly_output,
         },
     }
 }
@@ -5,6 +5,7 @@ use insta::assert_snapshot;
 use graphql_builtins::generate_builtins;
 use nitrogql_ast::{set_current_file_of_pos, OperationDocumentExt};
 use nitrogql_ast::{OperationDocument, TypeSystemDocument};
+use nitrogql_config_file::DocumentMode;
 use nitrogql_parser::{parse_operation_document, parse_type_system_document};
 use nitrogql_semantics::{
     ast_to_type_system, resolve_operation_extensions, OperationExtension, OperationResolver,
@@ -14,7 +15,22 @@ use sourcemap_writer::JustWriter;
 
 use crate::operation_base_printer::options::OperationBasePrinterOptions;
 use crate::print_types_for_operation_document;
+use crate::ts_types::TSType;
 use crate::OperationTypePrinterOptions;
+use crate::OperationTypePrinterPlugin;
+
+struct DummyPlugin;
+impl OperationTypePrinterPlugin for DummyPlugin {
+    fn transform_operation_output_types<'src>(
+        &self,
+        _document: &OperationDocument<'src>,
+        _base: std::collections::HashMap<&'src str, TSType>,
+    ) -> std::collections::HashMap<&'src str, TSType> {
+        unimplemented!()
+    }
+}
+
+static EMPTY_PLUGINS: &[DummyPlugin] = &[];
 
 fn type_system() -> TypeSystemDocument<'static> {
     let mut doc = parse_type_system_document(
@@ -259,10 +275,66 @@ fn print_values() {
         print_values: true,
         ..Default::default()
     };
-    print_types_for_operation_document(options, &schema, &doc, &mut writer);
+    print_types_for_operation_document(options, &schema, &doc, EMPTY_PLUGINS, &mut writer);
     assert_snapshot!(result);
 }
 
+#[test]
+fn print_persisted_only_values() {
+    let doc = parse_operation_document(
+        "
+        query GetMe {
+            me {
+                ...F
+            }
+        }
+        fragment F on User {
+            id name
+        }
+        ",
+    )
+    .unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    let options = OperationTypePrinterOptions {
+        print_values: true,
+        document_mode: DocumentMode::PersistedOnly,
+        ..Default::default()
+    };
+    let printed = print_document(&doc, options);
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn readonly_output() {
+    let doc = parse_operation_document(
+        "
+        query test {
+            me {
+                id
+                userName: name
+                posts {
+                    id
+                }
+            }
+            posts {
+                title
+                tags
+            }
+        }
+        ",
+    )
+    .unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    let printed = print_document(
+        &doc,
+        OperationTypePrinterOptions {
+            readonly_output: true,
+            ..Default::default()
+        },
+    );
+    assert_snapshot!(printed);
+}
+
 mod skip_include {
     use super::*;
 
@@ -787,6 +859,457 @@ mod import_fragments {
     }
 }
 
+mod fragment_masking {
+    use super::*;
+
+    #[test]
+    fn masked_fragment_spread() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    ...U
+                    posts {
+                        ...F
+                        ...P
+                    }
+                }
+            }
+            fragment U on User {
+                name
+                ...F
+            }
+            fragment F on HasID {
+                id
+            }
+            fragment P on Post {
+                title
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_masked_document(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn unmask_directive() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    ...U @unmask
+                }
+            }
+            fragment U on User {
+                name
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_masked_document(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn conditional_masked_fragment() {
+        let doc = parse_operation_document(
+            "
+            query test($flag: Boolean!) {
+                me {
+                    id
+                    ...U @include(if: $flag)
+                }
+            }
+            fragment U on User {
+                name
+                age @skip(if: $flag)
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_masked_document(&doc);
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_imported_fragment() {
+        let doc = parse_operation_document(
+            "
+            #import UserProfile from \"./user-profile.graphql\"
+            query myQuery {
+                me {
+                    id
+                    ...UserProfile
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let printed = print_masked_document(&doc);
+        assert_snapshot!(printed);
+    }
+
+    fn print_masked_document(document: &OperationDocumentExt) -> String {
+        set_current_file_of_pos(0);
+        let (document, extensions) = resolve_operation_extensions(document.clone()).unwrap();
+        let document = resolve_operation_imports(
+            (Path::new("/path/to/main.graphql"), &document, &extensions),
+            &TestOperationResolver,
+        )
+        .unwrap();
+        print_document(
+            &document,
+            OperationTypePrinterOptions {
+                fragment_masking: true,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+mod result_schemas {
+    use nitrogql_config_file::{
+        Config, ScalarSchemaConfig, SendReceiveScalarTypeConfig, ValidationSchemaLibrary,
+    };
+
+    use super::*;
+
+    #[test]
+    fn result_schema() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    name
+                    type
+                    age
+                    posts {
+                        __typename
+                        id
+                        ... on Post {
+                            title
+                            tags
+                        }
+                    }
+                }
+                nullablePosts {
+                    postId: id
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(&doc, result_schema_options());
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn valibot_result_schema() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    type
+                    posts {
+                        __typename
+                        id
+                    }
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                validation_schema_library: ValidationSchemaLibrary::Valibot,
+                ..result_schema_options()
+            },
+        );
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn branches_without_typename() {
+        let doc = parse_operation_document(
+            "
+            query test($flag: Boolean!) {
+                me {
+                    posts {
+                        id
+                        ... on Post {
+                            title
+                        }
+                    }
+                    age @include(if: $flag)
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(&doc, result_schema_options());
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_fragment_in_result_schema() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    ...U
+                }
+            }
+            fragment U on User {
+                name
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                fragment_masking: true,
+                ..result_schema_options()
+            },
+        );
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn no_result_schema_without_values() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                print_values: false,
+                ..result_schema_options()
+            },
+        );
+        assert!(!printed.contains("testResultSchema"));
+    }
+
+    #[test]
+    fn result_schema_uses_receive_scalar_schemas() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    name
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let mut config = Config::default();
+        config.generate.r#type.scalar_schemas.insert(
+            "String".to_owned(),
+            ScalarSchemaConfig::SendReceive(SendReceiveScalarTypeConfig {
+                send: "z.string().min(1)".to_owned(),
+                receive: "z.string().max(100)".to_owned(),
+            }),
+        );
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                print_values: true,
+                emit_result_schemas: true,
+                ..OperationTypePrinterOptions::from_config(&config)
+            },
+        );
+        assert!(printed.contains("z.string().max(100)"));
+        assert!(!printed.contains("z.string().min(1)"));
+    }
+
+    fn result_schema_options() -> OperationTypePrinterOptions {
+        OperationTypePrinterOptions {
+            print_values: true,
+            emit_result_schemas: true,
+            ..Default::default()
+        }
+    }
+}
+
+mod result_builders {
+    use super::*;
+
+    #[test]
+    fn result_builder() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    __typename
+                    id
+                    name
+                    type
+                    age
+                    posts {
+                        id
+                    }
+                }
+                myPosts: posts {
+                    title
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(&doc, result_builder_options());
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn masked_fragment_in_result_builder() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                    ...U
+                }
+            }
+            fragment U on User {
+                name
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                fragment_masking: true,
+                ..result_builder_options()
+            },
+        );
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn no_result_builder_without_values() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    id
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document(
+            &doc,
+            OperationTypePrinterOptions {
+                print_values: false,
+                ..result_builder_options()
+            },
+        );
+        assert!(!printed.contains("buildTestQueryResult"));
+        assert!(!printed.contains("Mocks"));
+    }
+
+    fn result_builder_options() -> OperationTypePrinterOptions {
+        OperationTypePrinterOptions {
+            print_values: true,
+            mocks_source: Some("./mocks".into()),
+            ..Default::default()
+        }
+    }
+}
+
+mod plugins {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Plugin that wraps output types with `Branded<...>`.
+    struct BrandPlugin;
+    impl OperationTypePrinterPlugin for BrandPlugin {
+        fn transform_operation_output_types<'src>(
+            &self,
+            _document: &OperationDocument<'src>,
+            base: HashMap<&'src str, TSType>,
+        ) -> HashMap<&'src str, TSType> {
+            base.into_iter()
+                .map(|(name, ts_type)| {
+                    let mut printed = String::new();
+                    ts_type.print_type(&mut JustWriter::new(&mut printed));
+                    (name, TSType::Raw(format!("Branded<{printed}, \"{name}\">")))
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn transform_output_types() {
+        let doc = parse_operation_document(
+            "
+            query test {
+                me {
+                    ...U
+                }
+            }
+            fragment U on User {
+                name
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document_with_plugins(
+            &doc,
+            OperationTypePrinterOptions::default(),
+            &[BrandPlugin],
+        );
+        assert_snapshot!(printed);
+    }
+
+    #[test]
+    fn anonymous_operation_is_not_transformed() {
+        let doc = parse_operation_document(
+            "
+            query {
+                me {
+                    name
+                }
+            }
+            ",
+        )
+        .unwrap();
+        let (doc, _) = resolve_operation_extensions(doc).unwrap();
+        let printed = print_document_with_plugins(
+            &doc,
+            OperationTypePrinterOptions::default(),
+            &[BrandPlugin],
+        );
+        assert!(!printed.contains("Branded"));
+    }
+}
+
 struct TestOperationResolver;
 impl<'src> OperationResolver<'src> for TestOperationResolver {
     fn resolve(
@@ -837,10 +1360,18 @@ fn print_document_default(document: &OperationDocumentExt) -> String {
 }
 
 fn print_document(document: &OperationDocument, options: OperationTypePrinterOptions) -> String {
+    print_document_with_plugins(document, options, EMPTY_PLUGINS)
+}
+
+fn print_document_with_plugins(
+    document: &OperationDocument,
+    options: OperationTypePrinterOptions,
+    plugins: &[impl OperationTypePrinterPlugin],
+) -> String {
     let mut result = String::new();
     let mut writer = JustWriter::new(&mut result);
     let schema = type_system();
     let schema = ast_to_type_system(&schema);
-    print_types_for_operation_document(options, &schema, document, &mut writer);
+    print_types_for_operation_document(options, &schema, document, plugins, &mut writer);
     result
 }
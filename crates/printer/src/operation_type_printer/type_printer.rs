@@ -2,7 +2,7 @@ use std::{collections::HashMap, convert::identity, iter::once};
 
 use crate::{
     ts_types::{ts_types_util::ts_union, type_to_ts_type::get_ts_type_of_type, ObjectField},
-    utils::interface_implementers,
+    utils::{interface_implementers, UNMASK_DIRECTIVE},
 };
 use graphql_type_system::{NamedType, Node, ObjectDefinition, Schema, Text, Type, TypeDefinition};
 use itertools::{Either, Itertools};
@@ -10,7 +10,7 @@ use nitrogql_ast::{
     base::Pos,
     directive::Directive,
     operation::{FragmentDefinition, OperationDocument},
-    selection_set::{Selection, SelectionSet},
+    selection_set::{FragmentSpread, Selection, SelectionSet},
     value::Value,
     variable::VariablesDefinition,
 };
@@ -25,11 +25,14 @@ use super::{
     selection_set_visitor::visit_fields_in_selection_set,
     selection_tree::{
         SelectionTree, SelectionTreeBranch, SelectionTreeEmptyLeaf, SelectionTreeField,
-        SelectionTreeLeaf, SelectionTreeObject,
+        SelectionTreeFragmentRefs, SelectionTreeLeaf, SelectionTreeObject,
     },
     visitor::OperationTypePrinterOptions,
 };
 
+/// Name of the field that holds references to masked fragments.
+pub const FRAGMENT_REFS_FIELD: &str = " $fragmentRefs";
+
 pub struct QueryTypePrinterContext<'a, 'src, S: Text<'src>> {
     pub options: &'a OperationTypePrinterOptions,
     pub schema: &'a Schema<S, Pos>,
@@ -263,6 +266,24 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
                     .fragment_definitions
                     .get(fragment.fragment_name.name)
                     .expect("Type system error");
+                if is_masked_fragment_spread(context, fragment) {
+                    let included = check_fragment_condition(
+                        context,
+                        branch.parent_obj,
+                        fragment_def.type_condition.name,
+                    ) && !check_skip_directive(branch, &fragment.directives);
+                    if !included {
+                        return vec![];
+                    }
+                    // The marker is not a field of the parent type,
+                    // so it is put in the same place as aliased fields.
+                    return vec![Either::Right(SelectionTreeField::FragmentRefs(
+                        SelectionTreeFragmentRefs {
+                            name: FRAGMENT_REFS_FIELD.into(),
+                            fragments: vec![fragment.fragment_name.name.to_owned()],
+                        },
+                    ))];
+                }
                 if check_fragment_condition(
                     context,
                     branch.parent_obj,
@@ -336,6 +357,18 @@ fn get_fields_for_selection_set<'a, 'src, S: Text<'src>>(
         .collect::<Vec<_>>()
 }
 
+/// Returns whether fields of given fragment spread are hidden behind a fragment reference.
+pub fn is_masked_fragment_spread<'src, S: Text<'src>>(
+    context: &QueryTypePrinterContext<'_, 'src, S>,
+    fragment: &FragmentSpread<'src>,
+) -> bool {
+    context.options.fragment_masking
+        && !fragment
+            .directives
+            .iter()
+            .any(|directive| directive.name.name == UNMASK_DIRECTIVE)
+}
+
 /// Examine directives and returns whether field should be skipped.
 fn check_skip_directive<'src, S: Text<'src>>(
     branch: &BranchingCondition<S>,
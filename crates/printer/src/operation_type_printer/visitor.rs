@@ -4,9 +4,12 @@ use graphql_type_system::{NamedType, Node, NonNullType, RootTypes, Schema, Text,
 use nitrogql_ast::{
     base::Pos,
     operation::{ExecutableDefinition, FragmentDefinition, OperationType},
+    selection_set::SelectionSet,
     OperationDocument,
 };
-use nitrogql_config_file::{Config, GenerateMode};
+use nitrogql_config_file::{
+    Config, DocumentMode, EnumStyle, GenerateMode, ValidationSchemaLibrary,
+};
 use nitrogql_utils::clone_into;
 use sourcemap_writer::SourceMapWriter;
 
@@ -15,17 +18,30 @@ use crate::{
         options::OperationBasePrinterOptions, OperationPrinterVisitor, PrintFragmentContext,
         PrintOperationContext,
     },
-    operation_js_printer::{print_fragment_runtime, print_operation_runtime},
+    operation_js_printer::{
+        print_fragment_runtime, print_operation_runtime, print_persisted_operation_runtime,
+    },
+    operation_type_printer::plugin::OperationTypePrinterPlugin,
     ts_types::TSType,
+    validation_schema_printer::library::LibrarySyntax,
+    SchemaTypePrinterOptions,
 };
 
 use super::{
-    selection_tree::{generate_selection_tree_type, GenerateSelectionTreeTypeContext},
+    selection_tree::{
+        generate_branch_mock_fields, generate_selection_tree_schema, generate_selection_tree_type,
+        GenerateSelectionTreeMockContext, GenerateSelectionTreeSchemaContext,
+        GenerateSelectionTreeTypeContext, SelectionTree,
+    },
     type_printer::{
         get_type_for_selection_set, get_type_for_variable_definitions, QueryTypePrinterContext,
+        FRAGMENT_REFS_FIELD,
     },
 };
 
+/// Name of the namespace that mock factories are imported as.
+const MOCKS_NAMESPACE: &str = "Mocks";
+
 #[derive(Clone, Debug)]
 pub struct OperationTypePrinterOptions {
     pub base_options: OperationBasePrinterOptions,
@@ -45,6 +61,24 @@ pub struct OperationTypePrinterOptions {
     pub fragment_type_suffix: String,
     /// Whether to allow undefined as input value.
     pub allow_undefined_as_optional_input: bool,
+    /// What is printed as the runtime value of operations.
+    pub document_mode: DocumentMode,
+    /// Whether fragment spreads are masked.
+    pub fragment_masking: bool,
+    /// Whether result types are readonly.
+    pub readonly_output: bool,
+    /// Whether runtime validation schemas of operation results are printed.
+    /// Effective only when values are printed.
+    pub emit_result_schemas: bool,
+    /// Library used by result schemas.
+    pub validation_schema_library: ValidationSchemaLibrary,
+    /// Validation schema of values of each scalar received from the server.
+    pub scalar_schemas: HashMap<String, String>,
+    /// Style of enums emitted in the schema file.
+    pub enum_style: EnumStyle,
+    /// Source of mock factories to import from.
+    /// Functions that build mock results are printed when this is set and values are printed.
+    pub mocks_source: Option<String>,
 }
 
 impl Default for OperationTypePrinterOptions {
@@ -59,6 +93,14 @@ impl Default for OperationTypePrinterOptions {
             operation_result_type_suffix: "Result".to_owned(),
             fragment_type_suffix: "".to_owned(),
             allow_undefined_as_optional_input: true,
+            document_mode: DocumentMode::Full,
+            fragment_masking: false,
+            readonly_output: false,
+            emit_result_schemas: false,
+            validation_schema_library: ValidationSchemaLibrary::Zod,
+            scalar_schemas: HashMap::new(),
+            enum_style: EnumStyle::Union,
+            mocks_source: None,
         }
     }
 }
@@ -73,6 +115,20 @@ impl OperationTypePrinterOptions {
         if config.generate.mode == GenerateMode::StandaloneTS4_0 {
             result.print_values = true;
         }
+        result.document_mode = config.generate.persisted_queries.document_mode;
+        result.fragment_masking = config.generate.r#type.fragment_masking;
+        result.readonly_output = config.generate.r#type.readonly_output;
+        result.emit_result_schemas = config.generate.emit_result_schemas;
+        result.validation_schema_library = config.generate.validation_schema_library;
+        // Results are validated with schemas of received values.
+        result.scalar_schemas = config
+            .generate
+            .r#type
+            .scalar_schemas
+            .iter()
+            .map(|(name, schema)| (name.clone(), schema.receive().to_owned()))
+            .collect();
+        result.enum_style = SchemaTypePrinterOptions::from_config(config).effective_enum_style();
         clone_into(
             &config.generate.name.operation_result_type_suffix,
             &mut result.operation_result_type_suffix,
@@ -92,6 +148,8 @@ impl OperationTypePrinterOptions {
 pub struct OperationTypePrinterVisitor<'a, 'src> {
     options: OperationTypePrinterOptions,
     context: OperationTypePrinterContext<'a, 'src, Cow<'src, str>>,
+    /// Output types of operations and fragments transformed by plugins.
+    output_types: HashMap<&'src str, TSType>,
 }
 
 impl<'a, 'src> OperationTypePrinterVisitor<'a, 'src>
@@ -118,7 +176,210 @@ where
             operation,
             fragment_definitions,
         };
-        Self { options, context }
+        Self {
+            options,
+            context,
+            output_types: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, 'src> OperationTypePrinterVisitor<'a, 'src> {
+    /// Lets plugins transform output types of named operations and fragments.
+    pub fn apply_plugins(&mut self, plugins: &[impl OperationTypePrinterPlugin]) {
+        if plugins.is_empty() {
+            return;
+        }
+        let document = self.context.operation;
+        let base = document
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                ExecutableDefinition::OperationDefinition(def) => def.name.map(|name| {
+                    let parent_type = self.operation_parent_type(def.operation_type);
+                    (
+                        name.name,
+                        self.output_type(&def.selection_set, &parent_type),
+                    )
+                }),
+                ExecutableDefinition::FragmentDefinition(def) => Some((
+                    def.name.name,
+                    self.output_type(&def.selection_set, &fragment_parent_type(def)),
+                )),
+            })
+            .collect();
+        self.output_types = plugins.iter().fold(base, |acc, plugin| {
+            plugin.transform_operation_output_types(document, acc)
+        });
+    }
+
+    /// Returns the type of the root field of given operation type.
+    fn operation_parent_type(&self, operation_type: OperationType) -> Type<Cow<'src, str>, Pos> {
+        let root_types = self.context.schema.root_types().unwrap_or_default();
+        let parent_type = select_root_type(&root_types, operation_type);
+        Type::NonNull(Box::new(NonNullType::from(Type::Named(NamedType::from(
+            parent_type.clone(),
+        )))))
+    }
+
+    /// Returns the output type of given selection set.
+    /// Types transformed by plugins take precedence when `name` is given.
+    fn output_type_of(
+        &self,
+        name: Option<&str>,
+        selection_set: &SelectionSet<'src>,
+        parent_type: &Type<Cow<'src, str>, Pos>,
+    ) -> TSType {
+        name.and_then(|name| self.output_types.get(name))
+            .cloned()
+            .unwrap_or_else(|| self.output_type(selection_set, parent_type))
+    }
+
+    fn output_type(
+        &self,
+        selection_set: &SelectionSet<'src>,
+        parent_type: &Type<Cow<'src, str>, Pos>,
+    ) -> TSType {
+        let type_printer_context = QueryTypePrinterContext {
+            options: &self.options,
+            schema: self.context.schema,
+            operation: self.context.operation,
+            fragment_definitions: &self.context.fragment_definitions,
+        };
+        let selection_tree =
+            get_type_for_selection_set(&type_printer_context, selection_set, parent_type);
+        generate_selection_tree_type(
+            &GenerateSelectionTreeTypeContext {
+                schema_root_namespace: &self.options.schema_root_namespace,
+                fragment_type_suffix: &self.options.fragment_type_suffix,
+                readonly_output: self.options.readonly_output,
+            },
+            &selection_tree,
+        )
+    }
+
+    fn emits_result_schemas(&self) -> bool {
+        self.options.print_values && self.options.emit_result_schemas
+    }
+
+    /// Prints a runtime validation schema of the result of given operation.
+    fn print_result_schema(
+        &self,
+        context: &PrintOperationContext,
+        parent_type: &Type<Cow<'src, str>, Pos>,
+        result_type_name: &str,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        let operation = &context.operation;
+        let library = self.options.validation_schema_library;
+        let selection_tree = self.unmasked_selection_tree(&operation.selection_set, parent_type);
+        let schema = generate_selection_tree_schema(
+            &GenerateSelectionTreeSchemaContext {
+                schema: self.context.schema,
+                schema_root_namespace: &self.options.schema_root_namespace,
+                library,
+                scalar_schemas: &self.options.scalar_schemas,
+                enum_style: self.options.enum_style,
+            },
+            &selection_tree,
+        );
+
+        // Schemas are always exported because they are only useful from other modules.
+        writer.write("export const ");
+        writer.write_for(&format!("{result_type_name}Schema"), &operation.name_pos());
+        writer.write(": ");
+        writer.write(&library.schema_type(result_type_name));
+        writer.write(" = ");
+        writer.write(&schema);
+        if self.options.fragment_masking {
+            // Output of the schema does not have fragment references.
+            writer.write(" as unknown as ");
+            writer.write(&library.schema_type(result_type_name));
+        }
+        writer.write(";\n\n");
+    }
+
+    fn emits_result_builders(&self) -> bool {
+        self.options.print_values && self.options.mocks_source.is_some()
+    }
+
+    /// Prints a function that builds a mock result of given operation.
+    /// Builders are meant to be imported from tests, so they are exported
+    /// regardless of export options.
+    fn print_result_builder(
+        &self,
+        context: &PrintOperationContext,
+        parent_type: &Type<Cow<'src, str>, Pos>,
+        result_type_name: &str,
+        writer: &mut impl SourceMapWriter,
+    ) {
+        let operation = &context.operation;
+        let selection_tree = self.unmasked_selection_tree(&operation.selection_set, parent_type);
+        let SelectionTree::NonNull(ref root) = selection_tree else {
+            return;
+        };
+        let SelectionTree::Object(ref branches) = **root else {
+            return;
+        };
+        let Some(branch) = branches.first() else {
+            return;
+        };
+        let fields = generate_branch_mock_fields(
+            &GenerateSelectionTreeMockContext {
+                mocks_namespace: MOCKS_NAMESPACE,
+            },
+            branch,
+        );
+
+        writer.write("export function ");
+        writer.write_for(
+            &format!(
+                "build{}Result",
+                context.operation_names.operation_variable_name
+            ),
+            &operation.name_pos(),
+        );
+        writeln!(
+            writer,
+            "(overrides: Partial<{result_type_name}> = {{}}): {result_type_name} {{"
+        );
+        writer.indent();
+        writer.write("return {\n");
+        writer.indent();
+        for (name, value) in fields {
+            writeln!(writer, "{name}: {value},");
+        }
+        writer.write("...overrides,\n");
+        writer.dedent();
+        if self.options.fragment_masking {
+            // Mock results are built from the unmasked selection tree.
+            writeln!(writer, "}} as unknown as {result_type_name};");
+        } else {
+            writer.write("};\n");
+        }
+        writer.dedent();
+        writer.write("}\n\n");
+    }
+
+    /// Generates a selection tree in which fragments are not masked.
+    /// Actual responses contain all fields of masked fragments,
+    /// so runtime values are generated from this tree.
+    fn unmasked_selection_tree(
+        &self,
+        selection_set: &SelectionSet<'src>,
+        parent_type: &Type<Cow<'src, str>, Pos>,
+    ) -> SelectionTree<Cow<'src, str>> {
+        let unmasked_options = OperationTypePrinterOptions {
+            fragment_masking: false,
+            ..self.options.clone()
+        };
+        let type_printer_context = QueryTypePrinterContext {
+            options: &unmasked_options,
+            schema: self.context.schema,
+            operation: self.context.operation,
+            fragment_definitions: &self.context.fragment_definitions,
+        };
+        get_type_for_selection_set(&type_printer_context, selection_set, parent_type)
     }
 }
 
@@ -135,13 +396,76 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
             "import type {{ TypedDocumentNode }} from \"{}\";",
             self.options.typed_document_node_source
         );
-        write!(
+        let import_type = if self.emits_result_schemas() {
+            writeln!(
+                writer,
+                "{}",
+                self.options.validation_schema_library.import()
+            );
+            // TypeScript enums are referred to as values.
+            if self.options.enum_style == EnumStyle::TsEnum {
+                ""
+            } else {
+                "type "
+            }
+        } else {
+            "type "
+        };
+        writeln!(
             writer,
-            "import type * as {} from \"{}\";\n\n",
+            "import {import_type}* as {} from \"{}\";",
             self.options.schema_root_namespace, self.options.schema_source,
         );
+        if let Some(mocks_source) = self
+            .options
+            .mocks_source
+            .as_ref()
+            .filter(|_| self.emits_result_builders())
+        {
+            writeln!(
+                writer,
+                "import * as {MOCKS_NAMESPACE} from \"{mocks_source}\";"
+            );
+        }
+        writer.write("\n");
+    }
+    fn print_trailer(&self, writer: &mut impl SourceMapWriter) {
+        if !self.options.fragment_masking {
+            return;
+        }
+        let document = self.context.operation;
+        let local_fragments = document
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                ExecutableDefinition::FragmentDefinition(def)
+                    if def.position.file == document.position.file =>
+                {
+                    Some(def)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if local_fragments.is_empty() {
+            return;
+        }
+        // Helper that unmasks a fragment reference into the data of the fragment.
+        writer.write("export type Unmask<Ref> =\n");
+        writer.indent();
+        writer.write("Ref extends readonly (infer Item)[] ? Unmask<Item>[] :\n");
+        for fragment in local_fragments {
+            let fragment_type_name = format!(
+                "{}{}",
+                fragment.name.name, self.options.fragment_type_suffix
+            );
+            writeln!(
+                writer,
+                "Ref extends {fragment_type_name}Ref ? {fragment_type_name} :"
+            );
+        }
+        writer.write("Ref;\n");
+        writer.dedent();
     }
-    fn print_trailer(&self, _writer: &mut impl SourceMapWriter) {}
     fn print_operation_definition(
         &self,
         context: PrintOperationContext,
@@ -159,32 +483,21 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
         writer.write_for(&result_type_name, &operation.name_pos());
         writer.write_for(" = ", &operation.selection_set);
 
-        let root_types = self.context.schema.root_types().unwrap_or_default();
-        let parent_type = select_root_type(&root_types, operation.operation_type);
-        let parent_type = Type::NonNull(Box::new(NonNullType::from(Type::Named(NamedType::from(
-            parent_type.clone(),
-        )))));
-        let type_printer_context = QueryTypePrinterContext {
-            options: &self.options,
-            schema: self.context.schema,
-            operation: self.context.operation,
-            fragment_definitions: &self.context.fragment_definitions,
-        };
-
-        let operation_type = get_type_for_selection_set(
-            &type_printer_context,
+        let parent_type = self.operation_parent_type(operation.operation_type);
+        let operation_type = self.output_type_of(
+            operation.name.map(|name| name.name),
             &operation.selection_set,
             &parent_type,
         );
-        let operation_type = generate_selection_tree_type(
-            &GenerateSelectionTreeTypeContext {
-                schema_root_namespace: &self.options.schema_root_namespace,
-            },
-            &operation_type,
-        );
         operation_type.print_type(writer);
         writer.write(";\n\n");
 
+        let type_printer_context = QueryTypePrinterContext {
+            options: &self.options,
+            schema: self.context.schema,
+            operation: self.context.operation,
+            fragment_definitions: &self.context.fragment_definitions,
+        };
         let input_variable_type = operation
             .variables_definition
             .as_ref()
@@ -225,7 +538,18 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
             return;
         }
         writer.write("> = ");
-        print_operation_runtime(writer, operation, context.fragments);
+        match self.options.document_mode {
+            DocumentMode::Full => {
+                print_operation_runtime(writer, context.runtime_operation, context.fragments);
+            }
+            DocumentMode::PersistedOnly => {
+                print_persisted_operation_runtime(
+                    writer,
+                    context.runtime_operation,
+                    context.fragments,
+                );
+            }
+        }
         // Use the `as unknown as` technique to avoid the type system complaining about
         // the type of the JSON object not matching the type of the TypedDocumentNode
         // (because of the use of enums in the TypedDocumentNode type)
@@ -234,6 +558,13 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
         writer.write(", ");
         writer.write(&input_variable_name);
         writer.write(">;\n\n");
+
+        if self.emits_result_schemas() {
+            self.print_result_schema(&context, &parent_type, &result_type_name, writer);
+        }
+        if self.emits_result_builders() {
+            self.print_result_builder(&context, &parent_type, &result_type_name, writer);
+        }
     }
 
     fn print_fragment_definition(
@@ -257,34 +588,23 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
 
         writer.write(" = ");
 
-        let parent_type = Type::NonNull(Box::new(NonNullType::from(Type::Named(NamedType::from(
-            Node::from(
-                fragment.type_condition.name,
-                fragment.type_condition.position,
-            ),
-        )))));
-
-        let type_printer_context = QueryTypePrinterContext {
-            options: &self.options,
-            schema: self.context.schema,
-            operation: self.context.operation,
-            fragment_definitions: &self.context.fragment_definitions,
-        };
-
-        let fragment_type = get_type_for_selection_set(
-            &type_printer_context,
+        let fragment_type = self.output_type_of(
+            Some(fragment.name.name),
             &fragment.selection_set,
-            &parent_type,
-        );
-        let fragment_type = generate_selection_tree_type(
-            &GenerateSelectionTreeTypeContext {
-                schema_root_namespace: &self.options.schema_root_namespace,
-            },
-            &fragment_type,
+            &fragment_parent_type(fragment),
         );
         fragment_type.print_type(writer);
         writer.write(";\n\n");
 
+        if self.options.fragment_masking && context.exported {
+            // type of references to the fragment
+            writeln!(
+                writer,
+                "export type {fragment_type_name}Ref = {{ \"{FRAGMENT_REFS_FIELD}\": {{ {}: {fragment_type_name} }} }};\n",
+                fragment.name.name
+            );
+        }
+
         // runtime value
         if context.exported {
             writer.write("export ");
@@ -307,7 +627,7 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
             return;
         }
         writer.write(" = ");
-        print_fragment_runtime(writer, fragment, context.fragments);
+        print_fragment_runtime(writer, context.runtime_fragment, context.fragments);
         writer.write(" as unknown as TypedDocumentNode<");
         writer.write_for(&fragment_type_name, fragment);
         writer.write(", never>;\n\n");
@@ -323,6 +643,15 @@ impl<'a, 'src> OperationPrinterVisitor for OperationTypePrinterVisitor<'a, 'src>
     }
 }
 
+fn fragment_parent_type<'src>(fragment: &FragmentDefinition<'src>) -> Type<Cow<'src, str>, Pos> {
+    Type::NonNull(Box::new(NonNullType::from(Type::Named(NamedType::from(
+        Node::from(
+            fragment.type_condition.name,
+            fragment.type_condition.position,
+        ),
+    )))))
+}
+
 fn select_root_type<T>(root_types: &RootTypes<T>, operation_type: OperationType) -> &T {
     match operation_type {
         OperationType::Query => &root_types.query_type,
@@ -0,0 +1,110 @@
+use std::collections::{BTreeMap, HashMap};
+
+use json_writer::JSONObjectWriter;
+use nitrogql_ast::{
+    operation::{ExecutableDefinition, FragmentDefinition, OperationDefinition},
+    OperationDocument,
+};
+use sha2::{Digest, Sha256};
+use sourcemap_writer::{JustWriter, SourceMapWriter};
+
+use crate::{
+    json_printer::ExecutableDefinitionRef, operation_js_printer::operation_document_definitions,
+    GraphQLPrinter,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// An operation registered as a persisted query.
+#[derive(Debug)]
+pub struct PersistedOperation {
+    /// SHA-256 hash of the document in hex.
+    pub hash: String,
+    /// Printed document that contains the operation and all fragments used by it.
+    pub document: String,
+}
+
+/// Generates persisted operations for all operations in given document.
+/// Imports in the document should be already resolved so that
+/// fragments from other documents are included.
+pub fn persisted_operations(document: &OperationDocument) -> Vec<PersistedOperation> {
+    let fragments: HashMap<&str, &FragmentDefinition> = document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            ExecutableDefinition::FragmentDefinition(def) => Some((def.name.name, def)),
+            _ => None,
+        })
+        .collect();
+    document
+        .definitions
+        .iter()
+        .filter_map(|def| match def {
+            ExecutableDefinition::OperationDefinition(op) => {
+                Some(persisted_operation(op, &fragments))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Generates a persisted operation for given operation.
+pub fn persisted_operation(
+    operation: &OperationDefinition,
+    fragments: &HashMap<&str, &FragmentDefinition>,
+) -> PersistedOperation {
+    let document = print_operation_document(operation, fragments);
+    PersistedOperation {
+        hash: document_hash(&document),
+        document,
+    }
+}
+
+/// Prints the document of given operation in the canonical form.
+/// Result only depends on the AST, so formatting of the source does not affect it.
+fn print_operation_document(
+    operation: &OperationDefinition,
+    fragments: &HashMap<&str, &FragmentDefinition>,
+) -> String {
+    let mut buffer = String::new();
+    let mut writer = JustWriter::new(&mut buffer);
+    for (idx, def) in operation_document_definitions(operation, fragments)
+        .into_iter()
+        .enumerate()
+    {
+        if idx > 0 {
+            writer.write("\n");
+        }
+        match def {
+            ExecutableDefinitionRef::OperationDefinition(op) => op.print_graphql(&mut writer),
+            ExecutableDefinitionRef::FragmentDefinition(fragment) => {
+                fragment.print_graphql(&mut writer)
+            }
+        }
+    }
+    buffer
+}
+
+/// Calculates the hash of given document.
+pub fn document_hash(document: &str) -> String {
+    format!("{:x}", Sha256::digest(document.as_bytes()))
+}
+
+/// Prints a JSON manifest that maps hashes to documents.
+/// Entries are sorted by hash so that the output is stable.
+pub fn print_persisted_query_manifest<'a>(
+    operations: impl IntoIterator<Item = &'a PersistedOperation>,
+) -> String {
+    let operations: BTreeMap<&str, &str> = operations
+        .into_iter()
+        .map(|op| (op.hash.as_str(), op.document.as_str()))
+        .collect();
+    let mut buffer = String::new();
+    let mut writer = JSONObjectWriter::new(&mut buffer);
+    for (hash, document) in operations {
+        writer.value(hash, document);
+    }
+    writer.end();
+    buffer
+}
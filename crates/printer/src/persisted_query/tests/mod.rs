@@ -0,0 +1,134 @@
+use std::path::Path;
+
+use insta::{assert_debug_snapshot, assert_snapshot};
+use nitrogql_ast::{set_current_file_of_pos, OperationDocument};
+use nitrogql_parser::parse_operation_document;
+use nitrogql_semantics::{
+    resolve_operation_extensions, resolve_operation_imports, OperationExtension, OperationResolver,
+};
+
+use super::{persisted_operations, print_persisted_query_manifest};
+
+#[test]
+fn operations_with_fragments() {
+    let doc = parse(
+        r#"
+        query GetMe($id: ID = "me") @cached {
+            me { ...UserFields }
+        }
+        mutation Rename($name: String!) {
+            rename(name: $name) { id }
+        }
+        fragment UserFields on User {
+            id
+            ... on User { name }
+            posts(first: 10) { ...PostFields }
+        }
+        fragment PostFields on Post {
+            title
+        }
+        fragment Unused on User {
+            id
+        }
+        "#,
+    );
+    assert_debug_snapshot!(persisted_operations(&doc));
+}
+
+#[test]
+fn hash_is_stable_across_formatting() {
+    let doc1 = parse(
+        "
+        query GetMe { me { id name } }
+        ",
+    );
+    let doc2 = parse(
+        "
+        # comment
+        query   GetMe {
+            me {
+                id,
+                name
+            }
+        }
+        ",
+    );
+    let ops1 = persisted_operations(&doc1);
+    let ops2 = persisted_operations(&doc2);
+    assert_eq!(ops1[0].hash, ops2[0].hash);
+    assert_eq!(ops1[0].document, ops2[0].document);
+}
+
+#[test]
+fn variable_defaults_and_directives_are_kept() {
+    // A server running a persisted document must see the same defaults as the client.
+    let doc = parse(
+        "
+        query Posts($first: Int = 10 @deprecated, $after: String) {
+            posts(first: $first, after: $after) { id }
+        }
+        ",
+    );
+    let ops = persisted_operations(&doc);
+    assert!(ops[0].document.contains("$first: Int = 10 @deprecated,\n"));
+}
+
+#[test]
+fn imported_fragments_are_included() {
+    set_current_file_of_pos(0);
+    let doc = parse_operation_document(
+        r#"
+        #import UserFields from "./fragments.graphql"
+        query GetMe { me { ...UserFields } }
+        "#,
+    )
+    .unwrap();
+    let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+    let doc = resolve_operation_imports(
+        (Path::new("/path/to/main.graphql"), &doc, &extensions),
+        &TestOperationResolver,
+    )
+    .unwrap();
+    let ops = persisted_operations(&doc);
+    assert_snapshot!(ops[0].document);
+}
+
+#[test]
+fn manifest() {
+    let doc = parse(
+        "
+        query A { me { id } }
+        query B { me { name } }
+        ",
+    );
+    let ops = persisted_operations(&doc);
+    assert_snapshot!(print_persisted_query_manifest(&ops));
+}
+
+fn parse(source: &str) -> OperationDocument<'_> {
+    let doc = parse_operation_document(source).unwrap();
+    let (doc, _) = resolve_operation_extensions(doc).unwrap();
+    doc
+}
+
+struct TestOperationResolver;
+
+impl<'src> OperationResolver<'src> for TestOperationResolver {
+    fn resolve(
+        &self,
+        path: &Path,
+    ) -> Option<(&OperationDocument<'src>, &OperationExtension<'src>)> {
+        if path != Path::new("/path/to/fragments.graphql") {
+            return None;
+        }
+        set_current_file_of_pos(1);
+        let doc = parse_operation_document(
+            "
+            fragment UserFields on User { id name }
+            ",
+        )
+        .unwrap();
+        let (doc, extensions) = resolve_operation_extensions(doc).unwrap();
+        Some((Box::leak(Box::new(doc)), Box::leak(Box::new(extensions))))
+    }
+}
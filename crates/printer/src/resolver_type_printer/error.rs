@@ -6,6 +6,12 @@ use nitrogql_ast::base::Pos;
 pub enum ResolverTypePrinterError {
     #[error("Type for scalar '{name}' is not provided")]
     ScalarTypeNotProvided { position: Pos, name: String },
+    #[error("Type '{name}' in resolver mappers does not exist in the schema")]
+    MapperTypeNotFound { name: String },
+    #[error("Field '{name}' in subscription payloads does not exist in the subscription type")]
+    SubscriptionFieldNotFound { name: String },
+    #[error("{0}")]
+    PluginFailed(String),
 }
 
 pub type ResolverTypePrinterResult<T> = Result<T, ResolverTypePrinterError>;
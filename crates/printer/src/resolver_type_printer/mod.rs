@@ -3,4 +3,5 @@ pub mod options;
 pub mod plugin;
 pub mod printer;
 mod tests;
+mod type_reference;
 mod visitor;
@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use nitrogql_config_file::Config;
+use nitrogql_utils::clone_into;
 
 #[derive(Debug)]
 pub struct ResolverTypePrinterOptions {
@@ -10,6 +13,17 @@ pub struct ResolverTypePrinterOptions {
     pub schema_source: String,
     /// Name of the root TypeScript namespace that contains schema types.
     pub schema_root_namespace: String,
+    /// Default type of context.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub context_type: Option<String>,
+    /// Parent type of resolvers for each GraphQL type.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub mappers: HashMap<String, String>,
+    /// Whether field resolvers are optional when the parent type has the same property.
+    pub optional_field_resolvers: bool,
+    /// Payload type of each subscription field.
+    /// Either `module#ExportName` or raw TypeScript type.
+    pub subscription_payloads: HashMap<String, String>,
 }
 
 impl Default for ResolverTypePrinterOptions {
@@ -19,12 +33,30 @@ impl Default for ResolverTypePrinterOptions {
             resolver_output_type: "ResolverOutput".into(),
             schema_source: "".into(),
             schema_root_namespace: "Schema".into(),
+            context_type: None,
+            mappers: HashMap::new(),
+            optional_field_resolvers: false,
+            subscription_payloads: HashMap::new(),
         }
     }
 }
 
 impl ResolverTypePrinterOptions {
-    pub fn from_config(_config: &Config) -> Self {
-        ResolverTypePrinterOptions::default()
+    pub fn from_config(config: &Config) -> Self {
+        let mut result = ResolverTypePrinterOptions::default();
+        let resolvers_config = &config.generate.resolvers;
+        clone_into(
+            &resolvers_config.resolvers_type_name,
+            &mut result.root_resolver_type,
+        );
+        clone_into(
+            &resolvers_config.resolver_output_type_name,
+            &mut result.resolver_output_type,
+        );
+        result.context_type = resolvers_config.context_type.clone();
+        result.mappers = resolvers_config.mappers.clone();
+        result.optional_field_resolvers = resolvers_config.optional_field_resolvers;
+        result.subscription_payloads = resolvers_config.subscription_payloads.clone();
+        result
     }
 }
@@ -1,22 +1,27 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, future::Future, pin::Pin};
 
 use nitrogql_ast::TypeSystemDocument;
 
 use crate::{ts_types::TSType, ResolverTypePrinterOptions};
 
 /// A plugin that can transform resolver output types.
+/// Errors are messages that describe failures of the plugin.
 pub trait ResolverTypePrinterPlugin {
     /// Transform resolver output types.
-    fn transform_resolver_output_types<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-        options: &ResolverTypePrinterOptions,
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+        options: &'a ResolverTypePrinterOptions,
         base: HashMap<&'src str, TSType>,
-    ) -> HashMap<&'src str, TSType>;
+    ) -> ResolverTypePrinterPluginFuture<'a, HashMap<&'src str, TSType>>;
     /// Transform document so that it represents which fields
     /// have resolvers.
-    fn transform_document_for_resolvers<'src>(
-        &self,
-        document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>>;
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> ResolverTypePrinterPluginFuture<'a, Option<TypeSystemDocument<'src>>>;
 }
+
+/// Result of a plugin hook that may not complete synchronously.
+pub type ResolverTypePrinterPluginFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T, String>> + 'a>>;
@@ -1,22 +1,32 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{
+    borrow::Cow,
+    collections::{BTreeSet, HashMap},
+};
 
 use graphql_type_system::Schema;
+use itertools::Itertools;
 use nitrogql_ast::{
     base::Pos,
-    type_system::{TypeDefinition, TypeSystemDefinition},
+    type_system::{ObjectTypeDefinition, TypeDefinition, TypeSystemDefinition},
     TypeSystemDocument,
 };
 use nitrogql_semantics::ast_to_type_system;
-use sourcemap_writer::SourceMapWriter;
+use sourcemap_writer::{JustWriter, SourceMapWriter};
 
 use crate::{
-    resolver_type_printer::visitor::{get_resolver_type, get_ts_type_for_resolver_output},
+    resolver_type_printer::visitor::{
+        directive_target_type_name, get_directive_resolver_type, get_resolver_type,
+        get_scalar_resolver_type, get_ts_type_for_resolver_output,
+    },
+    schema::get_builtin_scalar_types,
     ts_types::{ts_types_util::ts_union, ObjectField, ObjectKey, TSType},
 };
 
 use super::{
-    error::ResolverTypePrinterResult, options::ResolverTypePrinterOptions,
+    error::{ResolverTypePrinterError, ResolverTypePrinterResult},
+    options::ResolverTypePrinterOptions,
     plugin::ResolverTypePrinterPlugin,
+    type_reference::TypeReference,
 };
 
 pub struct ResolverTypePrinter<'a, Writer> {
@@ -28,6 +38,8 @@ pub struct ResolverTypePrinterContext<'src> {
     pub options: &'src ResolverTypePrinterOptions,
     pub document: &'src TypeSystemDocument<'src>,
     pub schema: &'src Schema<Cow<'src, str>, Pos>,
+    /// Payload type of each subscription field.
+    pub subscription_payloads: HashMap<&'src str, TSType>,
 }
 
 impl<'a, Writer> ResolverTypePrinter<'a, Writer>
@@ -38,27 +50,120 @@ where
         Self { options, writer }
     }
 
-    pub fn print_document(
+    pub async fn print_document(
         &mut self,
-        document: &TypeSystemDocument,
+        document: &TypeSystemDocument<'_>,
         plugins: &[impl ResolverTypePrinterPlugin],
     ) -> ResolverTypePrinterResult<()> {
         let schema = ast_to_type_system(document);
-        let context = ResolverTypePrinterContext {
-            options: &self.options,
-            document,
-            schema: &schema,
-        };
+        let mut document_for_resolvers = Cow::Borrowed(document);
+        for plugin in plugins {
+            if let Some(next) = plugin
+                .transform_document_for_resolvers(document_for_resolvers.as_ref())
+                .await
+                .map_err(ResolverTypePrinterError::PluginFailed)?
+            {
+                document_for_resolvers = Cow::Owned(next);
+            }
+        }
+        let options = &self.options;
+
+        let directive_definitions = document_for_resolvers
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                // Built-in directives are not implemented by servers.
+                // Directives only for executable documents do not affect schemas.
+                TypeSystemDefinition::DirectiveDefinition(def)
+                    if !def.position.builtin
+                        && def.locations.iter().any(|location| {
+                            directive_target_type_name(location.name).is_some()
+                        }) =>
+                {
+                    Some(def)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let builtin_scalar_types = get_builtin_scalar_types();
+        let custom_scalars = document
+            .definitions
+            .iter()
+            .filter_map(|def| match def {
+                TypeSystemDefinition::TypeDefinition(TypeDefinition::Scalar(def))
+                    if !builtin_scalar_types.contains_key(def.name.name) =>
+                {
+                    Some(def)
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let graphql_imports = directive_definitions
+            .iter()
+            .flat_map(|def| def.locations.iter())
+            .filter_map(|location| directive_target_type_name(location.name))
+            .chain(["GraphQLResolveInfo"])
+            .chain((!directive_definitions.is_empty()).then_some("GraphQLSchema"))
+            .chain((!custom_scalars.is_empty()).then_some("GraphQLScalarTypeConfig"))
+            .collect::<BTreeSet<_>>();
 
         writeln!(
             self.writer,
-            "import type {{ GraphQLResolveInfo }} from \"graphql\";"
+            "import type {{ {} }} from \"graphql\";",
+            graphql_imports.into_iter().join(", ")
         );
         writeln!(
             self.writer,
             "import type * as {} from \"{}\";",
-            context.options.schema_root_namespace, context.options.schema_source,
+            options.schema_root_namespace, options.schema_source,
         );
+        let context_type = options
+            .context_type
+            .as_ref()
+            .map(|context_type| print_type_reference(self.writer, context_type, "__Context"));
+        let mut mappers = options.mappers.iter().collect::<Vec<_>>();
+        mappers.sort_unstable_by_key(|(type_name, _)| *type_name);
+        let mappers = mappers
+            .into_iter()
+            .map(|(type_name, mapper)| {
+                if !has_output_type(document, type_name) {
+                    return Err(ResolverTypePrinterError::MapperTypeNotFound {
+                        name: type_name.clone(),
+                    });
+                }
+                let alias = format!("__{type_name}Mapper");
+                Ok((type_name, print_type_reference(self.writer, mapper, &alias)))
+            })
+            .collect::<ResolverTypePrinterResult<Vec<_>>>()?;
+        let subscription_type = subscription_type(document, &schema);
+        let mut subscription_payloads = options.subscription_payloads.iter().collect::<Vec<_>>();
+        subscription_payloads.sort_unstable_by_key(|(field_name, _)| *field_name);
+        let subscription_payloads = subscription_payloads
+            .into_iter()
+            .map(|(field_name, payload)| {
+                let has_field = subscription_type.is_some_and(|def| {
+                    def.fields.iter().any(|field| field.name.name == field_name)
+                });
+                if !has_field {
+                    return Err(ResolverTypePrinterError::SubscriptionFieldNotFound {
+                        name: field_name.clone(),
+                    });
+                }
+                let alias = format!("__{field_name}Payload");
+                Ok((
+                    field_name.as_str(),
+                    print_type_reference(self.writer, payload, &alias),
+                ))
+            })
+            .collect::<ResolverTypePrinterResult<HashMap<_, _>>>()?;
+
+        let context = ResolverTypePrinterContext {
+            options,
+            document,
+            schema: &schema,
+            subscription_payloads,
+        };
+
         writeln!(
             self.writer,
             "type __Resolver<Parent, Args, Context, Result> = (parent: Parent, args: Args, context: Context, info: GraphQLResolveInfo) => Result | Promise<Result>;"
@@ -67,8 +172,30 @@ where
             self.writer,
             "type __TypeResolver<Obj, Context, Result> = (object: Obj, context: Context, info: GraphQLResolveInfo) => Result | Promise<Result>;"
         );
+        if subscription_type.is_some() {
+            writeln!(
+                self.writer,
+                "type __Subscriber<Parent, Args, Context, Payload> = (parent: Parent, args: Args, context: Context, info: GraphQLResolveInfo) => AsyncIterable<Payload> | Promise<AsyncIterable<Payload>>;"
+            );
+        }
+        if !directive_definitions.is_empty() {
+            writeln!(
+                self.writer,
+                "type __DirectiveTransformer<Target, Args> = (target: Target, args: Args, schema: GraphQLSchema) => Target | null | undefined;"
+            );
+        }
+        if context.options.optional_field_resolvers {
+            writeln!(
+                self.writer,
+                "type __OptionalIfParentHas<Parent, Resolvers> = {{ [K in keyof Resolvers as K extends keyof Parent ? K : never]?: Resolvers[K] }} & {{ [K in keyof Resolvers as K extends keyof Parent ? never : K]: Resolvers[K] }};"
+            );
+            writeln!(
+                self.writer,
+                "type __OptionalIfEmpty<T> = {{ [K in keyof T as {{}} extends T[K] ? K : never]?: T[K] }} & {{ [K in keyof T as {{}} extends T[K] ? never : K]: T[K] }};"
+            );
+        }
 
-        let ts_types: HashMap<&str, TSType> = document
+        let mut ts_types: HashMap<&str, TSType> = document
             .definitions
             .iter()
             .filter_map(|type_definition| match type_definition {
@@ -79,16 +206,18 @@ where
                 _ => None,
             })
             .collect();
-        let ts_types = plugins.iter().fold(ts_types, |acc, plugin| {
-            plugin.transform_resolver_output_types(document, &self.options, acc)
-        });
-
-        let document_for_resolvers = plugins.iter().fold(Cow::Borrowed(document), |acc, plugin| {
-            match plugin.transform_document_for_resolvers(acc.as_ref()) {
-                Some(next) => Cow::Owned(next),
-                None => acc,
+        for plugin in plugins {
+            ts_types = plugin
+                .transform_resolver_output_types(document, options, ts_types)
+                .await
+                .map_err(ResolverTypePrinterError::PluginFailed)?;
+        }
+        // Mappers take precedence over plugins.
+        for (type_name, mapper) in mappers {
+            if let Some(ts_type) = ts_types.get_mut(type_name.as_str()) {
+                *ts_type = mapper;
             }
-        });
+        }
 
         // Emit each schema type (resolver output variant) as a local type alias.
         // This helps users to read generated types.
@@ -98,7 +227,15 @@ where
                     // input types can never be resolver outputs.
                     continue;
                 }
-                let ts_type = ts_types.get(def.name().name).unwrap();
+                // Types added by plugins are not known to resolver output types.
+                let added_type;
+                let ts_type = match ts_types.get(def.name().name) {
+                    Some(ts_type) => ts_type,
+                    None => {
+                        added_type = get_ts_type_for_resolver_output(def, &context);
+                        &added_type
+                    }
+                };
 
                 self.writer.write("type ");
                 self.writer.write_for(def.name().name, def.name());
@@ -160,9 +297,29 @@ where
                 .collect(),
         );
 
+        let root_resolvers_type = if context.options.optional_field_resolvers {
+            // Types whose resolvers are all optional can be omitted.
+            TSType::TypeFunc(
+                Box::new(TSType::TypeVariable("__OptionalIfEmpty".into())),
+                vec![root_resolvers_type],
+            )
+        } else {
+            root_resolvers_type
+        };
+
+        let context_parameter = match context_type {
+            Some(ref context_type) => {
+                let mut parameter = String::from("Context = ");
+                let mut writer = JustWriter::new(&mut parameter);
+                context_type.print_type(&mut writer);
+                parameter
+            }
+            None => "Context".to_owned(),
+        };
+
         write!(
             self.writer,
-            "export type {}<Context> = ",
+            "export type {}<{context_parameter}> = ",
             &context.options.root_resolver_type
         );
         root_resolvers_type.print_type(self.writer);
@@ -178,10 +335,93 @@ where
         resolver_output_type.print_type(self.writer);
         writeln!(self.writer, "[T];");
 
+        if !directive_definitions.is_empty() {
+            let directive_resolvers_type = TSType::Object(
+                directive_definitions
+                    .iter()
+                    .map(|def| ObjectField {
+                        key: ObjectKey::from(&def.name),
+                        r#type: get_directive_resolver_type(def, &context),
+                        description: None,
+                        readonly: false,
+                        optional: false,
+                    })
+                    .collect(),
+            );
+            write!(
+                self.writer,
+                "export type DirectiveResolvers<{context_parameter}> = "
+            );
+            directive_resolvers_type.print_type(self.writer);
+            writeln!(self.writer, ";");
+        }
+
+        if !custom_scalars.is_empty() {
+            let scalar_resolvers_type = TSType::Object(
+                custom_scalars
+                    .iter()
+                    .map(|def| ObjectField {
+                        key: ObjectKey::from(&def.name),
+                        r#type: get_scalar_resolver_type(def, &context),
+                        description: None,
+                        readonly: false,
+                        optional: false,
+                    })
+                    .collect(),
+            );
+            write!(self.writer, "export type ScalarResolvers = ");
+            scalar_resolvers_type.print_type(self.writer);
+            writeln!(self.writer, ";");
+        }
+
         Ok(())
     }
 }
 
+/// Prints an import for given type reference if needed,
+/// and returns the type to refer to it.
+fn print_type_reference(writer: &mut impl SourceMapWriter, reference: &str, alias: &str) -> TSType {
+    match TypeReference::parse(reference) {
+        TypeReference::Import {
+            module,
+            export_name,
+        } => {
+            writeln!(
+                writer,
+                "import type {{ {export_name} as {alias} }} from \"{module}\";"
+            );
+            TSType::TypeVariable(alias.into())
+        }
+        TypeReference::Raw(ty) => TSType::Raw(ty.to_owned()),
+    }
+}
+
+/// Finds the definition of the subscription root type.
+fn subscription_type<'a, 'src>(
+    document: &'a TypeSystemDocument<'src>,
+    schema: &Schema<Cow<'src, str>, Pos>,
+) -> Option<&'a ObjectTypeDefinition<'src>> {
+    let root_types = schema.root_types().unwrap_or_default();
+    document.definitions.iter().find_map(|def| match def {
+        TypeSystemDefinition::TypeDefinition(TypeDefinition::Object(def))
+            if root_types.subscription_type.as_ref() == def.name.name =>
+        {
+            Some(def)
+        }
+        _ => None,
+    })
+}
+
+/// Checks whether given document has an output type of given name.
+fn has_output_type(document: &TypeSystemDocument, type_name: &str) -> bool {
+    document.definitions.iter().any(|def| match def {
+        TypeSystemDefinition::TypeDefinition(def) => {
+            !matches!(def, TypeDefinition::InputObject(_)) && def.name().name == type_name
+        }
+        _ => false,
+    })
+}
+
 fn is_empty_object(ty: &TSType) -> bool {
     if let TSType::Object(fields) = ty {
         fields.is_empty()
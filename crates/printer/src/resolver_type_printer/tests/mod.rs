@@ -1,40 +1,308 @@
 #![cfg(test)]
 
+use std::collections::HashMap;
+
+use futures::executor::block_on;
+use graphql_builtins::generate_builtins;
 use insta::assert_snapshot;
 use nitrogql_ast::TypeSystemDocument;
 use nitrogql_parser::parse_type_system_document;
 use nitrogql_semantics::resolve_schema_extensions;
 use sourcemap_writer::JustWriter;
 
-use crate::ResolverTypePrinterPlugin;
+use crate::{ts_types::TSType, ResolverTypePrinterPlugin, ResolverTypePrinterPluginFuture};
 
 use super::{
-    error::ResolverTypePrinterResult, options::ResolverTypePrinterOptions,
+    error::{ResolverTypePrinterError, ResolverTypePrinterResult},
+    options::ResolverTypePrinterOptions,
     printer::ResolverTypePrinter,
 };
 
 struct DummyPlugin;
 impl ResolverTypePrinterPlugin for DummyPlugin {
-    fn transform_resolver_output_types<'src>(
-        &self,
-        _document: &TypeSystemDocument<'src>,
-        _options: &ResolverTypePrinterOptions,
-        _base: std::collections::HashMap<&'src str, crate::ts_types::TSType>,
-    ) -> std::collections::HashMap<&'src str, crate::ts_types::TSType> {
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        _document: &'a TypeSystemDocument<'src>,
+        _options: &'a ResolverTypePrinterOptions,
+        _base: HashMap<&'src str, TSType>,
+    ) -> ResolverTypePrinterPluginFuture<'a, HashMap<&'src str, TSType>> {
         unimplemented!()
     }
-    fn transform_document_for_resolvers<'src>(
-        &self,
-        _document: &TypeSystemDocument<'src>,
-    ) -> Option<TypeSystemDocument<'src>> {
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        _document: &'a TypeSystemDocument<'src>,
+    ) -> ResolverTypePrinterPluginFuture<'a, Option<TypeSystemDocument<'src>>> {
         unimplemented!()
     }
 }
 
+/// Plugin that adds a type to the document for resolvers.
+struct AddTypePlugin;
+impl ResolverTypePrinterPlugin for AddTypePlugin {
+    fn transform_resolver_output_types<'a, 'src>(
+        &'a self,
+        _document: &'a TypeSystemDocument<'src>,
+        _options: &'a ResolverTypePrinterOptions,
+        base: HashMap<&'src str, TSType>,
+    ) -> ResolverTypePrinterPluginFuture<'a, HashMap<&'src str, TSType>> {
+        Box::pin(std::future::ready(Ok(base)))
+    }
+    fn transform_document_for_resolvers<'a, 'src>(
+        &'a self,
+        document: &'a TypeSystemDocument<'src>,
+    ) -> ResolverTypePrinterPluginFuture<'a, Option<TypeSystemDocument<'src>>> {
+        let addition = resolve_schema_extensions(
+            parse_type_system_document("type Admin { id: ID! }").unwrap(),
+        )
+        .unwrap();
+        let mut document = document.clone();
+        document.definitions.extend(addition.definitions);
+        Box::pin(std::future::ready(Ok(Some(document))))
+    }
+}
+
 static EMPTY_PLUGINS: &[DummyPlugin] = &[];
 
 #[test]
 fn resolver_printing() {
+    let doc = type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn context_type_and_mappers() {
+    let doc = type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            context_type: Some("./context#Context".into()),
+            mappers: vec![
+                ("User".into(), "./models#UserModel".into()),
+                ("Post".into(), "{ id: string }".into()),
+            ]
+            .into_iter()
+            .collect(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn raw_context_type() {
+    let doc = type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            root_resolver_type: "AppResolvers".into(),
+            resolver_output_type: "AppResolverOutput".into(),
+            context_type: Some("{ userId: string }".into()),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert!(printed.contains("export type AppResolvers<Context = ({ userId: string })> = "));
+    assert!(printed.contains("export type AppResolverOutput<T extends "));
+    assert!(!printed.contains("__Context"));
+}
+
+#[test]
+fn optional_field_resolvers() {
+    let doc = type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            mappers: vec![("User".into(), "./models#UserModel".into())]
+                .into_iter()
+                .collect(),
+            optional_field_resolvers: true,
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn mapper_type_not_found() {
+    let doc = type_system();
+    let result = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            mappers: vec![("UserSearchQuery".into(), "./models#Query".into())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    );
+    assert!(matches!(
+        result,
+        Err(ResolverTypePrinterError::MapperTypeNotFound { ref name }) if name == "UserSearchQuery"
+    ));
+}
+
+#[test]
+fn subscription_resolvers() {
+    let doc = subscription_type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            subscription_payloads: vec![("messageAdded".into(), "./events#MessageEvent".into())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn subscription_payload_field_not_found() {
+    let doc = subscription_type_system();
+    let result = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            subscription_payloads: vec![("me".into(), "{ id: string }".into())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    );
+    assert!(matches!(
+        result,
+        Err(ResolverTypePrinterError::SubscriptionFieldNotFound { ref name }) if name == "me"
+    ));
+}
+
+#[test]
+fn directive_resolvers() {
+    let mut doc = parse_type_system_document(
+        "
+            enum Role {
+                USER
+                ADMIN
+            }
+
+            directive @auth(requires: Role! = ADMIN) on OBJECT | FIELD_DEFINITION
+            directive @cacheControl(maxAge: Int) repeatable on FIELD_DEFINITION | QUERY
+            directive @internal on ENUM_VALUE | INPUT_FIELD_DEFINITION | ARGUMENT_DEFINITION
+            directive @clientOnly on FIELD
+
+            type User @auth(requires: USER) {
+                id: ID!
+                email: String! @auth
+            }
+
+            type Query {
+                me: User! @cacheControl(maxAge: 60)
+            }
+            ",
+    )
+    .unwrap();
+    doc.extend(generate_builtins());
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            context_type: Some("./context#Context".into()),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn scalar_resolvers() {
+    let mut doc = parse_type_system_document(
+        "
+            scalar Date
+            scalar URL
+
+            type Query {
+                now: Date!
+                homepage: URL
+            }
+            ",
+    )
+    .unwrap();
+    doc.extend(generate_builtins());
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            ..Default::default()
+        },
+        EMPTY_PLUGINS,
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn type_added_by_plugin() {
+    let doc = type_system();
+    let printed = print_document(
+        &doc,
+        ResolverTypePrinterOptions {
+            schema_source: "schema".into(),
+            ..Default::default()
+        },
+        &[AddTypePlugin],
+    )
+    .unwrap();
+    assert_snapshot!(printed);
+}
+
+fn subscription_type_system() -> TypeSystemDocument<'static> {
+    let doc = parse_type_system_document(
+        "
+            type Message {
+                id: ID!
+                body: String!
+            }
+
+            type Query {
+                me: ID!
+            }
+
+            type Subscription {
+                messageAdded(channel: ID!): Message!
+                tick: Int!
+            }
+            ",
+    )
+    .unwrap();
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn type_system() -> TypeSystemDocument<'static> {
     let doc = parse_type_system_document(
         "
             type User implements HasID {
@@ -76,17 +344,7 @@ fn resolver_printing() {
             ",
     )
     .unwrap();
-    let doc = resolve_schema_extensions(doc).unwrap();
-    let printed = print_document(
-        &doc,
-        ResolverTypePrinterOptions {
-            schema_source: "schema".into(),
-            ..Default::default()
-        },
-        EMPTY_PLUGINS,
-    )
-    .unwrap();
-    assert_snapshot!(printed);
+    resolve_schema_extensions(doc).unwrap()
 }
 
 fn print_document(
@@ -97,6 +355,6 @@ fn print_document(
     let mut result = String::new();
     let mut writer = JustWriter::new(&mut result);
     let mut printer = ResolverTypePrinter::new(options, &mut writer);
-    printer.print_document(document, plugins)?;
+    block_on(printer.print_document(document, plugins))?;
     Ok(result)
 }
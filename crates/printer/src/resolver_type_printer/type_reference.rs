@@ -0,0 +1,36 @@
+/// Reference to a TypeScript type given in config.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TypeReference<'a> {
+    /// Type exported from a module, written as `module#ExportName`.
+    Import {
+        module: &'a str,
+        export_name: &'a str,
+    },
+    /// Raw TypeScript type.
+    Raw(&'a str),
+}
+
+impl<'a> TypeReference<'a> {
+    pub fn parse(value: &'a str) -> Self {
+        // Module specifiers may start with '#' (subpath imports),
+        // so the last '#' separates the export name.
+        match value.rsplit_once('#') {
+            Some((module, export_name)) if !module.is_empty() && is_identifier(export_name) => {
+                TypeReference::Import {
+                    module,
+                    export_name,
+                }
+            }
+            _ => TypeReference::Raw(value),
+        }
+    }
+}
+
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
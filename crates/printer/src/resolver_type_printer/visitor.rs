@@ -6,8 +6,8 @@ use crate::{
     utils::interface_implementers,
 };
 use nitrogql_ast::type_system::{
-    ArgumentsDefinition, InterfaceTypeDefinition, ObjectTypeDefinition, TypeDefinition,
-    UnionTypeDefinition,
+    ArgumentsDefinition, DirectiveDefinition, InterfaceTypeDefinition, ObjectTypeDefinition,
+    ScalarTypeDefinition, TypeDefinition, UnionTypeDefinition,
 };
 use nitrogql_config_file::TypeTarget;
 
@@ -62,6 +62,7 @@ fn get_object_resolver_type(
     context: &ResolverTypePrinterContext,
 ) -> Option<TSType> {
     let parent_type = TSType::TypeVariable((&def.name).into());
+    let is_subscription_type = is_subscription_type(def.name.name, context);
     let fields = def
         .fields
         .iter()
@@ -74,19 +75,29 @@ fn get_object_resolver_type(
                 TSType::TypeVariable((&name.name).into())
             });
 
-            let resolver_type = TSType::TypeFunc(
-                Box::new(TSType::TypeVariable("__Resolver".into())),
-                vec![
-                    // Parent
+            let resolver_type = if is_subscription_type {
+                get_subscription_resolver_type(
+                    field.name.name,
                     parent_type.clone(),
-                    // Args
                     arguments_type,
-                    // Context
-                    TSType::TypeVariable("Context".into()),
-                    // Result
                     result_type,
-                ],
-            );
+                    context,
+                )
+            } else {
+                TSType::TypeFunc(
+                    Box::new(TSType::TypeVariable("__Resolver".into())),
+                    vec![
+                        // Parent
+                        parent_type.clone(),
+                        // Args
+                        arguments_type,
+                        // Context
+                        TSType::TypeVariable("Context".into()),
+                        // Result
+                        result_type,
+                    ],
+                )
+            };
 
             ObjectField {
                 key: ObjectKey::from(&field.name),
@@ -96,10 +107,94 @@ fn get_object_resolver_type(
                 description: None,
             }
         })
-        .collect();
+        .collect::<Vec<_>>();
+    if context.options.optional_field_resolvers
+        && !fields.is_empty()
+        && !is_root_type(def.name.name, context)
+    {
+        // Resolvers for properties that parent already has are optional.
+        return Some(TSType::TypeFunc(
+            Box::new(TSType::TypeVariable("__OptionalIfParentHas".into())),
+            vec![parent_type, TSType::Object(fields)],
+        ));
+    }
     Some(TSType::Object(fields))
 }
 
+/// Generates resolver type of a subscription field,
+/// which consists of `subscribe` and `resolve`.
+fn get_subscription_resolver_type(
+    field_name: &str,
+    parent_type: TSType,
+    arguments_type: TSType,
+    result_type: TSType,
+    context: &ResolverTypePrinterContext,
+) -> TSType {
+    let configured_payload_type = context.subscription_payloads.get(field_name);
+    // Without `resolve`, the field of the payload is used as the result.
+    let resolve_is_optional = configured_payload_type.is_none();
+    let payload_type = configured_payload_type.cloned().unwrap_or_else(|| {
+        TSType::object(vec![(field_name.to_owned(), result_type.clone(), None)])
+    });
+    let subscriber_type = TSType::TypeFunc(
+        Box::new(TSType::TypeVariable("__Subscriber".into())),
+        vec![
+            // Parent
+            parent_type,
+            // Args
+            arguments_type.clone(),
+            // Context
+            TSType::TypeVariable("Context".into()),
+            // Payload
+            payload_type.clone(),
+        ],
+    );
+    let resolve_type = TSType::TypeFunc(
+        Box::new(TSType::TypeVariable("__Resolver".into())),
+        vec![
+            // Parent
+            payload_type,
+            // Args
+            arguments_type,
+            // Context
+            TSType::TypeVariable("Context".into()),
+            // Result
+            result_type,
+        ],
+    );
+    TSType::Object(vec![
+        ObjectField {
+            key: "subscribe".into(),
+            r#type: subscriber_type,
+            optional: false,
+            readonly: false,
+            description: None,
+        },
+        ObjectField {
+            key: "resolve".into(),
+            r#type: resolve_type,
+            optional: resolve_is_optional,
+            readonly: false,
+            description: None,
+        },
+    ])
+}
+
+/// Checks whether given type is the subscription root type.
+fn is_subscription_type(type_name: &str, context: &ResolverTypePrinterContext) -> bool {
+    let root_types = context.schema.root_types().unwrap_or_default();
+    root_types.subscription_type.as_ref() == type_name
+}
+
+/// Checks whether given type is a root operation type.
+/// Parent of root types is the root value, so their resolvers are always required.
+fn is_root_type(type_name: &str, context: &ResolverTypePrinterContext) -> bool {
+    let root_types = context.schema.root_types().unwrap_or_default();
+    root_types.query_type.as_ref() == type_name
+        || root_types.mutation_type.as_ref() == type_name
+        || root_types.subscription_type.as_ref() == type_name
+}
+
 fn get_interface_resolver_type(
     def: &InterfaceTypeDefinition,
     context: &ResolverTypePrinterContext,
@@ -164,6 +259,93 @@ fn get_union_resolver_type(
     Some(TSType::object(vec![("__resolveType", resolver_type, None)]))
 }
 
+/// Generates type of the implementation of given scalar.
+/// Internal values are what resolvers receive and external values are what clients receive.
+pub fn get_scalar_resolver_type(
+    def: &ScalarTypeDefinition,
+    context: &ResolverTypePrinterContext,
+) -> TSType {
+    let schema_type = |target: TypeTarget| {
+        TSType::NamespaceMember3(
+            context.options.schema_root_namespace.clone(),
+            target.to_string(),
+            def.name.name.to_string(),
+        )
+    };
+    TSType::TypeFunc(
+        Box::new(TSType::TypeVariable("GraphQLScalarTypeConfig".into())),
+        vec![
+            schema_type(TypeTarget::ResolverInput),
+            schema_type(TypeTarget::OperationOutput),
+        ],
+    )
+}
+
+/// Generates type of transformers of given directive.
+/// Each location has its own transformer that receives the schema element the directive is applied to.
+pub fn get_directive_resolver_type(
+    def: &DirectiveDefinition,
+    context: &ResolverTypePrinterContext,
+) -> TSType {
+    let arguments_type = def.arguments.as_ref().map_or_else(
+        || TSType::Object(vec![]),
+        |arguments| arguments_definition_to_ts(context, arguments),
+    );
+    TSType::Object(
+        def.locations
+            .iter()
+            .filter_map(|location| {
+                let target_type = directive_target_type(location.name)?;
+                Some(ObjectField {
+                    key: ObjectKey::from(location),
+                    r#type: TSType::TypeFunc(
+                        Box::new(TSType::TypeVariable("__DirectiveTransformer".into())),
+                        vec![target_type, arguments_type.clone()],
+                    ),
+                    description: None,
+                    readonly: false,
+                    optional: true,
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Name of the graphql-js type of schema elements at given directive location.
+/// Returns None for executable directive locations.
+pub fn directive_target_type_name(location: &str) -> Option<&'static str> {
+    match location {
+        "SCHEMA" => Some("GraphQLSchema"),
+        "SCALAR" => Some("GraphQLScalarType"),
+        "OBJECT" => Some("GraphQLObjectType"),
+        "FIELD_DEFINITION" => Some("GraphQLFieldConfig"),
+        "ARGUMENT_DEFINITION" => Some("GraphQLArgumentConfig"),
+        "INTERFACE" => Some("GraphQLInterfaceType"),
+        "UNION" => Some("GraphQLUnionType"),
+        "ENUM" => Some("GraphQLEnumType"),
+        "ENUM_VALUE" => Some("GraphQLEnumValueConfig"),
+        "INPUT_OBJECT" => Some("GraphQLInputObjectType"),
+        "INPUT_FIELD_DEFINITION" => Some("GraphQLInputFieldConfig"),
+        _ => None,
+    }
+}
+
+fn directive_target_type(location: &str) -> Option<TSType> {
+    let type_name = directive_target_type_name(location)?;
+    let target_type = TSType::TypeVariable(type_name.into());
+    Some(match location {
+        // These types take types of source and context.
+        "OBJECT" | "FIELD_DEFINITION" => TSType::TypeFunc(
+            Box::new(target_type),
+            vec![
+                TSType::TypeVariable("any".into()),
+                TSType::TypeVariable("Context".into()),
+            ],
+        ),
+        _ => target_type,
+    })
+}
+
 fn arguments_definition_to_ts(
     context: &ResolverTypePrinterContext,
     arguments: &ArgumentsDefinition,
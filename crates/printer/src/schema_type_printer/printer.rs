@@ -1,8 +1,10 @@
-use std::collections::HashMap;
+use std::{borrow::Cow, collections::HashMap};
 
+use graphql_type_system::{Schema, TypeDefinition as SchemaTypeDefinition};
+use nitrogql_ast::base::Pos;
 use nitrogql_ast::type_system::{TypeDefinition, TypeSystemDefinition, TypeSystemDocument};
-use nitrogql_config_file::{Config, ScalarTypeConfig, TypeTarget};
-use nitrogql_semantics::ast_to_type_system;
+use nitrogql_config_file::{Config, EnumStyle, ScalarTypeConfig, TypeTarget};
+use nitrogql_semantics::{ast_to_type_system, cache_keys_of_type, CacheKeys};
 use sourcemap_writer::SourceMapWriter;
 
 use crate::{schema::get_builtin_scalar_types, ts_types::TSType};
@@ -20,6 +22,13 @@ pub struct SchemaTypePrinterOptions {
     pub input_nullable_field_is_optional: bool,
     /// Whether to emit runtime for generated schema types.
     pub emit_schema_runtime: bool,
+    /// How enums are emitted.
+    pub enum_style: EnumStyle,
+    /// Whether to emit arrays of values of each enum.
+    pub emit_enum_values: bool,
+    /// Key fields of each type used by normalized caches.
+    /// `CacheKeyConfig` is emitted along with runtime if not empty.
+    pub cache_keys: HashMap<String, Vec<String>>,
 }
 
 impl Default for SchemaTypePrinterOptions {
@@ -29,15 +38,31 @@ impl Default for SchemaTypePrinterOptions {
             schema_metadata_type: "__nitrogql_schema".into(),
             input_nullable_field_is_optional: true,
             emit_schema_runtime: false,
+            enum_style: EnumStyle::ConstObject,
+            emit_enum_values: false,
+            cache_keys: HashMap::new(),
         }
     }
 }
 
 impl SchemaTypePrinterOptions {
+    /// Returns the style of enums that is actually emitted.
+    /// Enums are always emitted as unions when runtime is not emitted.
+    pub fn effective_enum_style(&self) -> EnumStyle {
+        if self.emit_schema_runtime {
+            self.enum_style
+        } else {
+            EnumStyle::Union
+        }
+    }
+
     /// Generate from config.
     pub fn from_config(config: &Config) -> Self {
         let mut result = SchemaTypePrinterOptions {
             emit_schema_runtime: config.generate.emit_schema_runtime,
+            enum_style: config.generate.enum_style.unwrap_or_default(),
+            emit_enum_values: config.generate.emit_enum_values,
+            cache_keys: config.cache_keys.clone(),
             input_nullable_field_is_optional: config
                 .generate
                 .r#type
@@ -102,9 +127,66 @@ where
             self.writer.write("\n");
         }
 
+        if self.options.emit_schema_runtime && !self.options.cache_keys.is_empty() {
+            self.print_cache_key_config(&schema);
+        }
+
         Ok(())
     }
 
+    /// Prints an object that can be passed to `keys` of Graphcache.
+    /// Types keyed by `id` are omitted as Graphcache uses it by default.
+    /// Types without keys are marked as embedded by returning null.
+    fn print_cache_key_config(&mut self, schema: &Schema<Cow<str>, Pos>) {
+        let root_types = schema.root_types().unwrap_or_default();
+        let root_type_names = [
+            &root_types.query_type,
+            &root_types.mutation_type,
+            &root_types.subscription_type,
+        ];
+        self.writer.write("export const CacheKeyConfig = {\n");
+        self.writer.indent();
+        for (type_name, def) in schema.iter_types() {
+            if type_name.starts_with("__")
+                || root_type_names.iter().any(|name| ***name == *type_name)
+            {
+                continue;
+            }
+            let SchemaTypeDefinition::Object(_) = **def else {
+                continue;
+            };
+            match cache_keys_of_type(def, &self.options.cache_keys) {
+                CacheKeys::Id => {}
+                CacheKeys::Configured(keys) if !keys.is_empty() => {
+                    let condition = keys
+                        .iter()
+                        .map(|key| format!("data.{key} == null"))
+                        .collect::<Vec<_>>()
+                        .join(" || ");
+                    let key = match keys[..] {
+                        [key] => format!("String(data.{key})"),
+                        _ => format!(
+                            "JSON.stringify([{}])",
+                            keys.iter()
+                                .map(|key| format!("data.{key}"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    };
+                    writeln!(
+                        self.writer,
+                        "{type_name}: (data: Record<string, unknown>): string | null => {condition} ? null : {key},"
+                    );
+                }
+                _ => {
+                    writeln!(self.writer, "{type_name}: (): null => null,");
+                }
+            }
+        }
+        self.writer.dedent();
+        self.writer.write("};\n");
+    }
+
     fn print_prelude(&mut self, document: &TypeSystemDocument) {
         self.writer.write("export type ");
         self.writer.write(&self.options.schema_metadata_type);
@@ -1,8 +1,10 @@
 #![cfg(test)]
 
 use insta::assert_snapshot;
+use std::collections::HashMap;
+
 use nitrogql_ast::type_system::TypeSystemDocument;
-use nitrogql_config_file::ScalarTypeConfig;
+use nitrogql_config_file::{EnumStyle, ScalarTypeConfig};
 
 use crate::schema_type_printer::{
     error::SchemaTypePrinterResult,
@@ -292,6 +294,126 @@ fn enum_runtime() {
     assert_snapshot!(printed);
 }
 
+#[test]
+fn enum_runtime_union() {
+    let printed = print_enum_runtime(SchemaTypePrinterOptions {
+        emit_schema_runtime: true,
+        enum_style: EnumStyle::Union,
+        ..SchemaTypePrinterOptions::default()
+    });
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn enum_runtime_ts_enum() {
+    let printed = print_enum_runtime(SchemaTypePrinterOptions {
+        emit_schema_runtime: true,
+        enum_style: EnumStyle::TsEnum,
+        ..SchemaTypePrinterOptions::default()
+    });
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn enum_ts_enum_without_runtime() {
+    // Enums are emitted as unions when runtime is not emitted.
+    let printed = print_enum_runtime(SchemaTypePrinterOptions {
+        enum_style: EnumStyle::TsEnum,
+        emit_enum_values: true,
+        ..SchemaTypePrinterOptions::default()
+    });
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn enum_values() {
+    let printed = print_enum_runtime(SchemaTypePrinterOptions {
+        emit_schema_runtime: true,
+        emit_enum_values: true,
+        ..SchemaTypePrinterOptions::default()
+    });
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn enum_values_ts_enum() {
+    let printed = print_enum_runtime(SchemaTypePrinterOptions {
+        emit_schema_runtime: true,
+        enum_style: EnumStyle::TsEnum,
+        emit_enum_values: true,
+        ..SchemaTypePrinterOptions::default()
+    });
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn cache_key_config() {
+    let doc = parse_type_system_document(
+        r#"
+        scalar ID
+        scalar String
+        type Query {
+            me: User!
+        }
+        interface Node {
+            id: ID!
+        }
+        type User implements Node {
+            id: ID!
+            profile: Profile!
+        }
+        type Profile {
+            bio: String!
+        }
+        type Product {
+            sku: String!
+            region: String!
+        }
+        interface Localized {
+            locale: String!
+        }
+        type Article implements Localized {
+            slug: String!
+            locale: String!
+        }
+        "#,
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    let options = SchemaTypePrinterOptions {
+        emit_schema_runtime: true,
+        cache_keys: HashMap::from([
+            (
+                "Product".to_owned(),
+                vec!["sku".to_owned(), "region".to_owned()],
+            ),
+            ("Localized".to_owned(), vec!["locale".to_owned()]),
+        ]),
+        ..SchemaTypePrinterOptions::default()
+    };
+    let printed = print_document(&doc, options).unwrap();
+    assert_snapshot!(printed);
+}
+
+fn print_enum_runtime(options: SchemaTypePrinterOptions) -> String {
+    let doc = parse_type_system_document(
+        r#"
+        "Type of user."
+        enum UserType {
+            NormalUser
+            PremiumUser
+            AdminUser
+        }
+        type User {
+            type: UserType!
+        }
+        "#,
+    )
+    .unwrap();
+    let doc = resolve_schema_extensions(doc).unwrap();
+    print_document(&doc, options).unwrap()
+}
+
 fn print_document(
     document: &TypeSystemDocument,
     options: SchemaTypePrinterOptions,
@@ -15,7 +15,7 @@ use nitrogql_ast::{
     },
     value::StringValue,
 };
-use nitrogql_config_file::TypeTarget;
+use nitrogql_config_file::{EnumStyle, TypeTarget};
 use sourcemap_writer::SourceMapWriter;
 
 use crate::jsdoc::print_description as jsdoc_print_description;
@@ -339,18 +339,22 @@ impl TypePrinter for EnumTypeDefinition<'_> {
         context: &SchemaTypePrinterContext,
         writer: &mut impl SourceMapWriter,
     ) -> SchemaTypePrinterResult<()> {
-        let enum_type = TSType::Union(
-            self.values
-                .iter()
-                .map(|mem| TSType::StringLiteral(mem.name.to_string()))
-                .collect(),
-        );
-
-        print_description(&self.description, writer);
         let local_name = context
             .local_type_names
             .get(self.name.name)
             .expect("Local type name not generated");
+        let enum_type = match context.options.effective_enum_style() {
+            EnumStyle::Union | EnumStyle::ConstObject => TSType::Union(
+                self.values
+                    .iter()
+                    .map(|mem| TSType::StringLiteral(mem.name.to_string()))
+                    .collect(),
+            ),
+            // refer to the enum declared outside of the namespace
+            EnumStyle::TsEnum => TSType::TypeVariable(ts_enum_alias(local_name).as_str().into()),
+        };
+
+        print_description(&self.description, writer);
         export_type(
             writer,
             &self.enum_keyword,
@@ -371,26 +375,83 @@ impl TypePrinter for EnumTypeDefinition<'_> {
             .local_type_names
             .get(self.name.name)
             .expect("Local type name not generated");
-        export_representative(
-            writer,
-            &self.enum_keyword,
-            &self.name,
-            local_name,
-            TypeTarget::OperationOutput,
-        );
-        if context.options.emit_schema_runtime {
+        let enum_style = context.options.effective_enum_style();
+        match enum_style {
+            EnumStyle::Union => {
+                export_representative(
+                    writer,
+                    &self.enum_keyword,
+                    &self.name,
+                    local_name,
+                    TypeTarget::OperationOutput,
+                );
+            }
+            EnumStyle::ConstObject => {
+                export_representative(
+                    writer,
+                    &self.enum_keyword,
+                    &self.name,
+                    local_name,
+                    TypeTarget::OperationOutput,
+                );
+                writer.write_for("export const ", &self.enum_keyword);
+                writer.write_for(self.name.name, &self.name);
+                writer.write(" = {\n");
+                writer.indent();
+                for value in &self.values {
+                    writer.write_for(value.name.name, &value.name);
+                    writer.write(": \"");
+                    writer.write_for(value.name.name, &value.name);
+                    writer.write("\",\n");
+                }
+                writer.dedent();
+                writer.write("} as const;\n");
+            }
+            EnumStyle::TsEnum => {
+                print_description(&self.description, writer);
+                if self.name.name == local_name {
+                    writer.write_for("export enum ", &self.enum_keyword);
+                } else {
+                    writer.write_for("enum ", &self.enum_keyword);
+                }
+                writer.write_for(local_name, &self.name);
+                writer.write(" {\n");
+                writer.indent();
+                for value in &self.values {
+                    writer.write_for(value.name.name, &value.name);
+                    writer.write(" = \"");
+                    writer.write_for(value.name.name, &value.name);
+                    writer.write("\",\n");
+                }
+                writer.dedent();
+                writer.write("}\n");
+                if self.name.name != local_name {
+                    writeln!(writer, "export {{ {local_name} as {} }};", self.name.name);
+                }
+                // Namespaces refer to the enum through this alias
+                // because the enum is shadowed by namespace members of the same name.
+                writeln!(writer, "type {} = {local_name};", ts_enum_alias(local_name));
+            }
+        }
+        if context.options.emit_schema_runtime && context.options.emit_enum_values {
             writer.write_for("export const ", &self.enum_keyword);
             writer.write_for(self.name.name, &self.name);
-            writer.write(" = {\n");
-            writer.indent();
-            for value in &self.values {
-                writer.write_for(value.name.name, &value.name);
-                writer.write(": \"");
-                writer.write_for(value.name.name, &value.name);
-                writer.write("\",\n");
+            writer.write("Values = [");
+            for (idx, value) in self.values.iter().enumerate() {
+                if idx > 0 {
+                    writer.write(", ");
+                }
+                if enum_style == EnumStyle::TsEnum {
+                    writer.write(local_name);
+                    writer.write(".");
+                    writer.write_for(value.name.name, &value.name);
+                } else {
+                    writer.write("\"");
+                    writer.write_for(value.name.name, &value.name);
+                    writer.write("\"");
+                }
             }
-            writer.dedent();
-            writer.write("} as const;\n")
+            writer.write("] as const;\n");
         }
         Ok(())
     }
@@ -530,6 +591,11 @@ fn export_representative(
     }
 }
 
+/// Name of the type alias of a TypeScript enum.
+fn ts_enum_alias(local_name: &str) -> String {
+    format!("__enum_{local_name}")
+}
+
 fn print_description(description: &Option<StringValue>, writer: &mut impl SourceMapWriter) {
     if let Some(description) = description {
         jsdoc_print_description(description, writer);
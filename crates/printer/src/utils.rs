@@ -4,6 +4,10 @@ use nitrogql_ast::{
     selection_set::{Selection, SelectionSet},
 };
 
+/// Name of directive that opts a fragment spread out of fragment masking.
+/// This directive is only meaningful to nitrogql and is not sent to the server.
+pub const UNMASK_DIRECTIVE: &str = "unmask";
+
 /// Returns an iterator over possible object types that implements given interface.
 pub fn interface_implementers<'a, 'src, S: Text<'src>, OriginalNode>(
     schema: &'a Schema<S, OriginalNode>,
@@ -0,0 +1,164 @@
+use nitrogql_config_file::{TypeTarget, ValidationSchemaLibrary};
+
+/// Syntax of schema expressions for each validation library.
+pub trait LibrarySyntax {
+    /// Import declaration of the library.
+    fn import(&self) -> &'static str;
+    /// Type that annotates a schema whose output is given type.
+    fn schema_type(&self, output_type: &str) -> String;
+    /// Schema of built-in scalar, if known.
+    fn builtin_scalar(&self, name: &str, target: TypeTarget) -> Option<&'static str>;
+    /// Schema that accepts any value as given type.
+    fn custom(&self, output_type: &str) -> String;
+    /// Schema of string enum with given values.
+    fn string_enum(&self, values: &[&str]) -> String;
+    /// Schema of TypeScript enum object.
+    fn native_enum(&self, enum_object: &str) -> String;
+    /// Schema that accepts no value.
+    fn never(&self) -> &'static str;
+    /// Schema of object field that must not exist.
+    fn absent(&self) -> &'static str;
+    /// Schema that only accepts given string.
+    fn literal(&self, value: &str) -> String;
+    /// Schema that accepts any of given schemas.
+    fn union(&self, schemas: &[String]) -> String;
+    /// Schema that selects one of given object schemas by the value of given key.
+    fn discriminated_union(&self, key: &str, schemas: &[String]) -> String;
+    fn nullable(&self, schema: &str) -> String;
+    /// Schema that also accepts null and undefined.
+    fn nullish(&self, schema: &str) -> String;
+    fn array(&self, schema: &str) -> String;
+    /// Beginning of object schema. Fields are printed after this.
+    fn object_start(&self) -> &'static str;
+    fn object_end(&self) -> &'static str;
+    fn lazy_start(&self) -> &'static str;
+    fn lazy_end(&self) -> &'static str;
+}
+
+impl LibrarySyntax for ValidationSchemaLibrary {
+    fn import(&self) -> &'static str {
+        match self {
+            ValidationSchemaLibrary::Zod => "import { z } from \"zod\";",
+            ValidationSchemaLibrary::Valibot => "import * as v from \"valibot\";",
+        }
+    }
+    fn schema_type(&self, output_type: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.ZodType<{output_type}>"),
+            ValidationSchemaLibrary::Valibot => format!("v.GenericSchema<{output_type}>"),
+        }
+    }
+    fn builtin_scalar(&self, name: &str, target: TypeTarget) -> Option<&'static str> {
+        // IDs are received as strings but can be sent as numbers.
+        let name = match (name, target) {
+            ("ID", TypeTarget::OperationOutput | TypeTarget::ResolverInput) => "String",
+            _ => name,
+        };
+        match (self, name) {
+            (ValidationSchemaLibrary::Zod, "String") => Some("z.string()"),
+            (ValidationSchemaLibrary::Zod, "ID") => Some("z.union([z.string(), z.number()])"),
+            (ValidationSchemaLibrary::Zod, "Int") => Some("z.number().int()"),
+            (ValidationSchemaLibrary::Zod, "Float") => Some("z.number()"),
+            (ValidationSchemaLibrary::Zod, "Boolean") => Some("z.boolean()"),
+            (ValidationSchemaLibrary::Valibot, "String") => Some("v.string()"),
+            (ValidationSchemaLibrary::Valibot, "ID") => Some("v.union([v.string(), v.number()])"),
+            (ValidationSchemaLibrary::Valibot, "Int") => Some("v.pipe(v.number(), v.integer())"),
+            (ValidationSchemaLibrary::Valibot, "Float") => Some("v.number()"),
+            (ValidationSchemaLibrary::Valibot, "Boolean") => Some("v.boolean()"),
+            _ => None,
+        }
+    }
+    fn custom(&self, output_type: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.custom<{output_type}>()"),
+            ValidationSchemaLibrary::Valibot => format!("v.custom<{output_type}>(() => true)"),
+        }
+    }
+    fn string_enum(&self, values: &[&str]) -> String {
+        let values = values
+            .iter()
+            .map(|value| format!("\"{value}\""))
+            .collect::<Vec<_>>()
+            .join(", ");
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.enum([{values}])"),
+            ValidationSchemaLibrary::Valibot => format!("v.picklist([{values}])"),
+        }
+    }
+    fn native_enum(&self, enum_object: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.nativeEnum({enum_object})"),
+            ValidationSchemaLibrary::Valibot => format!("v.enum({enum_object})"),
+        }
+    }
+    fn never(&self) -> &'static str {
+        match self {
+            ValidationSchemaLibrary::Zod => "z.never()",
+            ValidationSchemaLibrary::Valibot => "v.never()",
+        }
+    }
+    fn absent(&self) -> &'static str {
+        match self {
+            ValidationSchemaLibrary::Zod => "z.never().optional()",
+            ValidationSchemaLibrary::Valibot => "v.optional(v.never())",
+        }
+    }
+    fn literal(&self, value: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.literal(\"{value}\")"),
+            ValidationSchemaLibrary::Valibot => format!("v.literal(\"{value}\")"),
+        }
+    }
+    fn union(&self, schemas: &[String]) -> String {
+        let schemas = schemas.join(", ");
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.union([{schemas}])"),
+            ValidationSchemaLibrary::Valibot => format!("v.union([{schemas}])"),
+        }
+    }
+    fn discriminated_union(&self, key: &str, schemas: &[String]) -> String {
+        let schemas = schemas.join(", ");
+        match self {
+            ValidationSchemaLibrary::Zod => {
+                format!("z.discriminatedUnion(\"{key}\", [{schemas}])")
+            }
+            ValidationSchemaLibrary::Valibot => format!("v.variant(\"{key}\", [{schemas}])"),
+        }
+    }
+    fn nullable(&self, schema: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("{schema}.nullable()"),
+            ValidationSchemaLibrary::Valibot => format!("v.nullable({schema})"),
+        }
+    }
+    fn nullish(&self, schema: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("{schema}.nullish()"),
+            ValidationSchemaLibrary::Valibot => format!("v.nullish({schema})"),
+        }
+    }
+    fn array(&self, schema: &str) -> String {
+        match self {
+            ValidationSchemaLibrary::Zod => format!("z.array({schema})"),
+            ValidationSchemaLibrary::Valibot => format!("v.array({schema})"),
+        }
+    }
+    fn object_start(&self) -> &'static str {
+        match self {
+            ValidationSchemaLibrary::Zod => "z.object({",
+            ValidationSchemaLibrary::Valibot => "v.object({",
+        }
+    }
+    fn object_end(&self) -> &'static str {
+        "})"
+    }
+    fn lazy_start(&self) -> &'static str {
+        match self {
+            ValidationSchemaLibrary::Zod => "z.lazy(() => ",
+            ValidationSchemaLibrary::Valibot => "v.lazy(() => ",
+        }
+    }
+    fn lazy_end(&self) -> &'static str {
+        ")"
+    }
+}
@@ -0,0 +1,4 @@
+pub mod library;
+pub mod options;
+pub mod printer;
+mod tests;
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use nitrogql_config_file::{Config, EnumStyle, ValidationSchemaLibrary};
+
+use crate::SchemaTypePrinterOptions;
+
+#[derive(Debug)]
+pub struct ValidationSchemaPrinterOptions {
+    /// Library used by generated schemas.
+    pub library: ValidationSchemaLibrary,
+    /// Validation schema of values of each scalar sent to the server.
+    /// Provided as raw TypeScript code.
+    pub scalar_schemas: HashMap<String, String>,
+    /// Source of schema type to import from.
+    pub schema_source: String,
+    /// Name of the root TypeScript namespace that contains schema types.
+    pub schema_root_namespace: String,
+    /// Suffix for name of generated schemas.
+    pub schema_name_suffix: String,
+    /// Whether to make input nullable fields optional.
+    pub input_nullable_field_is_optional: bool,
+    /// Style of enums emitted in the schema file.
+    pub enum_style: EnumStyle,
+}
+
+impl Default for ValidationSchemaPrinterOptions {
+    fn default() -> Self {
+        ValidationSchemaPrinterOptions {
+            library: ValidationSchemaLibrary::Zod,
+            scalar_schemas: HashMap::new(),
+            schema_source: "".into(),
+            schema_root_namespace: "Schema".into(),
+            schema_name_suffix: "Schema".into(),
+            input_nullable_field_is_optional: true,
+            enum_style: EnumStyle::Union,
+        }
+    }
+}
+
+impl ValidationSchemaPrinterOptions {
+    pub fn from_config(config: &Config) -> Self {
+        ValidationSchemaPrinterOptions {
+            library: config.generate.validation_schema_library,
+            scalar_schemas: config
+                .generate
+                .r#type
+                .scalar_schemas
+                .iter()
+                .map(|(name, schema)| (name.clone(), schema.send().to_owned()))
+                .collect(),
+            input_nullable_field_is_optional: config
+                .generate
+                .r#type
+                .allow_undefined_as_optional_input,
+            // Enums must be validated in the same shape as the schema file.
+            enum_style: SchemaTypePrinterOptions::from_config(config).effective_enum_style(),
+            ..ValidationSchemaPrinterOptions::default()
+        }
+    }
+}
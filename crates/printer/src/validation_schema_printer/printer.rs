@@ -0,0 +1,189 @@
+use nitrogql_ast::{
+    base::Ident,
+    r#type::Type,
+    type_system::{
+        EnumTypeDefinition, InputObjectTypeDefinition, ScalarTypeDefinition, TypeDefinition,
+        TypeSystemDefinition,
+    },
+    value::StringValue,
+    TypeSystemDocument,
+};
+use nitrogql_config_file::{EnumStyle, TypeTarget};
+use sourcemap_writer::SourceMapWriter;
+
+use crate::jsdoc::print_description;
+
+use super::{library::LibrarySyntax, options::ValidationSchemaPrinterOptions};
+
+pub struct ValidationSchemaPrinter<'a, Writer> {
+    options: ValidationSchemaPrinterOptions,
+    writer: &'a mut Writer,
+}
+
+impl<'a, Writer> ValidationSchemaPrinter<'a, Writer>
+where
+    Writer: SourceMapWriter,
+{
+    pub fn new(options: ValidationSchemaPrinterOptions, writer: &'a mut Writer) -> Self {
+        Self { options, writer }
+    }
+
+    /// Prints validation schemas for all input objects, enums and scalars in given document.
+    pub fn print_document(&mut self, document: &TypeSystemDocument) {
+        let library = self.options.library;
+        writeln!(self.writer, "{}", library.import());
+        // TypeScript enums are referred to as values.
+        let import_type = if self.options.enum_style == EnumStyle::TsEnum {
+            ""
+        } else {
+            "type "
+        };
+        write!(
+            self.writer,
+            "import {import_type}* as {} from \"{}\";\n\n",
+            self.options.schema_root_namespace, self.options.schema_source,
+        );
+
+        for def in document.definitions.iter() {
+            let TypeSystemDefinition::TypeDefinition(def) = def else {
+                continue;
+            };
+            match def {
+                TypeDefinition::Scalar(def) => self.print_scalar(def),
+                TypeDefinition::Enum(def) => self.print_enum(def),
+                TypeDefinition::InputObject(def) => self.print_input_object(def),
+                TypeDefinition::Object(_)
+                | TypeDefinition::Interface(_)
+                | TypeDefinition::Union(_) => {}
+            }
+        }
+    }
+
+    fn print_scalar(&mut self, def: &ScalarTypeDefinition) {
+        let library = self.options.library;
+        let output_type = self.input_type(def.name.name);
+        let schema = self
+            .options
+            .scalar_schemas
+            .get(def.name.name)
+            .map(|schema| schema.as_str())
+            .or_else(|| library.builtin_scalar(def.name.name, TypeTarget::OperationInput))
+            .map(|schema| schema.to_owned())
+            .unwrap_or_else(|| library.custom(&output_type));
+
+        self.print_declaration_start(&def.description, &def.name, Some(&output_type));
+        self.writer.write(&schema);
+        self.writer.write(";\n\n");
+    }
+
+    fn print_enum(&mut self, def: &EnumTypeDefinition) {
+        let library = self.options.library;
+        let schema = match self.options.enum_style {
+            EnumStyle::Union | EnumStyle::ConstObject => {
+                let values = def
+                    .values
+                    .iter()
+                    .map(|value| value.name.name)
+                    .collect::<Vec<_>>();
+                library.string_enum(&values)
+            }
+            EnumStyle::TsEnum => library.native_enum(&format!(
+                "{}.{}",
+                self.options.schema_root_namespace, def.name.name
+            )),
+        };
+
+        // Not annotated so that enum values can be read from the schema.
+        self.print_declaration_start(&def.description, &def.name, None);
+        self.writer.write(&schema);
+        self.writer.write(";\n\n");
+    }
+
+    fn print_input_object(&mut self, def: &InputObjectTypeDefinition) {
+        let library = self.options.library;
+        let output_type = self.input_type(def.name.name);
+
+        self.print_declaration_start(&def.description, &def.name, Some(&output_type));
+        // Input objects may refer to each other (even recursively),
+        // so they are evaluated lazily.
+        self.writer.write(library.lazy_start());
+        self.writer.write(library.object_start());
+        self.writer.write("\n");
+        self.writer.indent();
+        for field in def.fields.iter() {
+            let optional = self.options.input_nullable_field_is_optional;
+            let schema = self.type_schema(&field.r#type, optional);
+            if let Some(description) = &field.description {
+                print_description(description, self.writer);
+            }
+            self.writer.write_for(field.name.name, &field.name);
+            self.writer.write(": ");
+            self.writer.write(&schema);
+            self.writer.write(",\n");
+        }
+        self.writer.dedent();
+        self.writer.write(library.object_end());
+        self.writer.write(library.lazy_end());
+        self.writer.write(";\n\n");
+    }
+
+    fn print_declaration_start(
+        &mut self,
+        description: &Option<StringValue>,
+        name: &Ident,
+        output_type: Option<&str>,
+    ) {
+        if let Some(description) = description {
+            print_description(description, self.writer);
+        }
+        self.writer.write("export const ");
+        self.writer.write_for(&self.schema_name(name.name), name);
+        if let Some(output_type) = output_type {
+            self.writer.write(": ");
+            self.writer
+                .write(&self.options.library.schema_type(output_type));
+        }
+        self.writer.write(" = ");
+    }
+
+    /// Generates a schema for given GraphQL type.
+    /// `optional` specifies whether undefined is allowed in addition to null.
+    fn type_schema(&self, ty: &Type, optional: bool) -> String {
+        let library = self.options.library;
+        match ty {
+            Type::NonNull(inner) => self.non_null_type_schema(&inner.r#type),
+            _ => {
+                let schema = self.non_null_type_schema(ty);
+                if optional {
+                    library.nullish(&schema)
+                } else {
+                    library.nullable(&schema)
+                }
+            }
+        }
+    }
+
+    fn non_null_type_schema(&self, ty: &Type) -> String {
+        match ty {
+            Type::Named(name) => self.schema_name(name.name.name),
+            Type::List(inner) => self
+                .options
+                .library
+                .array(&self.type_schema(&inner.r#type, false)),
+            Type::NonNull(inner) => self.non_null_type_schema(&inner.r#type),
+        }
+    }
+
+    fn schema_name(&self, type_name: &str) -> String {
+        format!("{}{}", type_name, self.options.schema_name_suffix)
+    }
+
+    fn input_type(&self, type_name: &str) -> String {
+        format!(
+            "{}.{}.{}",
+            self.options.schema_root_namespace,
+            TypeTarget::OperationInput,
+            type_name
+        )
+    }
+}
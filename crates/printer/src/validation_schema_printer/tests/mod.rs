@@ -0,0 +1,133 @@
+#![cfg(test)]
+
+use insta::assert_snapshot;
+use nitrogql_ast::type_system::TypeSystemDocument;
+use nitrogql_config_file::{EnumStyle, ValidationSchemaLibrary};
+use nitrogql_parser::parse_type_system_document;
+use nitrogql_semantics::resolve_schema_extensions;
+use sourcemap_writer::JustWriter;
+
+use crate::validation_schema_printer::{
+    options::ValidationSchemaPrinterOptions, printer::ValidationSchemaPrinter,
+};
+
+fn type_system() -> TypeSystemDocument<'static> {
+    let doc = parse_type_system_document(
+        "
+        scalar ID
+        scalar String
+        scalar Int
+        scalar Boolean
+        scalar Date
+
+        enum UserType {
+            NormalUser
+            PremiumUser
+        }
+
+        type User {
+            id: ID!
+            type: UserType!
+        }
+
+        \"Query to search users.\"
+        input UserSearchQuery {
+            \"Name of user.\"
+            name: String
+            type: UserType!
+            ids: [ID!]
+            tags: [String]!
+            createdAfter: Date
+            or: [UserSearchQuery!]
+        }
+
+        input Pagination {
+            first: Int!
+            after: ID
+        }
+
+        type Query {
+            users(query: UserSearchQuery!, pagination: Pagination): [User!]!
+        }
+        ",
+    )
+    .unwrap();
+    resolve_schema_extensions(doc).unwrap()
+}
+
+fn print_document(
+    document: &TypeSystemDocument,
+    options: ValidationSchemaPrinterOptions,
+) -> String {
+    let mut result = String::new();
+    let mut writer = JustWriter::new(&mut result);
+    let mut printer = ValidationSchemaPrinter::new(options, &mut writer);
+    printer.print_document(document);
+    result
+}
+
+fn default_options() -> ValidationSchemaPrinterOptions {
+    ValidationSchemaPrinterOptions {
+        schema_source: "./schema".into(),
+        ..ValidationSchemaPrinterOptions::default()
+    }
+}
+
+#[test]
+fn zod_schemas() {
+    let printed = print_document(&type_system(), default_options());
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn valibot_schemas() {
+    let printed = print_document(
+        &type_system(),
+        ValidationSchemaPrinterOptions {
+            library: ValidationSchemaLibrary::Valibot,
+            ..default_options()
+        },
+    );
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn nullable_field_is_not_optional() {
+    let printed = print_document(
+        &type_system(),
+        ValidationSchemaPrinterOptions {
+            input_nullable_field_is_optional: false,
+            ..default_options()
+        },
+    );
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn ts_enum_schemas() {
+    let printed = print_document(
+        &type_system(),
+        ValidationSchemaPrinterOptions {
+            enum_style: EnumStyle::TsEnum,
+            ..default_options()
+        },
+    );
+    assert_snapshot!(printed);
+}
+
+#[test]
+fn custom_scalar_schemas() {
+    let printed = print_document(
+        &type_system(),
+        ValidationSchemaPrinterOptions {
+            scalar_schemas: vec![
+                ("Date".into(), "z.coerce.date()".into()),
+                ("ID".into(), "z.string().uuid()".into()),
+            ]
+            .into_iter()
+            .collect(),
+            ..default_options()
+        },
+    );
+    assert_snapshot!(printed);
+}
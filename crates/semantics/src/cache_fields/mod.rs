@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+
+use graphql_type_system::{Field as SchemaField, Schema, Text, TypeDefinition};
+use nitrogql_ast::{
+    base::{Ident, Pos},
+    operation::{ExecutableDefinition, FragmentDefinition, OperationDefinition, OperationType},
+    selection_set::{Field, InlineFragment, Selection, SelectionSet},
+    OperationDocument,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Name of the key field used for types not listed in cache keys.
+const DEFAULT_KEY_FIELD: &str = "id";
+
+/// Adds `__typename` and key fields to selection sets of given document
+/// so that results can be stored in normalized caches.
+///
+/// `__typename` is added to every selection set of composite types except for
+/// root selection sets of operations. Key fields of each type are taken from
+/// `cache_keys`; types not listed there use `id` if they have such a field.
+/// Fields that are already selected (or whose response names are taken) are not added.
+pub fn add_cache_fields<'a, 'b, S: Text<'b>>(
+    document: &OperationDocument<'a>,
+    schema: &Schema<S, Pos>,
+    cache_keys: &'a HashMap<String, Vec<String>>,
+) -> OperationDocument<'a> {
+    let adder = CacheFieldsAdder { schema, cache_keys };
+    OperationDocument {
+        position: document.position,
+        definitions: document
+            .definitions
+            .iter()
+            .map(|def| match def {
+                ExecutableDefinition::OperationDefinition(def) => {
+                    ExecutableDefinition::OperationDefinition(adder.operation(def))
+                }
+                ExecutableDefinition::FragmentDefinition(def) => {
+                    ExecutableDefinition::FragmentDefinition(adder.fragment(def))
+                }
+            })
+            .collect(),
+    }
+}
+
+struct CacheFieldsAdder<'a, 's, S> {
+    schema: &'s Schema<S, Pos>,
+    cache_keys: &'a HashMap<String, Vec<String>>,
+}
+
+impl<'a, 'b, S: Text<'b>> CacheFieldsAdder<'a, '_, S> {
+    fn operation(&self, operation: &OperationDefinition<'a>) -> OperationDefinition<'a> {
+        let root_types = self.schema.root_types().unwrap_or_default();
+        let root_type = match operation.operation_type {
+            OperationType::Query => &root_types.query_type,
+            OperationType::Mutation => &root_types.mutation_type,
+            OperationType::Subscription => &root_types.subscription_type,
+        };
+        OperationDefinition {
+            selection_set: self.selection_set(&operation.selection_set, root_type, false, false),
+            ..operation.clone()
+        }
+    }
+
+    fn fragment(&self, fragment: &FragmentDefinition<'a>) -> FragmentDefinition<'a> {
+        FragmentDefinition {
+            selection_set: self.selection_set(
+                &fragment.selection_set,
+                fragment.type_condition.name,
+                true,
+                true,
+            ),
+            ..fragment.clone()
+        }
+    }
+
+    fn selection_set(
+        &self,
+        selection_set: &SelectionSet<'a>,
+        parent_type: &str,
+        add_typename: bool,
+        add_keys: bool,
+    ) -> SelectionSet<'a> {
+        let type_definition = self.schema.get_type(parent_type).map(|def| def.inner_ref());
+        let mut selections = selection_set
+            .selections
+            .iter()
+            .map(|selection| match selection {
+                Selection::Field(field) => Selection::Field(self.field(field, type_definition)),
+                Selection::FragmentSpread(_) => selection.clone(),
+                Selection::InlineFragment(fragment) => {
+                    Selection::InlineFragment(self.inline_fragment(fragment, parent_type))
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut added_fields = vec![];
+        if add_typename {
+            added_fields.push("__typename");
+        }
+        if add_keys {
+            added_fields.extend(type_definition.map_or(vec![], |def| self.key_fields(def)));
+        }
+        for name in added_fields {
+            let is_taken = selections.iter().any(|selection| {
+                matches!(selection, Selection::Field(field) if field.alias.unwrap_or(field.name).name == name)
+            });
+            if !is_taken {
+                selections.push(Selection::Field(Field {
+                    alias: None,
+                    name: Ident {
+                        name,
+                        position: selection_set.position,
+                    },
+                    arguments: None,
+                    directives: vec![],
+                    selection_set: None,
+                }));
+            }
+        }
+        SelectionSet {
+            position: selection_set.position,
+            selections,
+        }
+    }
+
+    fn field(&self, field: &Field<'a>, parent_type: Option<&TypeDefinition<S, Pos>>) -> Field<'a> {
+        let Some(selection_set) = field.selection_set.as_ref() else {
+            return field.clone();
+        };
+        let field_type = parent_type
+            .and_then(|def| {
+                fields_of_type(def)
+                    .iter()
+                    .find(|f| **f.name == *field.name.name)
+            })
+            .map(|f| f.r#type.unwrapped());
+        let Some(field_type) = field_type else {
+            // Invalid selections are left as is.
+            return field.clone();
+        };
+        Field {
+            selection_set: Some(self.selection_set(selection_set, field_type, true, true)),
+            ..field.clone()
+        }
+    }
+
+    fn inline_fragment(
+        &self,
+        fragment: &InlineFragment<'a>,
+        parent_type: &str,
+    ) -> InlineFragment<'a> {
+        // `__typename` is already added to the enclosing selection set.
+        // Key fields are needed when the fragment narrows the type.
+        let selection_set = match fragment.type_condition {
+            Some(type_condition) if type_condition.name != parent_type => {
+                self.selection_set(&fragment.selection_set, type_condition.name, false, true)
+            }
+            _ => self.selection_set(&fragment.selection_set, parent_type, false, false),
+        };
+        InlineFragment {
+            selection_set,
+            ..fragment.clone()
+        }
+    }
+
+    /// Returns key fields of given type.
+    fn key_fields(&self, type_definition: &TypeDefinition<S, Pos>) -> Vec<&'a str> {
+        cache_keys_of_type(type_definition, self.cache_keys).fields()
+    }
+}
+
+/// How objects of a type are identified in normalized caches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheKeys<'a> {
+    /// Keyed by fields configured in cache keys.
+    Configured(Vec<&'a str>),
+    /// Keyed by the `id` field.
+    Id,
+    /// Not keyed; objects are embedded in their parents.
+    Unkeyed,
+}
+
+impl<'a> CacheKeys<'a> {
+    /// Returns the key fields.
+    pub fn fields(&self) -> Vec<&'a str> {
+        match self {
+            CacheKeys::Configured(fields) => fields.clone(),
+            CacheKeys::Id => vec![DEFAULT_KEY_FIELD],
+            CacheKeys::Unkeyed => vec![],
+        }
+    }
+}
+
+/// Returns how objects of given type are keyed in normalized caches.
+/// Keys configured for the type take precedence over keys configured for
+/// interfaces it implements. Configured fields that the type does not have are ignored.
+pub fn cache_keys_of_type<'a, 'b, S: Text<'b>, D>(
+    type_definition: &TypeDefinition<S, D>,
+    cache_keys: &'a HashMap<String, Vec<String>>,
+) -> CacheKeys<'a> {
+    let fields = fields_of_type(type_definition);
+    let has_field = |name: &str| fields.iter().any(|field| **field.name == *name);
+    let interfaces = match type_definition {
+        TypeDefinition::Object(def) => &def.interfaces[..],
+        TypeDefinition::Interface(def) => &def.interfaces[..],
+        _ => &[],
+    };
+    let configured = cache_keys.get(type_definition.name().borrow()).or_else(|| {
+        interfaces
+            .iter()
+            .find_map(|interface| cache_keys.get(interface.borrow()))
+    });
+    match configured {
+        Some(keys) => CacheKeys::Configured(
+            keys.iter()
+                .filter(|key| has_field(key))
+                .map(|key| key.as_str())
+                .collect(),
+        ),
+        None if has_field(DEFAULT_KEY_FIELD) => CacheKeys::Id,
+        None => CacheKeys::Unkeyed,
+    }
+}
+
+fn fields_of_type<S, D>(type_definition: &TypeDefinition<S, D>) -> &[SchemaField<S, D>] {
+    match type_definition {
+        TypeDefinition::Object(def) => &def.fields,
+        TypeDefinition::Interface(def) => &def.fields,
+        _ => &[],
+    }
+}
@@ -0,0 +1,155 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use graphql_type_system::Schema;
+use insta::assert_snapshot;
+use nitrogql_ast::{base::Pos, OperationDocument};
+use nitrogql_parser::{parse_operation_document, parse_type_system_document};
+use nitrogql_printer::GraphQLPrinter;
+use sourcemap_writer::JustWriter;
+
+use crate::{
+    add_cache_fields, ast_to_type_system, resolve_operation_extensions, resolve_schema_extensions,
+};
+
+const SCHEMA: &str = r#"
+type Query {
+    me: User!
+    node(id: ID!): Node
+    search(text: String!): [SearchResult!]!
+    product(sku: String!): Product
+}
+
+type Mutation {
+    updateUser(name: String!): UpdateUserPayload!
+}
+
+interface Node {
+    id: ID!
+}
+
+type User implements Node {
+    id: ID!
+    name: String!
+    posts: [Post!]!
+}
+
+type Post implements Node {
+    id: ID!
+    title: String!
+    author: User!
+}
+
+type Product {
+    sku: String!
+    region: String!
+    name: String!
+}
+
+type UpdateUserPayload {
+    user: User!
+    ok: Boolean!
+}
+
+union SearchResult = User | Post | Product
+"#;
+
+#[test]
+fn nested_fields() {
+    let doc = add(
+        "
+query {
+    me {
+        name
+        posts {
+            title
+            author { name }
+        }
+    }
+}
+
+mutation {
+    updateUser(name: \"foo\") {
+        ok
+        user { name }
+    }
+}
+",
+        HashMap::new(),
+    );
+    assert_snapshot!(doc);
+}
+
+#[test]
+fn abstract_types() {
+    let doc = add(
+        "
+query {
+    node(id: \"1\") {
+        ... on User { name }
+    }
+    search(text: \"foo\") {
+        ... on User { name }
+        ... on Post { title }
+        ... on Product { name }
+    }
+}
+",
+        HashMap::new(),
+    );
+    assert_snapshot!(doc);
+}
+
+#[test]
+fn configured_keys() {
+    let doc = add(
+        "
+query {
+    product(sku: \"1\") { name }
+}
+
+fragment F on Product {
+    name
+}
+",
+        HashMap::from([(
+            "Product".to_owned(),
+            vec!["sku".to_owned(), "region".to_owned()],
+        )]),
+    );
+    assert_snapshot!(doc);
+}
+
+#[test]
+fn selected_fields_are_not_duplicated() {
+    let doc = add(
+        "
+query {
+    me {
+        id
+        __typename
+        posts {
+            id: title
+        }
+    }
+}
+",
+        HashMap::new(),
+    );
+    assert_snapshot!(doc);
+}
+
+fn add(source: &str, cache_keys: HashMap<String, Vec<String>>) -> String {
+    let schema = resolve_schema_extensions(parse_type_system_document(SCHEMA).unwrap()).unwrap();
+    let schema: Schema<Cow<str>, Pos> = ast_to_type_system(&schema);
+    let (document, _) =
+        resolve_operation_extensions(parse_operation_document(source).unwrap()).unwrap();
+    let document = add_cache_fields(&document, &schema, &cache_keys);
+    print_document(&document)
+}
+
+fn print_document(document: &OperationDocument) -> String {
+    let mut buffer = String::new();
+    let mut printer = JustWriter::new(&mut buffer);
+    document.print_graphql(&mut printer);
+    buffer
+}
@@ -0,0 +1,85 @@
+use nitrogql_ast::{
+    base::Ident,
+    directive::Directive,
+    operation::{ExecutableDefinition, FragmentDefinition, OperationDefinition},
+    selection_set::{Field, Selection, SelectionSet},
+    OperationDocument,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// Name of the directive that marks client-only selections.
+pub const CLIENT_DIRECTIVE: &str = "client";
+
+/// Checks whether given directives include `@client`.
+pub fn has_client_directive(directives: &[Directive]) -> bool {
+    directives
+        .iter()
+        .any(|directive| directive.name.name == CLIENT_DIRECTIVE)
+}
+
+/// Removes selections marked with `@client` from given document
+/// so that the document can be sent to the server.
+///
+/// Selection sets that become empty are given `__typename`
+/// so that the document stays valid.
+pub fn remove_client_fields<'a>(document: &OperationDocument<'a>) -> OperationDocument<'a> {
+    OperationDocument {
+        position: document.position,
+        definitions: document
+            .definitions
+            .iter()
+            .map(|def| match def {
+                ExecutableDefinition::OperationDefinition(def) => {
+                    ExecutableDefinition::OperationDefinition(OperationDefinition {
+                        selection_set: remove_from_selection_set(&def.selection_set),
+                        ..def.clone()
+                    })
+                }
+                ExecutableDefinition::FragmentDefinition(def) => {
+                    ExecutableDefinition::FragmentDefinition(FragmentDefinition {
+                        selection_set: remove_from_selection_set(&def.selection_set),
+                        ..def.clone()
+                    })
+                }
+            })
+            .collect(),
+    }
+}
+
+fn remove_from_selection_set<'a>(selection_set: &SelectionSet<'a>) -> SelectionSet<'a> {
+    let mut selections = selection_set
+        .selections
+        .iter()
+        .filter(|selection| !has_client_directive(selection.directives()))
+        .map(|selection| match selection {
+            Selection::Field(field) => Selection::Field(Field {
+                selection_set: field.selection_set.as_ref().map(remove_from_selection_set),
+                ..field.clone()
+            }),
+            Selection::FragmentSpread(_) => selection.clone(),
+            Selection::InlineFragment(fragment) => {
+                let mut fragment = fragment.clone();
+                fragment.selection_set = remove_from_selection_set(&fragment.selection_set);
+                Selection::InlineFragment(fragment)
+            }
+        })
+        .collect::<Vec<_>>();
+    if selections.is_empty() {
+        selections.push(Selection::Field(Field {
+            alias: None,
+            name: Ident {
+                name: "__typename",
+                position: selection_set.position,
+            },
+            arguments: None,
+            directives: vec![],
+            selection_set: None,
+        }));
+    }
+    SelectionSet {
+        position: selection_set.position,
+        selections,
+    }
+}
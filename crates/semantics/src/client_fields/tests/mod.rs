@@ -0,0 +1,62 @@
+use insta::assert_snapshot;
+use nitrogql_ast::OperationDocument;
+use nitrogql_parser::parse_operation_document;
+use nitrogql_printer::GraphQLPrinter;
+use sourcemap_writer::JustWriter;
+
+use crate::{remove_client_fields, resolve_operation_extensions};
+
+#[test]
+fn client_fields() {
+    let doc = remove(
+        "
+query {
+    me {
+        name
+        isSelected @client
+        settings @client { theme }
+        ... on User @client { draft }
+        ...F @client
+    }
+}
+
+fragment F on User {
+    name
+}
+",
+    );
+    assert_snapshot!(doc);
+}
+
+#[test]
+fn empty_selection_sets() {
+    let doc = remove(
+        "
+query {
+    isLoggedIn @client
+    me {
+        localNote @client
+    }
+}
+
+fragment F on User {
+    isSelected @client
+}
+",
+    );
+    assert_snapshot!(doc);
+}
+
+fn remove(source: &str) -> String {
+    let (document, _) =
+        resolve_operation_extensions(parse_operation_document(source).unwrap()).unwrap();
+    let document = remove_client_fields(&document);
+    print_document(&document)
+}
+
+fn print_document(document: &OperationDocument) -> String {
+    let mut buffer = String::new();
+    let mut printer = JustWriter::new(&mut buffer);
+    document.print_graphql(&mut printer);
+    buffer
+}
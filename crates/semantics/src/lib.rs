@@ -1,4 +1,6 @@
 mod ast_to_type_system;
+mod cache_fields;
+mod client_fields;
 mod definition_map;
 mod direct_fields_of_output_type;
 mod operation_extension_resolver;
@@ -10,6 +12,8 @@ mod type_system_to_ast;
 pub mod type_system_utils;
 
 pub use ast_to_type_system::ast_to_type_system;
+pub use cache_fields::{add_cache_fields, cache_keys_of_type, CacheKeys};
+pub use client_fields::{has_client_directive, remove_client_fields, CLIENT_DIRECTIVE};
 pub use definition_map::{generate_definition_map, DefinitionMap};
 pub use direct_fields_of_output_type::direct_fields_of_output_type;
 pub use operation_extension_resolver::{
@@ -1,4 +1,14 @@
-export type NitrogqlConfig = {
+export type NitrogqlConfig =
+  | (NitrogqlProjectConfig & { projects?: undefined })
+  | (Partial<NitrogqlProjectConfig> & {
+      /**
+       * Configs of each project.
+       * Settings not specified in a project are inherited from the root.
+       */
+      projects: Record<string, Partial<NitrogqlProjectConfig>>;
+    });
+
+type NitrogqlProjectConfig = {
   /**
    * List of paths to schema files.
    */
@@ -24,14 +34,33 @@ type ScalarTypeConfig =
       operationOutput: string;
     };
 
+type ScalarSchemaConfig = string | { send: string; receive: string };
+
 /**
  * Nitrogql's config object.
  */
 export type NitrogqlExtension = {
   /**
    * List of plugins to use.
+   * Names other than built-in plugins are treated as JavaScript modules.
+   * An object entry passes options to the plugin.
+   */
+  plugins?:
+    | readonly (string | { name: string; options?: unknown })[]
+    | undefined;
+  /**
+   * Key fields of each type used by normalized caches.
+   * Types not listed here are keyed by `id` if they have the field.
+   * Keys of an interface apply to object types implementing it.
+   * `CacheKeyConfig` is exported from the schema file only when emitSchemaRuntime is true.
+   */
+  cacheKeys?: Record<string, readonly string[]> | undefined;
+  /**
+   * List of paths to client schema files.
+   * Fields defined in these files must be selected with `@client`
+   * and are removed from documents sent to the server.
    */
-  plugins?: readonly string[] | undefined;
+  clientSchema?: string | readonly string[] | undefined;
   /**
    * Config related to the 'generate' command.
    */
@@ -62,6 +91,59 @@ export type NitrogqlExtension = {
          * Needed if you want to generate resolvers types.
          */
         resolversOutput?: string | undefined;
+        /**
+         * Config related to generated resolver types.
+         */
+        resolvers?:
+          | {
+              /**
+               * Name of the root resolvers type.
+               * @default "Resolvers"
+               */
+              resolversTypeName?: string | undefined;
+              /**
+               * Name of the resolver output type.
+               * @default "ResolverOutput"
+               */
+              resolverOutputTypeName?: string | undefined;
+              /**
+               * Default type of context.
+               * Either `module#ExportName` or a TypeScript type.
+               */
+              contextType?: string | undefined;
+              /**
+               * Mapping from GraphQL types to parent types of resolvers.
+               * Each value is either `module#ExportName` or a TypeScript type.
+               */
+              mappers?: Record<string, string> | undefined;
+              /**
+               * Whether field resolvers are optional
+               * when the parent type has the same property.
+               * @default false
+               */
+              optionalFieldResolvers?: boolean | undefined;
+              /**
+               * Mapping from subscription field names to payload types.
+               * Each value is either `module#ExportName` or a TypeScript type.
+               */
+              subscriptionPayloads?: Record<string, string> | undefined;
+            }
+          | undefined;
+        /**
+         * Path to the output validation schema file.
+         * Validation schemas are generated for input types, enums and scalars.
+         */
+        validationSchemaOutput?: string | undefined;
+        /**
+         * Library used by generated validation schemas.
+         * @default "zod"
+         */
+        validationSchemaLibrary?: "zod" | "valibot" | undefined;
+        /**
+         * Path to the output mock factories file.
+         * Mock factories are generated for output types.
+         */
+        mocksOutput?: string | undefined;
         /**
          * Module specifier for importing schema types from operations.
          * Defaults to relative paths if not specified.
@@ -76,12 +158,32 @@ export type NitrogqlExtension = {
                * Mapping from GraphQL scalar types to TypeScript types.
                */
               scalarTypes?: Record<string, ScalarTypeConfig> | undefined;
+              /**
+               * Mapping from GraphQL scalar types to validation schemas.
+               * `send` is used for input values and `receive` for operation results.
+               */
+              scalarSchemas?: Record<string, ScalarSchemaConfig> | undefined;
+              /**
+               * Mapping from GraphQL scalar types to mock values.
+               * Mocks of custom scalars not listed here return null.
+               */
+              scalarMocks?: Record<string, string> | undefined;
               /**
                * Whether to allow undefined as input value
                * for nullable fields.
                * @default true
                */
               allowUndefinedAsOptionalInput?: boolean | undefined;
+              /**
+               * Whether fragment spreads are masked in generated result types.
+               * @default false
+               */
+              fragmentMasking?: boolean | undefined;
+              /**
+               * Whether fields and lists in operation results are readonly.
+               * @default false
+               */
+              readonlyOutput?: boolean | undefined;
             }
           | undefined;
         /**
@@ -157,6 +259,155 @@ export type NitrogqlExtension = {
          * @default false
          */
         emitSchemaRuntime?: boolean | undefined;
+        /**
+         * How enums are emitted in the schema file.
+         * Effective only when emitSchemaRuntime is true.
+         * Otherwise enums are emitted as unions and a warning is reported.
+         * @default "const-object"
+         */
+        enumStyle?: "union" | "const-object" | "ts-enum" | undefined;
+        /**
+         * Whether to emit an array of values for each enum.
+         * Effective only when emitSchemaRuntime is true.
+         * @default false
+         */
+        emitEnumValues?: boolean | undefined;
+        /**
+         * Whether to emit runtime validation schemas of operation results.
+         * Requires the standalone-ts-4.0 mode.
+         * @default false
+         */
+        emitResultSchemas?: boolean | undefined;
+        /**
+         * Whether to add `__typename` and key fields to selection sets of operations.
+         * @default false
+         */
+        addCacheFields?: boolean | undefined;
+        /**
+         * Config related to persisted queries.
+         */
+        persistedQueries?:
+          | {
+              /**
+               * Path to the output manifest file that maps
+               * hashes of operation documents to the documents.
+               */
+              manifestOutput?: string | undefined;
+              /**
+               * What generated operation modules embed as the runtime value of operations.
+               * "persisted-only" embeds only the document hash and metadata of the operation.
+               * @default "full"
+               */
+              documentMode?: "full" | "persisted-only" | undefined;
+            }
+          | undefined;
+      }
+    | undefined;
+  /**
+   * Config related to the 'check' command.
+   */
+  check?:
+    | {
+        /**
+         * Lint rules applied to the schema. All rules are disabled by default.
+         */
+        schemaLint?:
+          | {
+              /**
+               * Whether type names must be in PascalCase.
+               */
+              typeNamePascalCase?: boolean | undefined;
+              /**
+               * Whether field and argument names must be in camelCase.
+               */
+              fieldNameCamelCase?: boolean | undefined;
+              /**
+               * Whether enum values must be in SCREAMING_SNAKE_CASE.
+               */
+              enumValueScreamingCase?: boolean | undefined;
+              /**
+               * Suffix that names of input object types must have.
+               */
+              inputTypeSuffix?: string | undefined;
+              /**
+               * Whether fields of root types must have descriptions.
+               */
+              requireRootFieldDescription?: boolean | undefined;
+              /**
+               * Whether items of list types must be non-nullable.
+               */
+              nonNullListItems?: boolean | undefined;
+              /**
+               * Whether every `@deprecated` must specify a reason.
+               */
+              requireDeprecationReason?: boolean | undefined;
+              /**
+               * Whether types named `*Connection` and `*Edge` must follow
+               * the Relay connection specification.
+               */
+              relayConnection?: boolean | undefined;
+            }
+          | undefined;
+        /**
+         * Lint rules applied to operations. All rules are disabled by default.
+         */
+        operationLint?:
+          | {
+              /**
+               * Whether every operation must have a name.
+               */
+              requireOperationName?: boolean | undefined;
+              /**
+               * Whether operation names must match the name of the file they are defined in.
+               */
+              operationNameMatchesFileName?: boolean | undefined;
+              /**
+               * Whether fragments spread across files must be imported by name
+               * instead of with a wildcard import.
+               */
+              requireNamedFragmentImports?: boolean | undefined;
+              /**
+               * Whether `id` must be selected from types that have an `id` field.
+               */
+              requireIdSelection?: boolean | undefined;
+              /**
+               * Maximum depth of selection sets.
+               */
+              maxSelectionDepth?: number | undefined;
+              /**
+               * Whether aliasing `__typename` is disallowed.
+               */
+              noTypenameAlias?: boolean | undefined;
+              /**
+               * Whether key fields must be selected from types listed in `cacheKeys`.
+               */
+              requireCacheKeys?: boolean | undefined;
+            }
+          | undefined;
+        /**
+         * Complexity limits applied to operations. Limits are not enforced by default.
+         * Depth of operations is limited by `operationLint.maxSelectionDepth`.
+         */
+        complexity?:
+          | {
+              /**
+               * Maximum number of aliases in an operation.
+               */
+              maxAliases?: number | undefined;
+              /**
+               * Maximum cost of an operation.
+               * Cost of fields and types can be specified by the `@cost(weight:)` directive,
+               * which must be declared in the schema.
+               */
+              maxCost?: number | undefined;
+              /**
+               * Number of items assumed for list fields
+               * that are not limited by `first` or `last` argument.
+               * @default 1
+               */
+              defaultListSize?: number | undefined;
+            }
+          | undefined;
       }
     | undefined;
 };
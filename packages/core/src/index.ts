@@ -6,6 +6,11 @@ import {
 import { NitrogqlConfig, NitrogqlExtension } from "./configFormat.js";
 import { loadSchemaJs } from "./loader.js";
 import { setMemory } from "./memory.js";
+import {
+  NitrogqlPlugin,
+  NitrogqlPluginCheckError,
+  NitrogqlPluginFactory,
+} from "./plugin.js";
 
 export {
   /**
@@ -18,4 +23,10 @@ export {
   loadSchemaJs,
 };
 
-export type { NitrogqlConfig, NitrogqlExtension };
+export type {
+  NitrogqlConfig,
+  NitrogqlExtension,
+  NitrogqlPlugin,
+  NitrogqlPluginCheckError,
+  NitrogqlPluginFactory,
+};
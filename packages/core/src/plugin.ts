@@ -0,0 +1,107 @@
+/**
+ * Error reported by a plugin.
+ */
+export type NitrogqlPluginCheckError = {
+  message: string;
+  /**
+   * Coordinate of the element the error is about.
+   * Errors of the schema use schema coordinates, e.g. `User`, `User.name` or `@auth`.
+   * Errors of operations use names of operations or fragments followed by
+   * response keys of fields, e.g. `GetUser` or `GetUser.user.name`.
+   */
+  coordinate?: string | undefined;
+};
+
+/**
+ * File generated by a plugin.
+ */
+export type NitrogqlPluginOutputFile = {
+  /**
+   * Path of the file, relative to the configuration file.
+   */
+  path: string;
+  content: string;
+};
+
+type MaybePromise<T> = T | Promise<T>;
+
+/**
+ * Interface of a plugin implemented as a JavaScript module.
+ * The plugin object should be the default export of the module.
+ *
+ * Each hook receives the whole schema as GraphQL source.
+ * Hooks for operations also receive an operation document as GraphQL source.
+ */
+export type NitrogqlPlugin = {
+  /**
+   * Name of the plugin.
+   */
+  name: string;
+  /**
+   * Additional schema definition provided by the plugin.
+   */
+  schemaAddition?: string | undefined;
+  /**
+   * Checks the schema and returns errors.
+   */
+  checkSchema?: (schema: string) => MaybePromise<NitrogqlPluginCheckError[]>;
+  /**
+   * Returns TypeScript types that replace resolver output types,
+   * keyed by type name. Types not in the returned object are not changed.
+   * `types` maps type names to the current resolver output types.
+   */
+  overrideResolverOutputTypes?: (
+    schema: string,
+    types: Record<string, string>
+  ) => MaybePromise<Record<string, string> | null>;
+  /**
+   * Transforms the schema so that it represents which fields have resolvers.
+   * Returns the transformed schema, or null to keep the schema as is.
+   * Built-in definitions are neither given nor expected to be returned.
+   */
+  transformDocumentForResolvers?: (
+    schema: string
+  ) => MaybePromise<string | null>;
+  /**
+   * Transforms the schema used by a GraphQL server at runtime.
+   * Returns the transformed schema, or null to keep the schema as is.
+   * Built-in definitions are neither given nor expected to be returned.
+   */
+  transformDocumentForRuntimeServer?: (
+    schema: string
+  ) => MaybePromise<string | null>;
+  /**
+   * Checks an operation document and returns errors.
+   * Called only for documents that passed built-in checks.
+   */
+  checkOperation?: (
+    document: string,
+    schema: string
+  ) => MaybePromise<NitrogqlPluginCheckError[]>;
+  /**
+   * Transforms a checked operation document.
+   * Returns the transformed document, or null to keep the document as is.
+   * The transformed document is checked again and used for generating code.
+   */
+  transformOperationDocument?: (
+    document: string,
+    schema: string
+  ) => MaybePromise<string | null>;
+  /**
+   * Generates additional files when the `generate` command is run.
+   * Receives the schema and all operation documents along with their paths.
+   */
+  generateFiles?: (input: {
+    schema: string;
+    operations: { path: string; document: string }[];
+  }) => MaybePromise<NitrogqlPluginOutputFile[]>;
+};
+
+/**
+ * Function that creates a plugin from options given in the config file.
+ * A plugin module that accepts options should default-export this function.
+ * `options` is null when no options are given.
+ */
+export type NitrogqlPluginFactory<Options = unknown> = (
+  options: Options | null
+) => MaybePromise<NitrogqlPlugin>;
@@ -3,7 +3,7 @@
 import path from "node:path";
 import { readFile } from "node:fs/promises";
 import { executeConfigFileSync } from "@nitrogql/core";
-import { init } from "@nitrogql/loader-core";
+import { init, listFiles } from "@nitrogql/loader-core";
 
 const { initiateTask, getLog } = await init();
 
@@ -29,7 +29,7 @@ export default function graphQLLoader(source) {
       const configFileSource = configFileIsJS(configFilePath)
         ? executeConfigFileSync(configFilePath)
         : await readFile(configFilePath, "utf-8");
-      task.loadConfig(configFileSource);
+      task.loadConfig(configFileSource, configFilePath);
     }
     lastLoadedConfigPath = configFilePath;
 
@@ -37,6 +37,16 @@ export default function graphQLLoader(source) {
     while (true) {
       const status = task.status();
       switch (status.status) {
+        case "directoryRequired": {
+          await Promise.all(
+            status.directories.map(async (directory) => {
+              this.addContextDependency(directory);
+              const files = await listFiles(directory);
+              task.supplyDirectory(directory, files);
+            })
+          );
+          break;
+        }
         case "fileRequired": {
           const requiredFiles = status.files;
           await Promise.all(
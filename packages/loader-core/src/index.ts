@@ -3,6 +3,8 @@ import { WASMInstance } from "./wasm.js";
 import { Task } from "./task.js";
 import { WASMBin, WasmError } from "./bin.js";
 
+export { listFiles } from "./listFiles.js";
+
 /**
  * Initialize the loader binary.
  */
@@ -0,0 +1,27 @@
+import { readdir } from "fs/promises";
+import path from "path";
+
+/**
+ * Lists all files in given directory recursively.
+ * Returns an empty list if the directory does not exist.
+ */
+export async function listFiles(directory: string): Promise<string[]> {
+  const entries = await readdir(directory, { withFileTypes: true }).catch(
+    (err: NodeJS.ErrnoException) => {
+      if (err.code === "ENOENT") {
+        return [];
+      }
+      throw err;
+    }
+  );
+  const files = await Promise.all(
+    entries.map(async (entry) => {
+      const entryPath = path.join(directory, entry.name);
+      if (entry.isDirectory()) {
+        return listFiles(entryPath);
+      }
+      return entry.isFile() ? [entryPath] : [];
+    })
+  );
+  return files.flat();
+}
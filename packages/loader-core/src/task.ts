@@ -1,6 +1,13 @@
 import { WasmError, type WASMBin } from "./bin.js";
 
 export type TaskStatus =
+  | {
+      status: "directoryRequired";
+      /**
+       * Directories whose files should be listed.
+       */
+      directories: readonly string[];
+    }
   | {
       status: "fileRequired";
       /**
@@ -25,21 +32,44 @@ export class Task {
 
   /**
    * Load configuration from given JSON string.
+   * Paths in the configuration are resolved from the directory of `configFilePath`.
    * TODO: in current implementation config is internally shared among all tasks.
    */
-  loadConfig(configString: string) {
-    const configFilePathString = this.#bin.alloc.allocString(configString);
-    this.#bin.exports.load_config(
-      configFilePathString.ptr,
-      configFilePathString.size
+  loadConfig(configString: string, configFilePath: string) {
+    const pathString = this.#bin.alloc.allocString(configFilePath);
+    const sourceString = this.#bin.alloc.allocString(configString);
+    const loadConfigResult = this.#bin.exports.load_config(
+      pathString.ptr,
+      pathString.size,
+      sourceString.ptr,
+      sourceString.size
     );
-    configFilePathString.free();
+    pathString.free();
+    sourceString.free();
+    if (!loadConfigResult) {
+      throw new WasmError("graphql-loader failed to load config", this.#bin);
+    }
   }
 
   /**
    * Returns the current status of the task.
    */
   status(): TaskStatus {
+    const getRequiredDirectoriesResult =
+      this.#bin.exports.get_required_directories(this.taskId);
+    if (!getRequiredDirectoriesResult) {
+      throw new WasmError(
+        "graphql-loader failed to get required directories",
+        this.#bin
+      );
+    }
+    const requiredDirectories = this.#bin
+      .readResult()
+      .split("\n")
+      .filter(Boolean);
+    if (requiredDirectories.length > 0) {
+      return { status: "directoryRequired", directories: requiredDirectories };
+    }
     const getRequiredFilesResult = this.#bin.exports.get_required_files(
       this.taskId
     );
@@ -56,6 +86,32 @@ export class Task {
     return { status: "fileRequired", files: requiredFiles };
   }
 
+  /**
+   * Supply the list of files in one directory to the task.
+   */
+  supplyDirectory(directory: string, files: readonly string[]) {
+    const directoryString = this.#bin.alloc.allocString(directory);
+    const filesString = this.#bin.alloc.allocString(files.join("\n"));
+    try {
+      const result = this.#bin.exports.load_directory(
+        this.taskId,
+        directoryString.ptr,
+        directoryString.size,
+        filesString.ptr,
+        filesString.size
+      );
+      if (!result) {
+        throw new WasmError(
+          "graphql-loader failed to load directory",
+          this.#bin
+        );
+      }
+    } finally {
+      directoryString.free();
+      filesString.free();
+    }
+  }
+
   /**
    * Supply one file to the task.
    */
@@ -8,13 +8,26 @@ export type WASMInterface = {
   free_string(ptr: number, size: number): void;
   get_result_ptr(): number;
   get_result_size(): number;
-  load_config(input_ptr: number, input_size: number): number;
+  load_config(
+    path_ptr: number,
+    path_size: number,
+    input_ptr: number,
+    input_size: number
+  ): number;
   initiate_task(
     filename_ptr: number,
     filename_size: number,
     input_ptr: number,
     input_size: number
   ): number;
+  get_required_directories(task_id: number): number;
+  load_directory(
+    task_id: number,
+    directory_ptr: number,
+    directory_size: number,
+    files_ptr: number,
+    files_size: number
+  ): number;
   get_required_files(task_id: number): number;
   load_file(
     task_id: number,
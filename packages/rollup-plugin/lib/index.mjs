@@ -3,7 +3,7 @@
 import path from "node:path";
 import { readFile } from "node:fs/promises";
 import { executeConfigFileSync } from "@nitrogql/core";
-import { init } from "@nitrogql/loader-core";
+import { init, listFiles } from "@nitrogql/loader-core";
 import { createFilter } from "@rollup/pluginutils";
 
 const { initiateTask, getLog } = await init();
@@ -45,13 +45,23 @@ export default function NitrogqlRollupPlugin(options) {
         const configFileSource = configFileIsJS(configFilePath)
           ? executeConfigFileSync(configFilePath)
           : await readFile(configFilePath, "utf-8");
-        task.loadConfig(configFileSource);
+        task.loadConfig(configFileSource, configFilePath);
       }
       lastLoadedConfigPath = configFilePath;
 
       while (true) {
         const status = task.status();
         switch (status.status) {
+          case "directoryRequired": {
+            await Promise.all(
+              status.directories.map(async (directory) => {
+                this.addWatchFile(directory);
+                const files = await listFiles(directory);
+                task.supplyDirectory(directory, files);
+              })
+            );
+            break;
+          }
           case "fileRequired": {
             const requiredFiles = status.files;
             await Promise.all(
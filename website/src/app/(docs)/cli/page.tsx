@@ -1,3 +1,4 @@
+import Link from "next/link";
 import { Hint } from "@/app/_utils/Hint";
 import { Highlight } from "@/app/_utils/Highlight";
 import { Toc } from "../_toc";
@@ -50,6 +51,19 @@ export default function CLIUsage() {
         </p>
         <Highlight language="bash">{`npx nitrogql generate --config-file ./path/to/config.yaml`}</Highlight>
 
+        <h4 id="project">
+          <code>--project</code>
+        </h4>
+        <p>
+          Specify the name of the project to run when the configuration file
+          has{" "}
+          <Link href="/configuration/options#projects">multiple projects</Link>.
+          By default, all projects are run in order. Projects after a failing
+          one are still run, and the command fails at the end with errors of
+          all failed projects.
+        </p>
+        <Highlight language="bash">{`npx nitrogql generate --project admin`}</Highlight>
+
         <h4 id="schema">
           <code>--schema</code>
         </h4>
@@ -138,7 +152,9 @@ export default function CLIUsage() {
         | "schemaTypeDefinition"
         | "schemaTypeDefinitionSourceMap"
         | "operationTypeDefinition"
-        | "operationTypeDefinitionSourceMap";
+        | "operationTypeDefinitionSourceMap"
+        | "pluginOutput"
+        | "pluginOutputSourceMap";
       path: string;
     }[];
   }
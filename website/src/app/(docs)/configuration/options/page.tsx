@@ -35,6 +35,15 @@ export default function ConfigurationOptions() {
           💡 Relative paths are always resolved from the location of the
           configuration file.
         </Hint>
+        <p>
+          The CLI validates the configuration file before running commands.
+          Invalid values are reported as errors with their location in the
+          file. Unknown keys in the top level and under{" "}
+          <code>extensions.nitrogql</code> are reported as warnings, with a
+          suggestion if a known key has a similar name. Other extensions are
+          left to the tools that use them. Options that have no effect because
+          of other options are also reported as warnings.
+        </p>
 
         <h3 id="schema-operations">schema and operations</h3>
         <p>
@@ -95,6 +104,45 @@ documents:
           Nitrogql only supports <code>.graphql</code> files for operations.
         </p>
 
+        <h4 id="projects">projects</h4>
+        <p>
+          To manage multiple sets of schema and operations in one configuration
+          file, list them under the <code>projects</code> top-level field.
+          Settings at the top level are inherited by every project; a project
+          can override them, and <code>extensions.nitrogql</code> settings are
+          merged field by field.
+        </p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+extensions:
+  nitrogql:
+    generate:
+      mode: standalone-ts-4.0
+projects:
+  web:
+    documents: "./web/**/*.graphql"
+    extensions:
+      nitrogql:
+        generate:
+          schemaOutput: "./web/generated/schema.ts"
+  admin:
+    schema: "./admin-schema/*.graphql"
+    documents: "./admin/**/*.graphql"
+    extensions:
+      nitrogql:
+        generate:
+          schemaOutput: "./admin/generated/schema.ts"`}
+        </Highlight>
+        <p>
+          The CLI runs all projects unless one is selected with the{" "}
+          <Link href="/cli#project">
+            <code>--project</code>
+          </Link>{" "}
+          option. Bundler plugins use the settings of the project whose{" "}
+          <code>documents</code> match the loaded file, and fail for files that
+          belong to no project.
+        </p>
+
         <Hint>
           💡 Other configuration options are placed under{" "}
           <code>extensions.nitrogql</code> in the configuration file.
@@ -105,23 +153,117 @@ documents:
           The <code>plugins</code> field is used to configure which plugins to
           use.
         </p>
-        <p>
-          Currently, third-party plugins are not supported. You can only use
-          built-in plugins. Available plugins are:
-        </p>
+        <p>Available built-in plugins are:</p>
         <ul>
           <li>
             <code>nitrogql:model-plugin</code>
             <code>nitrogql:graphql-scalars-plugin</code>
           </li>
         </ul>
+        <p>
+          Entries ending with <code>.wasm</code> are treated as paths to{" "}
+          <Link href="/references/plugin-wasm">WebAssembly plugins</Link>.
+          Other entries are treated as paths or package names of{" "}
+          <Link href="/references/plugin-js">JavaScript plugins</Link>.
+          Relative paths are resolved from the directory of the configuration
+          file. The webpack loader and the Rollup plugin only support built-in
+          plugins, because other plugins may transform operations.
+        </p>
         <p>Example:</p>
         <Highlight language="yaml">
           {`extensions:
   nitrogql:
     plugins:
-      - nitrogql:model-plugin`}
+      - nitrogql:model-plugin
+      - ./nitrogql-plugin.ts`}
         </Highlight>
+        <p>
+          To pass options to a plugin, write an entry as an object with{" "}
+          <code>name</code> and <code>options</code>. Options that the plugin
+          cannot accept are reported as configuration errors.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    plugins:
+      - name: nitrogql:model-plugin
+        options:
+          directiveName: entity`}
+        </Highlight>
+        <p>
+          <code>nitrogql:model-plugin</code> accepts the following options:
+        </p>
+        <ul>
+          <li>
+            <code>directiveName</code>: name of the directive provided by the
+            plugin. Defaults to <code>model</code>.
+          </li>
+        </ul>
+
+        <h3 id="cacheKeys">cacheKeys</h3>
+        <p>
+          Key fields of each type that identify objects in normalized caches,
+          such as those of urql&apos;s Graphcache or Apollo Client&apos;s{" "}
+          <code>InMemoryCache</code>. Types not listed here are identified by
+          the <code>id</code> field if they have one. Keys of an interface
+          apply to object types implementing it.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    cacheKeys:
+      Product: [sku, region]`}
+        </Highlight>
+        <p>
+          With the <code>check.operationLint.requireCacheKeys</code> option,
+          the <code>check</code> command reports selections of listed types
+          that do not select all key fields. Mutation results missing key
+          fields are reported separately because the cache cannot be updated
+          with them. This includes fragments spread into a mutation in the
+          same document.
+        </p>
+        <p>
+          When <code>generate.emitSchemaRuntime</code> is <code>true</code>, the
+          schema file also exports <code>CacheKeyConfig</code>, which can be
+          passed to the <code>keys</code> option of Graphcache. Types keyed by{" "}
+          <code>id</code> are omitted from it, and types without keys are marked
+          as embedded. If a schema file is emitted without runtime,{" "}
+          <code>CacheKeyConfig</code> is not exported and nitrogql warns about
+          it.
+        </p>
+        <Highlight language="ts">
+          {`import { cacheExchange } from "@urql/exchange-graphcache";
+import { CacheKeyConfig } from "./generated/schema";
+
+const cache = cacheExchange({ keys: CacheKeyConfig });`}
+        </Highlight>
+
+        <h3 id="clientSchema">clientSchema</h3>
+        <p>
+          Paths to schema files that extend the server schema with client-only
+          types and fields, such as local state managed by a client-side cache.
+          Like <code>schema</code>, globs are supported. GraphQL introspection
+          JSON cannot be combined with this option.
+        </p>
+        <Highlight language="yaml">
+          {`schema: ./schema/*.graphql
+documents: ./src/**/*.graphql
+extensions:
+  nitrogql:
+    clientSchema: ./client-schema/*.graphql`}
+        </Highlight>
+        <p>
+          Fields defined in client schema files must be selected with the{" "}
+          <code>@client</code> directive; the <code>check</code> command
+          reports those that are not. Selections marked with{" "}
+          <code>@client</code> are included in generated result types but
+          removed from documents sent to the server, including persisted
+          queries. Client-only definitions are also excluded from{" "}
+          <Link href="#generate.serverGraphqlOutput">
+            generate.serverGraphqlOutput
+          </Link>{" "}
+          and <Link href="#generate.resolversOutput">generate.resolversOutput</Link>.
+        </p>
 
         <h3 id="generate.schemaOutput">generate.schemaOutput</h3>
         <p>
@@ -211,6 +353,207 @@ extensions:
       resolversOutput: "./app/generated/resolvers.ts"`}
         </Highlight>
 
+        <h3 id="generate.resolvers">generate.resolvers</h3>
+        <p>
+          Set of configurations about the generated{" "}
+          <Link href="/references/resolvers-file">resolvers file</Link>.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      resolversOutput: "./app/generated/resolvers.ts"
+      resolvers:
+        # default values
+        resolversTypeName: Resolvers
+        resolverOutputTypeName: ResolverOutput
+        contextType: null
+        mappers: {}
+        optionalFieldResolvers: false
+        subscriptionPayloads: {}`}
+        </Highlight>
+
+        <h4 id="generate.resolvers.resolversTypeName">
+          resolversTypeName, resolverOutputTypeName
+        </h4>
+        <p>
+          Names of the <code>Resolvers</code> and <code>ResolverOutput</code>{" "}
+          types exported from the resolvers file.
+        </p>
+
+        <h4 id="generate.resolvers.contextType">contextType</h4>
+        <p>
+          Default type of the <code>Context</code> type parameter of{" "}
+          <code>Resolvers</code>. The value is either{" "}
+          <code>module#ExportName</code>, which imports a type from the module,
+          or any TypeScript type. Module specifiers are written to the
+          resolvers file as is, so relative paths are resolved from the
+          resolvers file.
+        </p>
+        <Highlight language="yaml">
+          {`resolvers:
+  contextType: "./context#Context"`}
+        </Highlight>
+        <p>
+          With the above configuration, you can write{" "}
+          <code>Resolvers</code> instead of <code>Resolvers&lt;Context&gt;</code>
+          .
+        </p>
+
+        <h4 id="generate.resolvers.mappers">mappers</h4>
+        <p>
+          Mapping from GraphQL types to the TypeScript types of objects that
+          represent them in resolvers. A mapped type is used as the parent
+          type of field resolvers and as the result type of resolvers that
+          return the GraphQL type. Values are in the same format as{" "}
+          <Link href="#generate.resolvers.contextType">contextType</Link>.
+        </p>
+        <Highlight language="yaml">
+          {`resolvers:
+  mappers:
+    User: "./models#UserModel"
+    Post: "{ id: string; authorId: string }"`}
+        </Highlight>
+        <p>
+          Mappers take precedence over the{" "}
+          <Link href="/references/plugin-model">
+            <code>nitrogql:model</code> plugin
+          </Link>
+          .
+        </p>
+
+        <h4 id="generate.resolvers.optionalFieldResolvers">
+          optionalFieldResolvers
+        </h4>
+        <p>
+          If <code>true</code>, a field resolver is optional when the parent
+          type already has a property of the same name, because the default
+          resolver returns that property. Types whose resolvers are all
+          optional can be omitted from <code>Resolvers</code>. Resolvers of
+          root operation types are always required. Default is{" "}
+          <code>false</code>.
+        </p>
+
+        <h4 id="generate.resolvers.subscriptionPayloads">
+          subscriptionPayloads
+        </h4>
+        <p>
+          Resolvers of subscription fields consist of a <code>subscribe</code>{" "}
+          function which returns an <code>AsyncIterable</code> of payloads and
+          a <code>resolve</code> function which converts each payload to the
+          field value. This option maps subscription field names to payload
+          types. Values are in the same format as{" "}
+          <Link href="#generate.resolvers.contextType">contextType</Link>.
+        </p>
+        <p>
+          When the payload type of a field is configured,{" "}
+          <code>resolve</code> is required. Otherwise, the payload is an object
+          that has the field value as a property of the same name and{" "}
+          <code>resolve</code> is optional.
+        </p>
+        <Highlight language="yaml">
+          {`resolvers:
+  subscriptionPayloads:
+    messageAdded: "./events#MessageEvent"`}
+        </Highlight>
+
+        <h3 id="generate.validationSchemaOutput">
+          generate.validationSchemaOutput
+        </h3>
+        <p>
+          When set, the <code>generate</code> command will generate a single
+          TypeScript file which contains validation schemas for every input
+          type, enum and scalar in the schema. This is helpful for validating
+          user input before sending it as operation variables.
+        </p>
+        <p>
+          Each schema is annotated with the corresponding input type, so the
+          inferred type of a schema is the same as the type of input values.
+          Nullable fields are optional if{" "}
+          <code>generate.type.allowUndefinedAsOptionalInput</code> is{" "}
+          <code>true</code>.
+        </p>
+        <p>
+          Like <code>generate.resolversOutput</code>, this file depends on the
+          generated schema types. You need to configure either{" "}
+          <code>generate.schemaOutput</code> or{" "}
+          <code>generate.schemaModuleSpecifier</code> to use this option.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+extensions:
+  nitrogql:
+    generate:
+      validationSchemaOutput: "./app/generated/validation.ts"`}
+        </Highlight>
+        <p>Generated schemas look like:</p>
+        <Highlight language="typescript">
+          {`export const UserSearchQuerySchema: z.ZodType<Schema.__OperationInput.UserSearchQuery> = z.lazy(() => z.object({
+  name: StringSchema.nullish(),
+  type: UserTypeSchema,
+}));`}
+        </Highlight>
+
+        <h3 id="generate.validationSchemaLibrary">
+          generate.validationSchemaLibrary
+        </h3>
+        <p>
+          Library used by generated validation schemas. Possible values are{" "}
+          <code>"zod"</code> and <code>"valibot"</code>. Default is{" "}
+          <code>"zod"</code>.
+        </p>
+
+        <h3 id="generate.mocksOutput">generate.mocksOutput</h3>
+        <p>
+          When set, the <code>generate</code> command will generate a single
+          TypeScript file which contains a mock factory for every output type
+          in the schema. Each factory returns a valid default value:
+        </p>
+        <ul>
+          <li>
+            Scalars use{" "}
+            <Link href="#generate.type.scalarMocks">
+              generate.type.scalarMocks
+            </Link>
+            . Built-in scalars have default values.
+          </li>
+          <li>Enums return their first value.</li>
+          <li>
+            Object types return an object whose nullable fields are{" "}
+            <code>null</code>, list fields are empty and other fields are
+            filled by other factories. Fields can be overridden by the
+            argument.
+          </li>
+          <li>Unions and interfaces return the first possible object type.</li>
+        </ul>
+        <p>
+          In the <code>standalone-ts-4.0</code> mode, each operation also
+          exports a <code>build{"{Name}"}Result</code> function that builds a
+          result typed against the generated result type.
+        </p>
+        <p>
+          Like <code>generate.resolversOutput</code>, this file depends on the
+          generated schema types. You need to configure either{" "}
+          <code>generate.schemaOutput</code> or{" "}
+          <code>generate.schemaModuleSpecifier</code> to use this option.
+        </p>
+        <p>Example:</p>
+        <Highlight language="yaml">
+          {`schema: "./schema/*.graphql"
+extensions:
+  nitrogql:
+    generate:
+      mocksOutput: "./app/generated/mocks.ts"`}
+        </Highlight>
+        <Highlight language="typescript">
+          {`import { mockUser } from "./app/generated/mocks";
+import { buildGetMeQueryResult } from "./getMe.graphql";
+
+const user = mockUser({ name: "John" });
+const result = buildGetMeQueryResult({ me: user });`}
+        </Highlight>
+
         <h3 id="generate.mode">generate.mode</h3>
         <p>
           Configures how types for operations are generated. Possible values
@@ -358,6 +701,115 @@ export const UserType = {
   PremiumUser: "PremiumUser",
 } as const;`}
         </Highlight>
+        <p>
+          The shape of emitted enums can be changed by{" "}
+          <Link href="#generate.enumStyle">
+            <code>generate.enumStyle</code>
+          </Link>
+          .
+        </p>
+
+        <h3 id="generate.enumStyle">generate.enumStyle</h3>
+        <p>
+          How enums are emitted in the schema file. This option is effective
+          only when <code>generate.emitSchemaRuntime</code> is{" "}
+          <code>true</code>; otherwise enums are always emitted as union types
+          and nitrogql warns if another style is set. Possible values are:
+        </p>
+        <ul>
+          <li>
+            <code>union</code>: only a union of string literal types is
+            emitted.
+          </li>
+          <li>
+            <code>const-object</code> (default): a union type and a const object
+            of the same name are emitted.
+          </li>
+          <li>
+            <code>ts-enum</code>: a TypeScript string enum is emitted. Operation
+            and resolver types use the enum too, so you need to use enum members
+            (e.g. <code>UserType.NormalUser</code>) for inputs.
+          </li>
+        </ul>
+        <Highlight language="typescript">
+          {`// enumStyle: ts-enum
+export enum UserType {
+  NormalUser = "NormalUser",
+  PremiumUser = "PremiumUser",
+}`}
+        </Highlight>
+
+        <h3 id="generate.emitEnumValues">generate.emitEnumValues</h3>
+        <p>
+          If <code>true</code>, an array of all values is emitted for each enum.
+          This is useful for building UI such as select boxes directly from the
+          schema. This option is effective only when{" "}
+          <code>generate.emitSchemaRuntime</code> is <code>true</code>. Default
+          is <code>false</code>.
+        </p>
+        <Highlight language="typescript">
+          {`export const UserTypeValues = ["NormalUser", "PremiumUser"] as const;`}
+        </Highlight>
+
+        <h3 id="generate.emitResultSchemas">generate.emitResultSchemas</h3>
+        <p>
+          If <code>true</code>, a runtime validation schema is emitted for the
+          result of each operation. The schema is derived from the same
+          selection as the result type, so you can check that a response from
+          an untrusted server matches the generated type. Default is{" "}
+          <code>false</code>.
+        </p>
+        <p>
+          Schemas are written with the library configured by{" "}
+          <Link href="#generate.validationSchemaLibrary">
+            generate.validationSchemaLibrary
+          </Link>{" "}
+          and custom scalars are validated with{" "}
+          <Link href="#generate.type.scalarSchemas">
+            generate.type.scalarSchemas
+          </Link>
+          . Objects of different types are distinguished by{" "}
+          <code>__typename</code> when it is selected.
+        </p>
+        <p>
+          Since schemas are runtime values, this option requires the{" "}
+          <code>standalone-ts-4.0</code> mode.
+        </p>
+        <Highlight language="typescript">
+          {`import { GetMeQuery, GetMeResultSchema } from "./getMe.graphql";
+
+const result = await client.query({ query: GetMeQuery });
+const data = GetMeResultSchema.parse(result.data);`}
+        </Highlight>
+
+        <h3 id="generate.addCacheFields">generate.addCacheFields</h3>
+        <p>
+          If <code>true</code>, operation documents are emitted with fields
+          that normalized caches need. <code>__typename</code> is added to every
+          selection set except for the root of operations, and key fields (see{" "}
+          <Link href="#cacheKeys">cacheKeys</Link>) are added to selection sets
+          of types that have them. Fields that are already selected are not
+          added twice. Default is <code>false</code>.
+        </p>
+        <p>
+          Added fields are also included in generated result types. This
+          option affects both documents emitted by the{" "}
+          <code>generate</code> command and those emitted by the webpack loader
+          and the Rollup plugin. To add fields, the loaders read GraphQL schema
+          files specified by the <code>schema</code> option; schemas given as
+          introspection results or JavaScript files are not supported by the
+          loaders.
+        </p>
+        <Highlight language="graphql">
+          {`# source
+query GetMe {
+  me { name }
+}
+# emitted
+query GetMe {
+  me { name __typename id }
+}`}
+        </Highlight>
 
         <h3 id="generate.type">generate.type</h3>
         <p>Set of configurations about details of generated types.</p>
@@ -369,7 +821,11 @@ export const UserType = {
       type:
         # default values
         scalarTypes: {}
-        allowUndefinedAsOptionalInput: true`}
+        scalarSchemas: {}
+        scalarMocks: {}
+        allowUndefinedAsOptionalInput: true
+        fragmentMasking: false
+        readonlyOutput: false`}
         </Highlight>
 
         <h4 id="generate.type.scalarTypes">scalarTypes</h4>
@@ -445,6 +901,50 @@ export const UserType = {
           </Link>
         </InPageNav>
 
+        <h4 id="generate.type.scalarSchemas">scalarSchemas</h4>
+        <p>
+          Configures validation schemas of GraphQL scalar types used in{" "}
+          <Link href="#generate.validationSchemaOutput">
+            generate.validationSchemaOutput
+          </Link>{" "}
+          and{" "}
+          <Link href="#generate.emitResultSchemas">
+            generate.emitResultSchemas
+          </Link>
+          . Each value is a TypeScript expression that evaluates to a schema
+          of the configured library. Built-in scalars have default schemas.
+          Other scalars without configuration accept any value.
+        </p>
+        <p>
+          Like <code>scalarTypes</code>, a value can be a pair of{" "}
+          <code>send</code> and <code>receive</code> schemas. Validation
+          schemas of input types use the <code>send</code> schema, and result
+          schemas use the <code>receive</code> schema, which validates values as
+          they appear in responses.
+        </p>
+        <Highlight language="yaml">
+          {`scalarSchemas:
+  Date:
+    send: "z.coerce.date()"
+    receive: "z.string().datetime()"
+  URL: "z.string().url()"`}
+        </Highlight>
+
+        <h4 id="generate.type.scalarMocks">scalarMocks</h4>
+        <p>
+          Configures mock values of GraphQL scalar types used in{" "}
+          <Link href="#generate.mocksOutput">generate.mocksOutput</Link>. Each
+          value is a TypeScript expression. Built-in scalars have default
+          values. Factories of custom scalars without mock values return{" "}
+          <code>null</code>, and the <code>generate</code> command warns about
+          them.
+        </p>
+        <Highlight language="yaml">
+          {`scalarMocks:
+  Date: '"2020-01-01T00:00:00Z"'
+  URL: '"https://example.com/"'`}
+        </Highlight>
+
         <h4 id="generate.type.allowUndefinedAsOptionalInput">
           allowUndefinedAsOptionalInput
         </h4>
@@ -467,6 +967,78 @@ export const UserType = {
           defaults to <code>true</code>.
         </p>
 
+        <h4 id="generate.type.fragmentMasking">fragmentMasking</h4>
+        <p>
+          If this option is set to <code>true</code>, fields selected by a
+          fragment spread are not included in the result type of the enclosing
+          selection. Instead, the result type has a{" "}
+          <code>&quot; $fragmentRefs&quot;</code> marker that tells which
+          fragments were spread. This ensures that a component can only read
+          data it asked for in its own fragment.
+        </p>
+        <Highlight language="graphql">
+          {`# UserCard.graphql
+fragment UserCard on User {
+  name
+}
+
+# Profile.graphql
+#import UserCard from "./UserCard.graphql"
+query Profile {
+  me {
+    id
+    ...UserCard
+  }
+}`}
+        </Highlight>
+        <p>
+          With fragment masking, <code>data.me</code> only has <code>id</code>{" "}
+          and a reference to <code>UserCard</code>. The module generated for a
+          fragment exports a <code>UserCardRef</code> type for props and an{" "}
+          <code>Unmask</code> helper type that turns a reference into the
+          fragment&apos;s data:
+        </p>
+        <Highlight language="typescript">
+          {`import type { UserCardRef, Unmask } from "./UserCard.graphql";
+
+// Unmasking only changes the type; data is passed through as is.
+function unmask<Ref>(ref: Ref): Unmask<Ref> {
+  return ref as Unmask<Ref>;
+}
+
+export const UserCard: React.FC<{ user: UserCardRef }> = (props) => {
+  const user = unmask(props.user);
+  return <p>{user.name}</p>;
+};`}
+        </Highlight>
+        <p>
+          To read the fields of a fragment directly, add the{" "}
+          <code>@unmask</code> directive to the spread (e.g.{" "}
+          <code>...UserCard @unmask</code>). <code>@unmask</code> only affects
+          generated types and is removed from documents sent to the server.
+          This option defaults to <code>false</code>.
+        </p>
+
+        <h4 id="generate.type.readonlyOutput">readonlyOutput</h4>
+        <p>
+          If this option is set to <code>true</code>, all fields and lists in
+          operation result types are <code>readonly</code>. This is useful when
+          query results are frozen by your client or state management library.
+        </p>
+        <Highlight language="typescript">
+          {`import { useQuery } from "@apollo/client";
+import getPostsQuery from "./getPosts.graphql";
+
+const { data } = useQuery(getPostsQuery);
+// Type error: Index signature in type 'readonly ...[]' only permits reading.
+data.posts[0] = newPost;`}
+        </Highlight>
+        <p>
+          Input types and resolver arguments are always <code>readonly</code>{" "}
+          regardless of this option. This option defaults to{" "}
+          <code>false</code>.
+        </p>
+
         <h3 id="generate.name">generate.name</h3>
         <p>
           Set of configurations about names of generated variables and types.
@@ -676,6 +1248,105 @@ import { GetUserVariables } from "./app/graphql/queries/getUser.graphql";`}
           <code>@graphql-typed-document-node/core</code> package to extract the
           variables type from your operation document.
         </Hint>
+
+        <h3 id="generate.persistedQueries">generate.persistedQueries</h3>
+        <p>
+          Set of configurations for persisted queries (also known as trusted
+          documents).
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    generate:
+      persistedQueries:
+        manifestOutput: "./app/generated/persisted-queries.json"
+        documentMode: full
+`}
+        </Highlight>
+
+        <h4 id="generate.persistedQueries.manifestOutput">manifestOutput</h4>
+        <p>
+          Path to the manifest file. If specified, the <code>generate</code>{" "}
+          command emits a JSON file that maps the SHA-256 hash of each
+          operation to its document. The document contains the operation and
+          all fragments used by it, including imported ones.
+        </p>
+        <p>
+          Documents are printed in a canonical form, so hashes do not change
+          when you only reformat your operation files.
+        </p>
+
+        <h4 id="generate.persistedQueries.documentMode">documentMode</h4>
+        <p>
+          What generated operation modules embed as the runtime value of
+          operations. Default is <code>&quot;full&quot;</code>.
+        </p>
+        <ul>
+          <li>
+            <code>&quot;full&quot;</code>: the full document AST.
+          </li>
+          <li>
+            <code>&quot;persisted-only&quot;</code>: only the document hash and
+            metadata of the operation, in the form of{" "}
+            <code>{`{ documentId, __meta__: { operationName, operationType } }`}</code>
+            .
+          </li>
+        </ul>
+        <p>
+          This option affects operation modules emitted in the{" "}
+          <code>standalone-ts-4.0</code> mode and those emitted by the
+          webpack loader. Both use the same hashing as the manifest, so{" "}
+          <code>documentId</code> always matches a key in the manifest.
+        </p>
+
+        <h3 id="check.complexity">check.complexity</h3>
+        <p>
+          Limits on the complexity of operations, checked by the{" "}
+          <code>check</code> command. All limits are off by default. The depth
+          of operations is limited by{" "}
+          <code>check.operationLint.maxSelectionDepth</code> instead.
+        </p>
+        <Highlight language="yaml">
+          {`extensions:
+  nitrogql:
+    check:
+      complexity:
+        maxAliases: 10
+        maxCost: 1000
+        defaultListSize: 10
+`}
+        </Highlight>
+
+        <h4 id="check.complexity.maxAliases">maxAliases</h4>
+        <p>Maximum number of aliases in one operation.</p>
+
+        <h4 id="check.complexity.maxCost">maxCost</h4>
+        <p>
+          Maximum cost of one operation. The cost of a field is its weight plus
+          the cost of its selections. For list fields, the cost of selections
+          is multiplied by the value of the <code>first</code> or{" "}
+          <code>last</code> argument, or by <code>defaultListSize</code> when
+          neither is given.
+        </p>
+        <p>
+          Weights are given with the <code>@cost</code> directive on fields or
+          on types. A field without a weight weighs 1 if it has selections and 0
+          otherwise. nitrogql does not define this directive, so declare it in
+          your schema:
+        </p>
+        <Highlight language="graphql">
+          {`directive @cost(weight: Int!) on FIELD_DEFINITION | OBJECT | INTERFACE | UNION | ENUM | SCALAR
+
+type Query {
+  search(first: Int): [Item!]! @cost(weight: 5)
+}`}
+        </Highlight>
+
+        <h4 id="check.complexity.defaultListSize">defaultListSize</h4>
+        <p>
+          Expected number of items of list fields without a <code>first</code>{" "}
+          or <code>last</code> argument. Default is <code>1</code>.
+        </p>
       </main>
     </Toc>
   );
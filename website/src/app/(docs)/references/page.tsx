@@ -63,8 +63,8 @@ export default function References() {
 
         <h3>Plugins</h3>
         <p>
-          Currently, two built-in plugins are available. Third-party plugins are
-          not supported yet.
+          Currently, two built-in plugins are available. You can also write
+          your own plugins in JavaScript.
         </p>
         <ul>
           <li>
@@ -75,6 +75,12 @@ export default function References() {
               nitrogql:graphql-scalars-plugin
             </Link>
           </li>
+          <li>
+            <Link href="/references/plugin-js">JavaScript plugins</Link>
+          </li>
+          <li>
+            <Link href="/references/plugin-wasm">WebAssembly plugins</Link>
+          </li>
         </ul>
       </main>
     </Toc>
@@ -0,0 +1,215 @@
+import Link from "next/link";
+import { Highlight } from "@/app/_utils/Highlight";
+import { Toc } from "../../_toc";
+import { Breadcrumb } from "@/app/_utils/Breadcrumb";
+import { ogp } from "@/app/_utils/metadata";
+import { Hint } from "@/app/_utils/Hint";
+
+export const metadata = ogp({
+  title: "JavaScript plugins",
+});
+
+export default function JsPlugin() {
+  return (
+    <Toc>
+      <main>
+        <Breadcrumb
+          parents={[{ label: "References", href: "/references" }]}
+          current="JavaScript plugins"
+        />
+        <h2>JavaScript plugins</h2>
+        <p>
+          In addition to built-in plugins, you can write your own plugin as a
+          JavaScript or TypeScript module. A plugin module is loaded when its
+          path or package name is listed in the{" "}
+          <Link href="/configuration/options#plugins">
+            <code>plugins</code>
+          </Link>{" "}
+          option. Relative paths are resolved from the directory of the
+          configuration file.
+        </p>
+        <Highlight language="yaml">
+          {`schema: ./schema/*.graphql
+extensions:
+  nitrogql:
+    plugins:
+      - "nitrogql:model-plugin"
+      - "./nitrogql-plugin.ts"`}
+        </Highlight>
+
+        <h3 id="plugin-object">Plugin object</h3>
+        <p>
+          A plugin module default-exports a plugin object. The type of plugin
+          objects is exported from <code>@nitrogql/core</code> as{" "}
+          <code>NitrogqlPlugin</code>. Every hook is optional and receives the
+          whole schema as GraphQL source. Hooks for operations also receive an
+          operation document as GraphQL source. Hooks may return a Promise.
+        </p>
+        <Highlight language="typescript">
+          {`import type { NitrogqlPlugin } from "@nitrogql/core";
+import { parse, print, visit } from "graphql";
+
+const plugin: NitrogqlPlugin = {
+  name: "internal-fields-plugin",
+  schemaAddition: "directive @internal on FIELD_DEFINITION",
+  checkSchema: (schema) => {
+    const errors = [];
+    // ...
+    errors.push({ message: "Not allowed", coordinate: "User.password" });
+    return errors;
+  },
+  transformDocumentForRuntimeServer: (schema) =>
+    print(
+      visit(parse(schema), {
+        Directive: (node) => (node.name.value === "internal" ? null : undefined),
+      })
+    ),
+};
+
+export default plugin;`}
+        </Highlight>
+
+        <h3 id="options">Options</h3>
+        <p>
+          A plugin that accepts{" "}
+          <Link href="/configuration/options#plugins">options</Link> should
+          default-export a function that receives the options and returns a
+          plugin object. The type of such functions is exported as{" "}
+          <code>NitrogqlPluginFactory</code>. The function receives{" "}
+          <code>null</code> when no options are given. Giving options to a
+          plugin that exports a plain object is an error.
+        </p>
+        <Highlight language="typescript">
+          {`import type { NitrogqlPluginFactory } from "@nitrogql/core";
+
+const createPlugin: NitrogqlPluginFactory<{ prefix: string }> = (options) => ({
+  name: "prefix-plugin",
+  checkSchema: (schema) => {
+    // use options?.prefix ...
+    return [];
+  },
+});
+
+export default createPlugin;`}
+        </Highlight>
+
+        <h4 id="schemaAddition">schemaAddition</h4>
+        <p>
+          GraphQL source that is added to the schema, such as definitions of
+          directives that the plugin provides.
+        </p>
+
+        <h4 id="checkSchema">checkSchema</h4>
+        <p>
+          Returns errors found in the schema. Each error may have a{" "}
+          <code>coordinate</code> such as <code>User</code>,{" "}
+          <code>User.name</code> or <code>@auth</code> to point to the schema
+          element the error is about.
+        </p>
+
+        <h4 id="overrideResolverOutputTypes">overrideResolverOutputTypes</h4>
+        <p>
+          Returns an object that maps type names to TypeScript types. Those
+          types replace the{" "}
+          <Link href="/references/resolvers-file#resolver-output">
+            resolver output types
+          </Link>{" "}
+          of the corresponding GraphQL types; other types are not changed. In
+          addition to the schema, this hook receives the current resolver
+          output types as an object that maps type names to TypeScript types.
+        </p>
+        <Highlight language="typescript">
+          {`overrideResolverOutputTypes: (schema, types) => ({
+  User: \`Omit<\${types.User}, "password">\`,
+}),`}
+        </Highlight>
+
+        <h4 id="transformDocumentForResolvers">
+          transformDocumentForResolvers
+        </h4>
+        <p>
+          Returns the schema that represents which fields have resolvers, or{" "}
+          <code>null</code> to keep the schema as is. Fields removed from the
+          returned schema do not appear in the generated <code>Resolvers</code>{" "}
+          type, and types and fields added to it do.
+        </p>
+
+        <h4 id="transformDocumentForRuntimeServer">
+          transformDocumentForRuntimeServer
+        </h4>
+        <p>
+          Returns the schema emitted to{" "}
+          <Link href="/configuration/options#generate.serverGraphqlOutput">
+            <code>generate.serverGraphqlOutput</code>
+          </Link>
+          , or <code>null</code> to keep the schema as is.
+        </p>
+
+        <h4 id="checkOperation">checkOperation</h4>
+        <p>
+          Receives an operation document and the schema, and returns errors
+          found in the document. Called for each operation document that passed
+          built-in checks. The document includes fragments imported from other
+          files. Each error may have a <code>coordinate</code> that consists of
+          the name of an operation or a fragment followed by response keys of
+          selected fields, such as <code>GetUser</code> or{" "}
+          <code>GetUser.user.name</code>.
+        </p>
+        <Highlight language="typescript">
+          {`checkOperation: (document, schema) => {
+  const errors = [];
+  // ...
+  errors.push({ message: "Do not select this field", coordinate: "GetUser.user.password" });
+  return errors;
+},`}
+        </Highlight>
+
+        <h4 id="transformOperationDocument">transformOperationDocument</h4>
+        <p>
+          Receives an operation document and the schema, and returns a
+          transformed document as GraphQL source, or <code>null</code> to keep
+          the document as is. Called after all operation documents are checked.
+          The transformed document is checked again and then used for
+          generating code.
+        </p>
+
+        <h4 id="generateFiles">generateFiles</h4>
+        <p>
+          Called by the <code>generate</code> command. Receives an object with
+          the schema and all operation documents along with their absolute
+          paths, and returns additional files to write. Paths of returned files
+          are resolved from the directory of the configuration file. Generated
+          files are reported as <code>pluginOutput</code> in the{" "}
+          <Link href="/cli#output-format">JSON output</Link> of the CLI.
+        </p>
+        <Highlight language="typescript">
+          {`generateFiles: ({ schema, operations }) =>
+  operations.map(({ path, document }) => ({
+    path: \`./src/generated/\${basename(path, ".graphql")}.hooks.ts\`,
+    content: generateHooks(document),
+  })),`}
+        </Highlight>
+
+        <Hint>
+          💡 The{" "}
+          <Link href="/guides/getting-started#setting-up-graphql-loader-for-webpack">
+            webpack loader
+          </Link>{" "}
+          and the Rollup plugin do not run plugins, so they cannot apply{" "}
+          <code>transformOperationDocument</code>. They report an error when
+          the configuration file lists plugins other than built-in ones.
+        </Hint>
+
+        <Hint>
+          💡 <code>transformDocumentForResolvers</code> and{" "}
+          <code>transformDocumentForRuntimeServer</code> receive the schema as
+          transformed by preceding plugins, without built-in definitions such
+          as <code>String</code>. Returned schemas should not include them
+          either. Note that <code>printSchema</code> of graphql-js omits applied
+          directives; use <code>parse</code> and <code>print</code> to keep
+          them.
+        </Hint>
+      </main>
+    </Toc>
+  );
+}
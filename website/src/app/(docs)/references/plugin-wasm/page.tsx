@@ -0,0 +1,216 @@
+import Link from "next/link";
+import { Highlight } from "@/app/_utils/Highlight";
+import { Toc } from "../../_toc";
+import { Breadcrumb } from "@/app/_utils/Breadcrumb";
+import { ogp } from "@/app/_utils/metadata";
+import { Hint } from "@/app/_utils/Hint";
+
+export const metadata = ogp({
+  title: "WebAssembly plugins",
+});
+
+export default function WasmPlugin() {
+  return (
+    <Toc>
+      <main>
+        <Breadcrumb
+          parents={[{ label: "References", href: "/references" }]}
+          current="WebAssembly plugins"
+        />
+        <h2>WebAssembly plugins</h2>
+        <p>
+          A plugin can also be a WebAssembly module written in any language
+          that compiles to WebAssembly. WebAssembly plugins are run by a runtime
+          embedded in nitrogql, so they do not need Node.js. A plugin module is
+          loaded when its path, ending with <code>.wasm</code>, is listed in the{" "}
+          <Link href="/configuration/options#plugins">
+            <code>plugins</code>
+          </Link>{" "}
+          option. Paths are resolved from the directory of the configuration
+          file.
+        </p>
+        <Highlight language="yaml">
+          {`schema: ./schema/*.graphql
+extensions:
+  nitrogql:
+    plugins:
+      - "./plugins/internal-fields.wasm"`}
+        </Highlight>
+        <p>
+          WebAssembly plugins provide the same hooks as{" "}
+          <Link href="/references/plugin-js">JavaScript plugins</Link>, such as
+          checking operations and{" "}
+          <Link href="#generate_files">generating additional files</Link>.
+        </p>
+
+        <h3 id="abi">ABI</h3>
+        <p>
+          A plugin is a core WebAssembly module (not a component). The module
+          must not have any imports; WASI is not available to plugins. It must
+          export its linear memory as <code>memory</code> and the functions
+          described below. This ABI is identified by the{" "}
+          <code>nitrogql_plugin_v1_</code> prefix of function names and will
+          not change in a backward-incompatible way.
+        </p>
+        <p>
+          All inputs and outputs are UTF-8 encoded JSON. To pass an input, nitrogql
+          calls <code>nitrogql_plugin_v1_alloc</code> to allocate memory, writes
+          the input there and passes its pointer and length. Functions return
+          the pointer and length of their output packed into an{" "}
+          <code>i64</code>: the pointer in the upper 32 bits and the length in
+          the lower 32 bits. nitrogql never frees memory; a plugin may reuse
+          memory of previous inputs and outputs on each call.
+        </p>
+        <p>
+          Each call of a plugin function may execute a limited number of
+          instructions. If a function traps, runs out of this budget or returns
+          invalid output, the command fails with an error that names the
+          plugin.
+        </p>
+        <Highlight language="wasm">
+          {`(func (export "nitrogql_plugin_v1_alloc") (param $size i32) (result i32))
+(func (export "nitrogql_plugin_v1_name") (result i64))
+;; optional
+(func (export "nitrogql_plugin_v1_configure") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_schema_addition") (result i64))
+(func (export "nitrogql_plugin_v1_check_schema") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_transform_resolver_output_types") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_transform_document_for_resolvers") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_transform_document_for_runtime_server") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_check_operation") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_transform_operation_document") (param $ptr i32) (param $len i32) (result i64))
+(func (export "nitrogql_plugin_v1_generate_files") (param $ptr i32) (param $len i32) (result i64))`}
+        </Highlight>
+        <p>
+          Schemas and operation documents are passed as documents in the JSON
+          representation of the{" "}
+          <a href="https://graphql.org/graphql-js/language/" target="_blank">
+            graphql-js
+          </a>{" "}
+          AST, without location information.
+        </p>
+
+        <h4 id="name">nitrogql_plugin_v1_name</h4>
+        <p>Returns the name of the plugin as a JSON string.</p>
+
+        <h4 id="configure">nitrogql_plugin_v1_configure</h4>
+        <p>
+          Receives{" "}
+          <Link href="/configuration/options#plugins">options</Link> given in
+          the configuration file, or <code>null</code> when no options are
+          given. Called before any other function. Returns <code>null</code>{" "}
+          on success, or an error message as a JSON string when the options
+          are invalid. This function is required if the plugin is given
+          options.
+        </p>
+
+        <h4 id="schema_addition">nitrogql_plugin_v1_schema_addition</h4>
+        <p>
+          Returns GraphQL source that is added to the schema as a JSON string,
+          or <code>null</code>.
+        </p>
+
+        <h4 id="check_schema">nitrogql_plugin_v1_check_schema</h4>
+        <p>
+          Receives the schema and returns an array of errors. Each error has a{" "}
+          <code>message</code> and may have a <code>coordinate</code> such as{" "}
+          <code>User</code>, <code>User.name</code> or <code>@auth</code>.
+        </p>
+        <Highlight language="json">
+          {`[{ "message": "Not allowed", "coordinate": "User.password" }]`}
+        </Highlight>
+
+        <h4 id="transform_resolver_output_types">
+          nitrogql_plugin_v1_transform_resolver_output_types
+        </h4>
+        <p>
+          Receives an object with the schema and the current{" "}
+          <Link href="/references/resolvers-file#resolver-output">
+            resolver output types
+          </Link>{" "}
+          as TypeScript source. Returns an object that maps type names to
+          TypeScript types to use instead.
+        </p>
+        <Highlight language="json">
+          {`// input
+{ "schema": { "kind": "Document", "definitions": [] }, "types": { "User": "User" } }
+// output
+{ "User": "import('./model').User" }`}
+        </Highlight>
+
+        <h4 id="transform_document_for_resolvers">
+          nitrogql_plugin_v1_transform_document_for_resolvers
+        </h4>
+        <p>
+          Receives the schema and returns the schema that represents which
+          fields have resolvers, or <code>null</code> to keep the schema as is.
+        </p>
+
+        <h4 id="transform_document_for_runtime_server">
+          nitrogql_plugin_v1_transform_document_for_runtime_server
+        </h4>
+        <p>
+          Receives the schema and returns the schema emitted to{" "}
+          <Link href="/configuration/options#generate.serverGraphqlOutput">
+            <code>generate.serverGraphqlOutput</code>
+          </Link>
+          , or <code>null</code> to keep the schema as is.
+        </p>
+
+        <h4 id="check_operation">nitrogql_plugin_v1_check_operation</h4>
+        <p>
+          Receives an object with the schema and an operation document that
+          passed built-in checks, and returns an array of errors. The document
+          includes fragments imported from other files. The{" "}
+          <code>coordinate</code> of an error consists of the name of an
+          operation or a fragment followed by response keys of selected
+          fields.
+        </p>
+        <Highlight language="json">
+          {`// input
+{ "schema": { "kind": "Document", "definitions": [] }, "document": { "kind": "Document", "definitions": [] } }
+// output
+[{ "message": "Do not select this field", "coordinate": "GetUser.user.password" }]`}
+        </Highlight>
+
+        <h4 id="transform_operation_document">
+          nitrogql_plugin_v1_transform_operation_document
+        </h4>
+        <p>
+          Receives the same input as{" "}
+          <code>nitrogql_plugin_v1_check_operation</code> after all operation
+          documents are checked. Returns the transformed document as GraphQL
+          source in a JSON string, or <code>null</code> to keep the document as
+          is. The transformed document is checked again and then used for
+          generating code.
+        </p>
+
+        <h4 id="generate_files">nitrogql_plugin_v1_generate_files</h4>
+        <p>
+          Called by the <code>generate</code> command. Receives the schema and
+          all operation documents, and returns additional files to write.
+          Paths of returned files are resolved from the directory of the
+          configuration file. Generated files are reported as{" "}
+          <code>pluginOutput</code> in the{" "}
+          <Link href="/cli#output-format">JSON output</Link> of the CLI.
+        </p>
+        <Highlight language="json">
+          {`// input
+{
+  "schema": { "kind": "Document", "definitions": [] },
+  "operations": [
+    { "path": "/app/src/me.graphql", "document": { "kind": "Document", "definitions": [] } }
+  ]
+}
+// output
+[{ "path": "./src/generated/hooks.ts", "content": "export function useMe() { ... }" }]`}
+        </Highlight>
+
+        <Hint>
+          💡 Unlike JavaScript plugins, returned schemas can only remove schema
+          elements. Elements added by returned schemas are ignored.
+        </Hint>
+      </main>
+    </Toc>
+  );
+}
@@ -39,7 +39,11 @@ export default function ResolversFile() {
         </p>
         <p>
           <code>Context</code> is the type of the context object passed to
-          resolvers.
+          resolvers. Its default can be configured by the{" "}
+          <Link href="/configuration/options#generate.resolvers.contextType">
+            <code>generate.resolvers.contextType</code>
+          </Link>{" "}
+          option.
         </p>
         <p>Example usage:</p>
         <Highlight language="typescript">
@@ -63,6 +67,23 @@ const resolvers: Resolvers<Context> = {
 const queryResolvers: Resolvers<Context>["Query"] = { /* ... */ };`}
         </Highlight>
 
+        <p>
+          Resolvers of subscription fields have <code>subscribe</code> and{" "}
+          <code>resolve</code> functions as required by graphql-js. Payload
+          types can be configured by the{" "}
+          <Link href="/configuration/options#generate.resolvers.subscriptionPayloads">
+            <code>generate.resolvers.subscriptionPayloads</code>
+          </Link>{" "}
+          option.
+        </p>
+        <Highlight language="typescript">
+          {`const subscriptionResolvers: Resolvers<Context>["Subscription"] = {
+  messageAdded: {
+    subscribe: (_, { channel }, { pubsub }) => pubsub.subscribe(channel),
+  },
+};`}
+        </Highlight>
+
         <h4 id="resolver-output">ResolverOutput</h4>
         <p>
           By <code>ResolverOutput&lt;T&gt;</code> we mean the TypeScript type of
@@ -102,6 +123,78 @@ const userResolvers: Resolvers<{}>["User"] = {
           <code>ResolverOutput</code> is useful when you want to give a name to
           the type of an object that should be returned by a resolver.
         </p>
+
+        <h4 id="scalar-resolvers">ScalarResolvers</h4>
+        <p>
+          <code>ScalarResolvers</code> is emitted when the schema defines custom
+          scalars. For each custom scalar, it has a{" "}
+          <code>GraphQLScalarTypeConfig</code> type whose internal type is the
+          type that resolvers receive as arguments and whose external type is
+          the type that clients receive. Both are derived from{" "}
+          <Link href="/configuration/options#generate.type.scalarTypes">
+            <code>generate.type.scalarTypes</code>
+          </Link>
+          , so implementations of <code>serialize</code>,{" "}
+          <code>parseValue</code> and <code>parseLiteral</code> are checked
+          against the same types as other generated code.
+        </p>
+        <Highlight language="typescript">
+          {`import { GraphQLScalarType } from "graphql";
+import { ScalarResolvers } from "./generated/resolvers";
+
+const scalarResolvers: ScalarResolvers = {
+  Date: {
+    name: "Date",
+    serialize: (value) => (value as Date).toISOString(),
+    parseValue: (value) => new Date(value as string),
+  },
+};
+const resolvers = {
+  Query: { /* ... */ },
+  Date: new GraphQLScalarType(scalarResolvers.Date),
+};`}
+        </Highlight>
+
+        <h4 id="directive-resolvers">DirectiveResolvers</h4>
+        <p>
+          <code>DirectiveResolvers&lt;Context&gt;</code> is emitted when the
+          schema defines custom directives that can be applied to schema
+          elements. It is an object type that has a property for each
+          directive. Built-in directives and directives that can only be used
+          in operations are excluded.
+        </p>
+        <p>
+          For each location of the directive, you can provide a transformer
+          function that receives the graphql-js object of the schema element
+          (e.g. <code>GraphQLFieldConfig</code> for{" "}
+          <code>FIELD_DEFINITION</code>) and the arguments of the directive.
+          The transformer returns a new schema element, <code>null</code> to
+          remove it, or <code>undefined</code> to leave it unchanged.
+        </p>
+        <Highlight language="typescript">
+          {`import { defaultFieldResolver } from "graphql";
+import { DirectiveResolvers } from "./generated/resolvers";
+
+// directive @auth(requires: Role!) on FIELD_DEFINITION
+const directiveResolvers: DirectiveResolvers<Context> = {
+  auth: {
+    FIELD_DEFINITION: (fieldConfig, { requires }) => {
+      const resolve = fieldConfig.resolve ?? defaultFieldResolver;
+      return {
+        ...fieldConfig,
+        resolve: (parent, args, context, info) => {
+          assertRole(context, requires);
+          return resolve(parent, args, context, info);
+        },
+      };
+    },
+  },
+};`}
+        </Highlight>
+        <p>
+          With <code>@graphql-tools/utils</code>, transformers can be applied
+          by <code>mapSchema</code> together with <code>getDirective</code>.
+        </p>
       </main>
     </Toc>
   );